@@ -1,77 +1,152 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use criterion_cycles_per_byte::CyclesPerByte;
 
-use ntrust_native::AesState;
-use ntrust_native::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
-use ntrust_native::{CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
-
-pub fn bench_complete_kem(criterion: &mut Criterion<CyclesPerByte>) {
-    let mut rng = AesState::new();
-    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
-    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
-    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
-    let mut ss_alice = [0u8; CRYPTO_BYTES];
-    let mut ss_bob = [0u8; CRYPTO_BYTES];
-
-    criterion.bench_function("kem", |b| {
-        b.iter(|| {
-            crypto_kem_keypair(&mut pk, &mut sk, &mut rng).expect("crypto_kem_keypair failed!");
-            crypto_kem_enc(&mut ct, &mut ss_alice, &pk, &mut rng).expect("crypto_kem_enc failed!");
-            crypto_kem_dec(&mut ss_bob, &ct, &sk).expect("crypto_kem_dec failed!");
-            assert_eq!(ss_bob, ss_alice, "shared keys do not match");
-        })
-    });
-}
+macro_rules! bench_variant {
+    ($variant:ident) => {
+        mod $variant {
+            use criterion::Criterion;
+            use criterion_cycles_per_byte::CyclesPerByte;
 
-pub fn bench_kem_keypair(criterion: &mut Criterion<CyclesPerByte>) {
-    let mut rng = AesState::new();
-    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
-    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+            use ntrust_native::$variant::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
+            use ntrust_native::$variant::{
+                CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES,
+            };
+            use ntrust_native::AesState;
 
-    criterion.bench_function("kem_keypair", |b| {
-        b.iter(|| {
-            crypto_kem_keypair(&mut pk, &mut sk, &mut rng).expect("crypto_kem_keypair failed!");
-        })
-    });
-}
+            pub fn bench_complete_kem(criterion: &mut Criterion<CyclesPerByte>) {
+                let mut rng = AesState::new();
+                let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+                let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+                let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+                let mut ss_alice = [0u8; CRYPTO_BYTES];
+                let mut ss_bob = [0u8; CRYPTO_BYTES];
 
-pub fn bench_kem_enc(criterion: &mut Criterion<CyclesPerByte>) {
-    let mut rng = AesState::new();
-    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
-    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
-    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
-    let mut ss_alice = [0u8; CRYPTO_BYTES];
+                criterion.bench_function(concat!(stringify!($variant), "/kem"), |b| {
+                    b.iter(|| {
+                        crypto_kem_keypair(&mut pk, &mut sk, &mut rng)
+                            .expect("crypto_kem_keypair failed!");
+                        crypto_kem_enc(&mut ct, &mut ss_alice, &pk, &mut rng)
+                            .expect("crypto_kem_enc failed!");
+                        crypto_kem_dec(&mut ss_bob, &ct, &sk).expect("crypto_kem_dec failed!");
+                        assert_eq!(ss_bob, ss_alice, "shared keys do not match");
+                    })
+                });
+            }
 
-    crypto_kem_keypair(&mut pk, &mut sk, &mut rng).expect("crypto_kem_keypair failed!");
+            pub fn bench_kem_keypair(criterion: &mut Criterion<CyclesPerByte>) {
+                let mut rng = AesState::new();
+                let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+                let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
 
-    criterion.bench_function("kem_enc", |b| {
-        b.iter(|| {
-            crypto_kem_enc(&mut ct, &mut ss_alice, &pk, &mut rng).expect("crypto_kem_enc failed!");
-        })
-    });
-}
+                criterion.bench_function(concat!(stringify!($variant), "/kem_keypair"), |b| {
+                    b.iter(|| {
+                        crypto_kem_keypair(&mut pk, &mut sk, &mut rng)
+                            .expect("crypto_kem_keypair failed!");
+                    })
+                });
+            }
+
+            pub fn bench_kem_enc(criterion: &mut Criterion<CyclesPerByte>) {
+                let mut rng = AesState::new();
+                let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+                let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+                let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+                let mut ss_alice = [0u8; CRYPTO_BYTES];
+
+                crypto_kem_keypair(&mut pk, &mut sk, &mut rng).expect("crypto_kem_keypair failed!");
+
+                criterion.bench_function(concat!(stringify!($variant), "/kem_enc"), |b| {
+                    b.iter(|| {
+                        crypto_kem_enc(&mut ct, &mut ss_alice, &pk, &mut rng)
+                            .expect("crypto_kem_enc failed!");
+                    })
+                });
+            }
+
+            /// `crypto_kem_dec` recomputes the public key with the `fault-hardened` feature
+            #[cfg(not(feature = "fault-hardened"))]
+            const KEM_DEC: &str = concat!(stringify!($variant), "/kem_dec");
+            #[cfg(feature = "fault-hardened")]
+            const KEM_DEC: &str = concat!(stringify!($variant), "/kem_dec_fault_hardened");
+
+            pub fn bench_kem_dec(criterion: &mut Criterion<CyclesPerByte>) {
+                let mut rng = AesState::new();
+                let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+                let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+                let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+                let mut ss_alice = [0u8; CRYPTO_BYTES];
+                let mut ss_bob = [0u8; CRYPTO_BYTES];
 
-pub fn bench_kem_dec(criterion: &mut Criterion<CyclesPerByte>) {
-    let mut rng = AesState::new();
-    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
-    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
-    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
-    let mut ss_alice = [0u8; CRYPTO_BYTES];
-    let mut ss_bob = [0u8; CRYPTO_BYTES];
-
-    crypto_kem_keypair(&mut pk, &mut sk, &mut rng).expect("crypto_kem_keypair failed!");
-    crypto_kem_enc(&mut ct, &mut ss_alice, &pk, &mut rng).expect("crypto_kem_enc failed!");
-
-    criterion.bench_function("kem_dec", |b| {
-        b.iter(|| {
-            crypto_kem_dec(&mut ss_bob, &ct, &sk).expect("crypto_kem_dec failed!");
-        })
-    });
+                crypto_kem_keypair(&mut pk, &mut sk, &mut rng).expect("crypto_kem_keypair failed!");
+                crypto_kem_enc(&mut ct, &mut ss_alice, &pk, &mut rng)
+                    .expect("crypto_kem_enc failed!");
+
+                criterion.bench_function(KEM_DEC, |b| {
+                    b.iter(|| {
+                        crypto_kem_dec(&mut ss_bob, &ct, &sk).expect("crypto_kem_dec failed!");
+                    })
+                });
+            }
+
+            /// Compare the implementations of the ring multiplications
+            #[cfg(feature = "avx2")]
+            pub fn bench_backends(criterion: &mut Criterion<CyclesPerByte>) {
+                use ntrust_native::{set_backend, Backend};
+
+                let mut rng = AesState::new();
+                let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+                let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+                let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+                let mut ss_alice = [0u8; CRYPTO_BYTES];
+                let mut ss_bob = [0u8; CRYPTO_BYTES];
+
+                for backend in [Backend::Portable, Backend::Avx2] {
+                    if set_backend(Some(backend)).is_err() {
+                        continue;
+                    }
+                    let name = |op: &str| format!("{}/{}/{}", stringify!($variant), op, backend);
+
+                    criterion.bench_function(&name("kem_keypair"), |b| {
+                        b.iter(|| {
+                            crypto_kem_keypair(&mut pk, &mut sk, &mut rng)
+                                .expect("crypto_kem_keypair failed!");
+                        })
+                    });
+                    criterion.bench_function(&name("kem_enc"), |b| {
+                        b.iter(|| {
+                            crypto_kem_enc(&mut ct, &mut ss_alice, &pk, &mut rng)
+                                .expect("crypto_kem_enc failed!");
+                        })
+                    });
+                    criterion.bench_function(&name("kem_dec"), |b| {
+                        b.iter(|| {
+                            crypto_kem_dec(&mut ss_bob, &ct, &sk).expect("crypto_kem_dec failed!");
+                        })
+                    });
+                }
+                set_backend(None).expect("automatic backend selection failed!");
+            }
+
+            pub fn bench(criterion: &mut Criterion<CyclesPerByte>) {
+                bench_complete_kem(criterion);
+                bench_kem_keypair(criterion);
+                bench_kem_enc(criterion);
+                bench_kem_dec(criterion);
+                #[cfg(feature = "avx2")]
+                bench_backends(criterion);
+            }
+        }
+    };
 }
 
+bench_variant!(ntruhps2048509);
+bench_variant!(ntruhps2048677);
+bench_variant!(ntruhps4096821);
+bench_variant!(ntruhrss701);
+
 criterion_group!(
     name = benches;
     config = Criterion::default().with_measurement(CyclesPerByte);
-    targets = bench_complete_kem, bench_kem_keypair, bench_kem_enc, bench_kem_dec
+    targets = ntruhps2048509::bench, ntruhps2048677::bench, ntruhps4096821::bench, ntruhrss701::bench
 );
 criterion_main!(benches);
@@ -0,0 +1,46 @@
+//! Run key generation, encapsulation and decapsulation of every variant
+//! with secrets marked as undefined for Valgrind's Memcheck.
+//!
+//! Memcheck reports every branch and memory access depending on a secret:
+//!
+//! ```bash
+//! $ cargo build --release --example ctgrind --features ctgrind
+//! $ valgrind --error-exitcode=1 target/release/examples/ctgrind
+//! ```
+//!
+//! The release build matters, since the overflow checks of debug builds branch on secrets.
+
+use ntrust_native::{ct_unpoison, running_on_valgrind, AesState, Variant};
+
+use std::error;
+
+fn main() -> Result<(), Box<dyn error::Error>> {
+    if !running_on_valgrind() {
+        eprintln!("Warning: not running on Valgrind, secret-dependent code will not be reported.");
+    }
+
+    let mut rng = AesState::new();
+    for variant in Variant::ALL {
+        let mut pk = vec![0u8; variant.public_key_len()];
+        let mut sk = vec![0u8; variant.secret_key_len()];
+        let mut ct = vec![0u8; variant.ciphertext_len()];
+        let mut ss_alice = vec![0u8; variant.shared_key_len()];
+        let mut ss_bob = vec![0u8; variant.shared_key_len()];
+
+        println!("[{}]\tRunning key generation …", variant);
+        variant.keypair(&mut pk, &mut sk, &mut rng)?;
+        println!("[{}]\tRunning encapsulation …", variant);
+        variant.encapsulate(&mut ct, &mut ss_bob, &pk, &mut rng)?;
+        println!("[{}]\tRunning decapsulation …", variant);
+        variant.decapsulate(&mut ss_alice, &ct, &sk)?;
+
+        // the shared keys are secret, declassify them for the comparison
+        ct_unpoison(&mut ss_alice[..]);
+        ct_unpoison(&mut ss_bob[..]);
+        if ss_alice != ss_bob {
+            return Err(format!("shared keys of {} differ", variant).into());
+        }
+    }
+
+    Ok(())
+}
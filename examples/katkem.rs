@@ -1,13 +1,8 @@
-use hex;
-
 use std::io::Write;
 use std::io::{BufRead, BufReader};
 use std::{env, error, fmt, fs};
 
-use ntrust_native::CRYPTO_ALGNAME;
-use ntrust_native::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
-use ntrust_native::{AesState, RNGState};
-use ntrust_native::{CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
+use ntrust_native::{AesState, RNGState, Variant};
 
 #[derive(Debug)]
 struct InvalidFileFormat(String, usize);
@@ -26,10 +21,10 @@ type R = Result<(), Box<dyn error::Error>>;
 struct Testcase {
     count: usize,
     seed: [u8; 48],
-    pk: [u8; CRYPTO_PUBLICKEYBYTES],
-    sk: [u8; CRYPTO_SECRETKEYBYTES],
-    ct: [u8; CRYPTO_CIPHERTEXTBYTES],
-    ss: [u8; CRYPTO_BYTES],
+    pk: Vec<u8>,
+    sk: Vec<u8>,
+    ct: Vec<u8>,
+    ss: Vec<u8>,
 }
 
 fn is_zero(x: &[u8]) -> bool {
@@ -41,31 +36,38 @@ fn is_zero(x: &[u8]) -> bool {
 }
 
 impl Testcase {
-    fn new() -> Testcase {
-        Testcase {
-            count: 0,
-            seed: [0u8; 48],
-            pk: [0u8; CRYPTO_PUBLICKEYBYTES],
-            sk: [0u8; CRYPTO_SECRETKEYBYTES],
-            ct: [0u8; CRYPTO_CIPHERTEXTBYTES],
-            ss: [0u8; CRYPTO_BYTES],
-        }
+    fn new(variant: Variant) -> Testcase {
+        Testcase::with_seed(variant, 0, &[0u8; 48])
     }
 
-    fn with_seed(count: usize, seed: &[u8; 48]) -> Testcase {
+    fn with_seed(variant: Variant, count: usize, seed: &[u8; 48]) -> Testcase {
         Testcase {
-            count: count,
+            count,
             seed: *seed,
-            pk: [0u8; CRYPTO_PUBLICKEYBYTES],
-            sk: [0u8; CRYPTO_SECRETKEYBYTES],
-            ct: [0u8; CRYPTO_CIPHERTEXTBYTES],
-            ss: [0u8; CRYPTO_BYTES],
+            pk: vec![0u8; variant.public_key_len()],
+            sk: vec![0u8; variant.secret_key_len()],
+            ct: vec![0u8; variant.ciphertext_len()],
+            ss: vec![0u8; variant.shared_key_len()],
         }
     }
 
+    /// Run key generation, encapsulation and decapsulation with the given RNG
+    /// storing pk, sk, ct and ss. Returns the decapsulated shared key.
+    fn run(
+        &mut self,
+        variant: Variant,
+        rng: &mut AesState,
+    ) -> Result<Vec<u8>, Box<dyn error::Error>> {
+        let mut ss = vec![0u8; variant.shared_key_len()];
+        variant.keypair(&mut self.pk, &mut self.sk, rng)?;
+        variant.encapsulate(&mut self.ct, &mut self.ss, &self.pk, rng)?;
+        variant.decapsulate(&mut ss, &self.ct, &self.sk)?;
+        Ok(ss)
+    }
+
     fn write_to_file(&self, fd: &mut fs::File) -> R {
         let repr_bytes = |bytes: &[u8]| -> String {
-            if is_zero(&bytes) {
+            if is_zero(bytes) {
                 "".to_string()
             } else {
                 hex::encode_upper(bytes)
@@ -110,10 +112,10 @@ impl Testcase {
         match name {
             "count" => self.count = value.parse::<usize>()?,
             "seed" => hex::decode_to_slice(value, &mut self.seed as &mut [u8])?,
-            "pk" => hex::decode_to_slice(value, &mut self.pk as &mut [u8])?,
-            "sk" => hex::decode_to_slice(value, &mut self.sk as &mut [u8])?,
-            "ct" => hex::decode_to_slice(value, &mut self.ct as &mut [u8])?,
-            "ss" => hex::decode_to_slice(value, &mut self.ss as &mut [u8])?,
+            "pk" => hex::decode_to_slice(value, &mut self.pk)?,
+            "sk" => hex::decode_to_slice(value, &mut self.sk)?,
+            "ct" => hex::decode_to_slice(value, &mut self.ct)?,
+            "ss" => hex::decode_to_slice(value, &mut self.ss)?,
             _ => return err(&format!("assignment to unknown key '{}'", name)),
         };
 
@@ -139,7 +141,7 @@ impl fmt::Display for Testcase {
         //   to abstract Testcase.write_to_file(…) for stdout AND files.
         //   As a result, I decided to duplicate the code.
         let repr_bytes = |bytes: &[u8]| -> String {
-            if is_zero(&bytes) {
+            if is_zero(bytes) {
                 "".to_string()
             } else {
                 hex::encode_upper(bytes)
@@ -155,19 +157,19 @@ impl fmt::Display for Testcase {
     }
 }
 
-fn create_request_file(filepath: &str, rng: &mut impl RNGState) -> R {
+fn create_request_file(variant: Variant, filepath: &str, rng: &mut AesState) -> R {
     let mut fd = fs::File::create(filepath)?;
 
     // initialize RNG
     let mut entropy_input = [0u8; 48];
-    for i in 0..48 {
-        entropy_input[i] = i as u8;
+    for (i, e) in entropy_input.iter_mut().enumerate() {
+        *e = i as u8;
     }
     rng.randombytes_init(entropy_input);
 
     // create 100 testcase seeds
     for t in 0..100 {
-        let mut tc = Testcase::new();
+        let mut tc = Testcase::new(variant);
         tc.count = t;
         rng.randombytes(&mut tc.seed)?;
 
@@ -177,30 +179,27 @@ fn create_request_file(filepath: &str, rng: &mut impl RNGState) -> R {
     Ok(())
 }
 
-fn create_response_file(filepath: &str, rng: &mut impl RNGState) -> R {
+fn create_response_file(variant: Variant, filepath: &str, rng: &mut AesState) -> R {
     let mut fd = fs::File::create(filepath)?;
-    writeln!(&mut fd, "# {}\n", CRYPTO_ALGNAME)?;
+    writeln!(&mut fd, "# {}\n", variant)?;
 
     // initialize RNG
     let mut entropy_input = [0u8; 48];
-    for i in 0..48 {
-        entropy_input[i] = i as u8;
+    for (i, e) in entropy_input.iter_mut().enumerate() {
+        *e = i as u8;
     }
     rng.randombytes_init(entropy_input);
 
     // create 100 testcase seeds
     for t in 0..100 {
-        let mut tc = Testcase::new();
+        let mut tc = Testcase::new(variant);
         tc.count = t;
         rng.randombytes(&mut tc.seed)?;
 
         let mut tc_rng = AesState::new();
         tc_rng.randombytes_init(tc.seed);
 
-        crypto_kem_keypair(&mut tc.pk, &mut tc.sk, &mut tc_rng)?;
-        crypto_kem_enc(&mut tc.ct, &mut tc.ss, &tc.pk, &mut tc_rng)?;
-        let mut ss = [0u8; CRYPTO_BYTES];
-        crypto_kem_dec(&mut ss, &tc.ct, &tc.sk)?;
+        let ss = tc.run(variant, &mut tc_rng)?;
 
         assert_eq!(tc.ss, ss);
         tc.write_to_file(&mut fd)?;
@@ -209,27 +208,25 @@ fn create_response_file(filepath: &str, rng: &mut impl RNGState) -> R {
     Ok(())
 }
 
-fn verify(filepath: &str) -> R {
+fn verify(variant: Variant, filepath: &str) -> R {
     let fd = fs::File::open(filepath)?;
     let mut reader = BufReader::new(fd);
     let mut rng = AesState::new();
 
     // first record in a response file is empty (e.g. “# ntruhps2048509\n”)
     // hence, skip it
-    let mut expected = Testcase::new();
+    let mut expected = Testcase::new(variant);
     expected.read_from_file(&mut reader)?;
 
     // create 100 testcase seeds
     for t in 0..100 {
-        let mut expected = Testcase::new();
+        let mut expected = Testcase::new(variant);
         expected.read_from_file(&mut reader)?;
 
         rng.randombytes_init(expected.seed);
 
-        let mut actual = Testcase::with_seed(t, &expected.seed);
-        crypto_kem_keypair(&mut actual.pk, &mut actual.sk, &mut rng)?;
-        crypto_kem_enc(&mut actual.ct, &mut actual.ss, &actual.pk, &mut rng)?;
-        crypto_kem_dec(&mut actual.ss, &actual.ct, &actual.sk)?;
+        let mut actual = Testcase::with_seed(variant, t, &expected.seed);
+        actual.ss = actual.run(variant, &mut rng)?;
 
         //assert_eq!(expected, actual);
         assert_eq!(
@@ -262,32 +259,45 @@ fn verify(filepath: &str) -> R {
     Ok(())
 }
 
+fn usage() {
+    eprintln!("usage: ./PQCgenKAT_kem <variant> <request:filepath> <response:filepath>");
+    eprintln!("  generate a request and response file\n");
+    eprintln!("usage: ./PQCgenKAT_kem <variant> <response:filepath>");
+    eprintln!("  verify the given response file\n");
+    eprintln!("  <variant> is one of ntruhps2048509, ntruhps2048677, ntruhps4096821, ntruhrss701");
+}
+
 fn main() -> R {
     let mut args = env::args();
-    match args.len() {
-        1 => {
-            eprintln!("usage: ./PQCgenKAT_kem <request:filepath> <response:filepath>");
-            eprintln!("  generate a request and response file\n");
-            eprintln!("usage: ./PQCgenKAT_kem <response:filepath>");
-            eprintln!("  verify the given response file\n");
-            panic!("wrong number of arguments");
+    if args.len() < 3 {
+        usage();
+        panic!("wrong number of arguments");
+    }
+
+    args.next().unwrap();
+    let name = args.next().unwrap();
+    let variant = match Variant::from_name(&name) {
+        Some(variant) => variant,
+        None => {
+            usage();
+            panic!("unknown variant '{}'", name);
         }
+    };
 
-        2 => {
-            args.next().unwrap();
+    match args.len() {
+        1 => {
             let rsp_file = args.next().unwrap();
-            verify(&rsp_file)?;
+            verify(variant, &rsp_file)?;
 
             println!("Verification successful.");
         }
 
-        3 => {
-            args.next().unwrap();
+        2 => {
             let req_file = args.next().unwrap();
             let rsp_file = args.next().unwrap();
 
-            create_request_file(&req_file, &mut AesState::new())?;
-            create_response_file(&rsp_file, &mut AesState::new())?;
+            create_request_file(variant, &req_file, &mut AesState::new())?;
+            create_response_file(variant, &rsp_file, &mut AesState::new())?;
 
             println!("request and response file created.");
         }
@@ -1,10 +1,11 @@
 //! Simple example illustrating shared key negotiation.
 
+use ntrust_native::ntruhps2048509::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
+use ntrust_native::ntruhps2048509::{
+    CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES,
+};
 use ntrust_native::AesState;
-use ntrust_native::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
-use ntrust_native::{CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
 
-use hex;
 use std::error;
 
 fn main() -> Result<(), Box<dyn error::Error>> {
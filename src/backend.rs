@@ -0,0 +1,119 @@
+//! Selection of the implementation of the ring multiplications (`avx2` feature).
+//!
+//! `poly_rq_mul`, `poly_sq_mul` and `poly_s3_mul` have a portable implementation and,
+//! on x86_64 CPUs supporting AVX2, a vectorized one. By default, the AVX2 implementation
+//! is used if `is_x86_feature_detected!("avx2")` reports support for it at runtime.
+//! `set_backend` overrides this choice for the whole process, e.g. to compare both
+//! implementations. Both compute identical results.
+
+use core::fmt;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::error::NtruError;
+
+/// Implementation of the ring multiplications
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Portable implementation (Toom-Cook-4 and Karatsuba)
+    Portable,
+    /// Vectorized implementation using AVX2 instructions (x86_64 only)
+    Avx2,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Backend::Portable => write!(f, "portable"),
+            Backend::Avx2 => write!(f, "avx2"),
+        }
+    }
+}
+
+const AUTO: u8 = 0;
+const FORCE_PORTABLE: u8 = 1;
+const FORCE_AVX2: u8 = 2;
+
+/// Backend set by `set_backend`, `AUTO` if none is set
+static OVERRIDE: AtomicU8 = AtomicU8::new(AUTO);
+
+/// Returns true if the CPU supports AVX2
+fn avx2_detected() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    return std::is_x86_feature_detected!("avx2");
+    #[cfg(not(target_arch = "x86_64"))]
+    return false;
+}
+
+/// Force the ring multiplications to use `backend`, or `None` to detect
+/// the best supported backend at runtime again.
+/// Fails with `NtruError::UnsupportedBackend` if the CPU does not support `backend`.
+pub fn set_backend(backend: Option<Backend>) -> Result<(), NtruError> {
+    let setting = match backend {
+        None => AUTO,
+        Some(Backend::Portable) => FORCE_PORTABLE,
+        Some(Backend::Avx2) if avx2_detected() => FORCE_AVX2,
+        Some(Backend::Avx2) => return Err(NtruError::UnsupportedBackend),
+    };
+    OVERRIDE.store(setting, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Returns the backend currently used by the ring multiplications
+pub fn backend() -> Backend {
+    if use_avx2() {
+        Backend::Avx2
+    } else {
+        Backend::Portable
+    }
+}
+
+/// Returns true if the ring multiplications shall use AVX2.
+/// Only returns true if the CPU supports AVX2.
+pub(crate) fn use_avx2() -> bool {
+    match OVERRIDE.load(Ordering::Relaxed) {
+        FORCE_PORTABLE => false,
+        FORCE_AVX2 => true,
+        _ => avx2_detected(),
+    }
+}
+
+/// Run `test` once with every backend supported by the CPU.
+/// The backend is global, so the runs are serialized with the other callers.
+#[cfg(test)]
+pub(crate) fn for_each_backend<E>(mut test: impl FnMut() -> Result<(), E>) -> Result<(), E> {
+    use std::sync::Mutex;
+
+    static LOCK: Mutex<()> = Mutex::new(());
+    let _guard = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+
+    let mut result = Ok(());
+    for backend in [Backend::Portable, Backend::Avx2] {
+        if set_backend(Some(backend)).is_ok() {
+            result = test();
+            if result.is_err() {
+                break;
+            }
+        }
+    }
+    set_backend(None).unwrap();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_backend() {
+        let mut used = [false; 2];
+        for_each_backend(|| {
+            let forced = backend();
+            used[forced as usize] = true;
+            assert_eq!(set_backend(Some(Backend::Avx2)).is_ok(), avx2_detected());
+            set_backend(Some(forced))
+        })
+        .unwrap();
+        assert_eq!(used, [true, avx2_detected()]);
+        assert_eq!(backend() == Backend::Avx2, avx2_detected());
+    }
+}
@@ -1,17 +1,30 @@
-use crate::api::CRYPTO_BYTES;
-use crate::params::NTRU_OWCPA_MSGBYTES;
+//! Constant-time selection and comparison of byte strings.
+//!
+//! Conditions are passed as `subtle::Choice`, which hides its value from the
+//! optimizer, so the masks cannot be turned back into branches.
 
-/* b = 1 means mov, b = 0 means don't mov*/
-pub fn cmov(r: &mut [u8; CRYPTO_BYTES], x: &[u8; NTRU_OWCPA_MSGBYTES], len: isize, b: u8) {
-    let b_temp = (!b).wrapping_add(1);
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
-    for i in 0..len as usize {
-        r[i] ^= b_temp & (x[i] ^ r[i]);
+/// Copy `x` to `r` if `b` is set, leave `r` unchanged otherwise.
+/// `r` and `x` must have the same length.
+pub fn cmov(r: &mut [u8], x: &[u8], b: Choice) {
+    debug_assert_eq!(r.len(), x.len());
+    for (ri, xi) in r.iter_mut().zip(x.iter()) {
+        ri.conditional_assign(xi, b);
     }
 }
 
+/// Returns a set `Choice` if `a` and `b` differ, an unset one if they are equal.
+/// `a` and `b` must have the same length.
+pub fn verify(a: &[u8], b: &[u8]) -> Choice {
+    debug_assert_eq!(a.len(), b.len());
+    !a.ct_eq(b)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::super::api::CRYPTO_BYTES;
+    use super::super::params::NTRU_OWCPA_MSGBYTES;
     use super::*;
 
     #[test]
@@ -20,20 +33,32 @@ mod tests {
         let mut x = [0u8; NTRU_OWCPA_MSGBYTES];
         let len = CRYPTO_BYTES.min(NTRU_OWCPA_MSGBYTES);
 
-        for i in 0..NTRU_OWCPA_MSGBYTES {
-            x[i] = i as u8;
+        for (i, xi) in x.iter_mut().enumerate() {
+            *xi = i as u8;
         }
 
-        cmov(&mut r, &x, len as isize, 0);
+        cmov(&mut r[..len], &x[..len], Choice::from(0));
 
-        for i in 0..len {
-            assert_eq!(r[i], 42);
+        for ri in r.iter().take(len) {
+            assert_eq!(*ri, 42);
         }
 
-        cmov(&mut r, &x, len as isize, 1);
+        cmov(&mut r[..len], &x[..len], Choice::from(1));
+
+        for (i, ri) in r.iter().enumerate().take(len) {
+            assert_eq!(*ri, i as u8);
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let a = [3u8; CRYPTO_BYTES];
+        let mut b = a;
+        assert_eq!(verify(&a, &b).unwrap_u8(), 0);
 
-        for i in 0..len {
-            assert_eq!(r[i], i as u8);
+        for i in 0..8 {
+            b[CRYPTO_BYTES - 1] = 3 ^ (1 << i);
+            assert_eq!(verify(&a, &b).unwrap_u8(), 1);
         }
     }
 }
@@ -1,5 +1,4 @@
 /* assume 2 <= n <= 0x40000000 */
-#[cfg(feature = "ntruhps")]
 fn int32_minmax(a: &mut i32, b: &mut i32) {
     let ab = (*b) ^ (*a);
     let mut c = (*b as i64 - *a as i64) as i32;
@@ -10,7 +9,6 @@ fn int32_minmax(a: &mut i32, b: &mut i32) {
     *(b) ^= c;
 }
 
-#[cfg(feature = "ntruhps")]
 pub fn crypto_sort_int32(x: &mut [i32]) {
     let mut top: isize = 1;
     let mut q: isize;
@@ -51,7 +49,7 @@ pub fn crypto_sort_int32(x: &mut [i32]) {
         'qp_while: while q > p {
             if j != i {
                 loop {
-                    if j == (x.len() as isize - q) as isize {
+                    if j == (x.len() as isize - q) {
                         // perform "increment" operation before continuing
                         // so infinitely looping on the same q is avoided
                         q >>= 1;
@@ -110,7 +108,6 @@ pub fn crypto_sort_int32(x: &mut [i32]) {
     }
 }
 
-#[cfg(feature = "ntruhps")]
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,14 +128,14 @@ mod tests {
 
     #[test]
     fn test_int32_minmax_boundary() {
-        let (mut a, mut b) = (std::i32::MAX, std::i32::MIN);
+        let (mut a, mut b) = (i32::MAX, i32::MIN);
         int32_minmax(&mut a, &mut b);
         assert!(a <= b);
     }
 
     #[test]
     fn test_int32_minmax_diff_31bit() {
-        let (mut a, mut b) = (0, std::i32::MAX);
+        let (mut a, mut b) = (0, i32::MAX);
         int32_minmax(&mut a, &mut b);
         assert!(a <= b);
     }
@@ -0,0 +1,142 @@
+//! Annotations for checking constant-time behaviour with Valgrind (ctgrind).
+//!
+//! With the `ctgrind` feature, `ct_poison` marks memory holding secrets as undefined
+//! by a Memcheck client request. Memcheck then reports every conditional branch and
+//! every memory access whose address depends on the secret, since they depend on
+//! undefined values. `ct_unpoison` marks values as defined again once they may
+//! become public (e.g. a public key or a ciphertext).
+//!
+//! A client request is a sequence of instructions without effect, which Valgrind
+//! recognizes while translating the program. Without Valgrind, it costs a few cycles.
+//! Without the feature or on targets other than x86_64 and aarch64, both functions do nothing.
+//!
+//! See <https://github.com/agl/ctgrind> for the idea.
+
+#[cfg(all(
+    feature = "ctgrind",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+use core::arch::asm;
+
+/// `VG_USERREQ_TOOL_BASE('M', 'C')` of `memcheck.h`
+#[cfg(feature = "ctgrind")]
+const MEMCHECK_BASE: usize = (b'M' as usize) << 24 | (b'C' as usize) << 16;
+#[cfg(feature = "ctgrind")]
+const VG_USERREQ_MAKE_MEM_UNDEFINED: usize = MEMCHECK_BASE + 1;
+#[cfg(feature = "ctgrind")]
+const VG_USERREQ_MAKE_MEM_DEFINED: usize = MEMCHECK_BASE + 2;
+#[cfg(feature = "ctgrind")]
+const VG_USERREQ_RUNNING_ON_VALGRIND: usize = 0x1001;
+
+/// Issue the client request `args[0]` with the arguments `args[1..]`.
+/// Returns `default` if the program does not run on Valgrind.
+/// The instruction sequences are the ones of `valgrind.h`.
+#[cfg(all(feature = "ctgrind", target_arch = "x86_64"))]
+fn client_request(default: usize, args: &[usize; 6]) -> usize {
+    let result;
+    // SAFETY: the rotations of rdi add up to 128 bits and `xchg rbx, rbx` has no
+    // effect, so the sequence only reads `args` if Valgrind interprets it.
+    unsafe {
+        asm!(
+            "rol rdi, 3",
+            "rol rdi, 13",
+            "rol rdi, 61",
+            "rol rdi, 51",
+            "xchg rbx, rbx",
+            inout("rdx") default => result,
+            in("rax") args.as_ptr(),
+            out("rdi") _,
+            options(nostack),
+        );
+    }
+    result
+}
+
+#[cfg(all(feature = "ctgrind", target_arch = "aarch64"))]
+fn client_request(default: usize, args: &[usize; 6]) -> usize {
+    let result;
+    // SAFETY: the rotations of x12 add up to 128 bits and `orr x10, x10, x10` has no
+    // effect, so the sequence only reads `args` if Valgrind interprets it.
+    unsafe {
+        asm!(
+            "ror x12, x12, #3",
+            "ror x12, x12, #13",
+            "ror x12, x12, #51",
+            "ror x12, x12, #61",
+            "orr x10, x10, x10",
+            inout("x3") default => result,
+            in("x4") args.as_ptr(),
+            out("x12") _,
+            options(nostack),
+        );
+    }
+    result
+}
+
+#[cfg(all(
+    feature = "ctgrind",
+    not(any(target_arch = "x86_64", target_arch = "aarch64"))
+))]
+fn client_request(default: usize, _args: &[usize; 6]) -> usize {
+    default
+}
+
+/// Mark the memory of `secret` as undefined for Memcheck
+#[cfg(feature = "ctgrind")]
+pub fn ct_poison<T: ?Sized>(secret: &T) {
+    let args = [
+        VG_USERREQ_MAKE_MEM_UNDEFINED,
+        secret as *const T as *const u8 as usize,
+        core::mem::size_of_val(secret),
+        0,
+        0,
+        0,
+    ];
+    client_request(0, &args);
+}
+
+/// Mark the memory of `public` as defined for Memcheck.
+/// It takes a mutable reference, so the compiler has to reload `public` from memory
+/// afterwards instead of reusing copies in registers, which are still undefined.
+#[cfg(feature = "ctgrind")]
+pub fn ct_unpoison<T: ?Sized>(public: &mut T) {
+    let args = [
+        VG_USERREQ_MAKE_MEM_DEFINED,
+        public as *mut T as *mut u8 as usize,
+        core::mem::size_of_val(public),
+        0,
+        0,
+        0,
+    ];
+    client_request(0, &args);
+}
+
+/// Returns `true` if the program runs on Valgrind
+#[cfg(feature = "ctgrind")]
+pub fn running_on_valgrind() -> bool {
+    client_request(0, &[VG_USERREQ_RUNNING_ON_VALGRIND, 0, 0, 0, 0, 0]) != 0
+}
+
+#[cfg(not(feature = "ctgrind"))]
+pub(crate) fn ct_poison<T: ?Sized>(_secret: &T) {}
+
+#[cfg(not(feature = "ctgrind"))]
+pub(crate) fn ct_unpoison<T: ?Sized>(_public: &mut T) {}
+
+#[cfg(all(test, feature = "ctgrind"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poison_unpoison() {
+        let mut secret = [0x5au8; 64];
+        ct_poison(&secret);
+        ct_unpoison(&mut secret);
+        // a branch on the unpoisoned bytes, which Memcheck reports if unpoisoning failed
+        assert!(secret.iter().all(|&b| b == 0x5a));
+        // without Valgrind, client requests return the default value
+        if !running_on_valgrind() {
+            assert_eq!(client_request(7, &[0, 0, 0, 0, 0, 0]), 7);
+        }
+    }
+}
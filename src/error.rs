@@ -0,0 +1,135 @@
+//! Errors returned by the KEM functions.
+//!
+//! All errors are plain values, so they can be matched on
+//! and returned without allocating.
+
+use core::error;
+use core::fmt;
+
+/// Error returned if a buffer does not have the size required by the variant
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidLength {
+    /// The number of bytes required by the variant
+    pub expected: usize,
+    /// The number of bytes provided
+    pub actual: usize,
+}
+
+impl error::Error for InvalidLength {}
+
+impl fmt::Display for InvalidLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer has invalid length {} (expected {} bytes)",
+            self.actual, self.expected
+        )
+    }
+}
+
+/// Reason why `crypto_kem_check_sk` rejects a secret key
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidSecretKey {
+    /// `f·fp ≢ 1 mod (3, Φn)`, i.e. `fp` is not the inverse of `f`
+    FpNotInverse,
+    /// `hq` is not invertible mod (q, Φn)
+    HqNotInvertible,
+    /// `hq·h ≢ 1 mod (q, Φn)`, i.e. the secret key does not belong to the public key
+    PublicKeyMismatch,
+}
+
+impl error::Error for InvalidSecretKey {}
+
+impl fmt::Display for InvalidSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidSecretKey::FpNotInverse => {
+                write!(f, "fp is not the inverse of f mod (3, Phi_n)")
+            }
+            InvalidSecretKey::HqNotInvertible => write!(f, "hq is not invertible mod (q, Phi_n)"),
+            InvalidSecretKey::PublicKeyMismatch => {
+                write!(f, "secret key does not belong to the public key")
+            }
+        }
+    }
+}
+
+/// Error returned by the functions of this crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NtruError {
+    /// The random number generator failed to provide random bytes
+    Rng,
+    /// A buffer does not have the size required by the variant
+    InvalidLength(InvalidLength),
+    /// A public key is not a valid encoding of a public key of the variant
+    InvalidPublicKey,
+    /// A secret key is inconsistent
+    InvalidSecretKey(InvalidSecretKey),
+    /// A freshly generated key pair failed the pairwise consistency test
+    PairwiseConsistency,
+    /// Redundant computations of the decapsulation disagree, e.g. due to a fault attack
+    FaultDetected,
+    /// Mapping, locking or guarding memory for a `LockedSecretKey` failed
+    LockedMemory,
+    /// The CPU does not support the backend passed to `set_backend`
+    UnsupportedBackend,
+}
+
+impl From<InvalidLength> for NtruError {
+    fn from(err: InvalidLength) -> Self {
+        NtruError::InvalidLength(err)
+    }
+}
+
+impl From<InvalidSecretKey> for NtruError {
+    fn from(err: InvalidSecretKey) -> Self {
+        NtruError::InvalidSecretKey(err)
+    }
+}
+
+impl error::Error for NtruError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            NtruError::InvalidLength(err) => Some(err),
+            NtruError::InvalidSecretKey(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for NtruError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NtruError::Rng => write!(f, "random number generator failed"),
+            NtruError::InvalidLength(err) => write!(f, "{}", err),
+            NtruError::InvalidPublicKey => write!(f, "invalid public key"),
+            NtruError::InvalidSecretKey(err) => write!(f, "invalid secret key: {}", err),
+            NtruError::PairwiseConsistency => write!(f, "pairwise consistency test failed"),
+            NtruError::FaultDetected => {
+                write!(f, "inconsistent redundant computations (fault detected)")
+            }
+            NtruError::LockedMemory => write!(f, "allocating locked memory failed"),
+            NtruError::UnsupportedBackend => write!(f, "backend not supported by the CPU"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::error::Error;
+
+    #[test]
+    fn test_invalid_length_source() {
+        let err = NtruError::from(InvalidLength {
+            expected: 32,
+            actual: 31,
+        });
+        assert_eq!(
+            err.to_string(),
+            "buffer has invalid length 31 (expected 32 bytes)"
+        );
+        assert!(err.source().is_some());
+        assert!(NtruError::Rng.source().is_none());
+    }
+}
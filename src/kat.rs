@@ -0,0 +1,91 @@
+//! Known-answer self test of a variant
+//!
+//! Reruns the first testcase (`count = 0`) of the NIST KAT files. `pk`, `sk`
+//! and `ct` are compared by their SHA3-256 digests to keep the embedded data small.
+
+use rand_core::RngCore;
+use subtle::Choice;
+
+use super::api::{
+    CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_ENCSEEDBYTES, CRYPTO_KEYPAIRSEEDBYTES,
+    CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES,
+};
+use super::cmov::verify;
+use super::kem::{crypto_kem_dec, crypto_kem_enc_derand, crypto_kem_keypair_derand, sha3_256};
+use super::params::NTRU_SAMPLE_FG_BYTES;
+use super::variant::{KAT_CT_SHA3, KAT_PK_SHA3, KAT_SK_SHA3, KAT_SS};
+use crate::rng::AesState;
+use crate::wipe::wipe;
+
+/// Seed of the first testcase, identical for all variants
+const KAT_SEED: [u8; 48] = [
+    0x06, 0x15, 0x50, 0x23, 0x4D, 0x15, 0x8C, 0x5E, 0xC9, 0x55, 0x95, 0xFE, 0x04, 0xEF, 0x7A, 0x25,
+    0x76, 0x7F, 0x2E, 0x24, 0xCC, 0x2B, 0xC4, 0x79, 0xD0, 0x9D, 0x86, 0xDC, 0x9A, 0xBC, 0xFD, 0xE7,
+    0x05, 0x6A, 0x8C, 0x26, 0x6F, 0x9E, 0xF9, 0x7E, 0xD0, 0x85, 0x41, 0xDB, 0xD2, 0xE1, 0xFF, 0xA1,
+];
+
+/// Run the embedded known-answer test: derive keypair, ciphertext and shared key from
+/// the KAT seed and compare them with the expected values.
+/// Returns `true` if all of them match.
+pub fn crypto_kem_self_test() -> bool {
+    let mut rng = AesState::new();
+    rng.randombytes_init(KAT_SEED);
+
+    // `AesState` output depends on how the bytes are requested,
+    // thus fetch them like `crypto_kem_keypair` and `crypto_kem_enc` do
+    let mut keypair_seed = [0u8; CRYPTO_KEYPAIRSEEDBYTES];
+    let mut enc_seed = [0u8; CRYPTO_ENCSEEDBYTES];
+    rng.fill_bytes(&mut keypair_seed[..NTRU_SAMPLE_FG_BYTES]);
+    rng.fill_bytes(&mut keypair_seed[NTRU_SAMPLE_FG_BYTES..]);
+
+    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+    crypto_kem_keypair_derand(&mut pk, &mut sk, &keypair_seed);
+
+    rng.fill_bytes(&mut enc_seed);
+    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+    let mut ss_enc = [0u8; CRYPTO_BYTES];
+    crypto_kem_enc_derand(&mut ct, &mut ss_enc, &pk, &enc_seed);
+
+    let mut ss_dec = [0u8; CRYPTO_BYTES];
+    let dec_ok = crypto_kem_dec(&mut ss_dec, &ct, &sk).is_ok();
+
+    let mut digest = [0u8; 32];
+    let mut fail = Choice::from(0);
+    sha3_256(&mut digest, &pk);
+    fail |= verify(&digest, &KAT_PK_SHA3);
+    sha3_256(&mut digest, &sk);
+    fail |= verify(&digest, &KAT_SK_SHA3);
+    sha3_256(&mut digest, &ct);
+    fail |= verify(&digest, &KAT_CT_SHA3);
+    fail |= verify(&ss_enc, &KAT_SS);
+    fail |= verify(&ss_dec, &KAT_SS);
+
+    wipe(&mut keypair_seed);
+    wipe(&mut enc_seed);
+    wipe(&mut sk);
+    wipe(&mut ss_enc);
+    wipe(&mut ss_dec);
+
+    dec_ok && !bool::from(fail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test() {
+        assert!(crypto_kem_self_test());
+    }
+
+    #[cfg(feature = "avx2")]
+    #[test]
+    fn test_self_test_each_backend() {
+        crate::backend::for_each_backend(|| match crypto_kem_self_test() {
+            true => Ok(()),
+            false => Err(crate::backend::backend()),
+        })
+        .unwrap();
+    }
+}
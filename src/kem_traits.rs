@@ -0,0 +1,105 @@
+//! Implementation of the traits of the RustCrypto `kem` crate
+//!
+//! `Ciphertext` is the encapsulated key, `Encapsulator` generates it for a
+//! recipient's `PublicKey` and `SecretKey` acts as decapsulator.
+//!
+//! `kem` 0.3 switched to `hybrid-array`, which has no lengths like the ciphertext
+//! sizes of NTRU. Hence, we stay with `kem` 0.2 and the `generic-array` 0.14 it
+//! requires, whose latest patch releases deprecate all of its items.
+#![allow(deprecated)]
+
+use kem::generic_array::typenum::{Unsigned, U32};
+use kem::generic_array::GenericArray;
+use kem::{Decapsulator, EncappedKey, Error, SharedSecret};
+use rand_core::{CryptoRng, RngCore};
+
+use super::api::{CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES};
+use super::kem::{crypto_kem_dec, crypto_kem_enc};
+use super::types::{Ciphertext, PublicKey, SecretKey};
+use super::variant::CiphertextSize;
+use crate::wipe::wipe;
+
+const _: () = assert!(CiphertextSize::USIZE == CRYPTO_CIPHERTEXTBYTES);
+const _: () = assert!(U32::USIZE == CRYPTO_BYTES);
+
+impl EncappedKey for Ciphertext {
+    type EncappedKeySize = CiphertextSize;
+    type SharedSecretSize = U32;
+    // NTRU does not authenticate the sender
+    type SenderPublicKey = ();
+    type RecipientPublicKey = PublicKey;
+
+    fn from_bytes(bytes: &GenericArray<u8, CiphertextSize>) -> Result<Self, Error> {
+        Ciphertext::from_bytes(&bytes[..]).map_err(|_| Error)
+    }
+}
+
+/// Encapsulates shared secrets for the owner of a `PublicKey`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Encapsulator;
+
+impl kem::Encapsulator<Ciphertext> for Encapsulator {
+    fn try_encap<R: CryptoRng + RngCore>(
+        &self,
+        csprng: &mut R,
+        recip_pubkey: &PublicKey,
+    ) -> Result<(Ciphertext, SharedSecret<Ciphertext>), Error> {
+        let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+        let mut ss = [0u8; CRYPTO_BYTES];
+        crypto_kem_enc(&mut ct, &mut ss, recip_pubkey.as_bytes(), csprng).map_err(|_| Error)?;
+        let shared_secret = SharedSecret::new(ss.into());
+        wipe(&mut ss);
+        Ok((Ciphertext::from(ct), shared_secret))
+    }
+}
+
+impl Decapsulator<Ciphertext> for SecretKey {
+    fn try_decap(&self, encapped_key: &Ciphertext) -> Result<SharedSecret<Ciphertext>, Error> {
+        let mut ss = [0u8; CRYPTO_BYTES];
+        crypto_kem_dec(&mut ss, encapped_key.as_bytes(), self.as_bytes()).map_err(|_| Error)?;
+        let shared_secret = SharedSecret::new(ss.into());
+        wipe(&mut ss);
+        Ok(shared_secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::NtruError;
+    use crate::rng::AesState;
+    use kem::Encapsulator as _;
+    use rand_core::OsRng;
+
+    use super::super::types::keypair;
+
+    /// Negotiate a key using nothing but the `kem` traits
+    fn negotiate<EK, E, D>(encapper: &E, pk: &EK::RecipientPublicKey, decapper: &D) -> bool
+    where
+        EK: EncappedKey,
+        E: kem::Encapsulator<EK>,
+        D: Decapsulator<EK>,
+    {
+        let (ek, ss_bob) = encapper.try_encap(&mut OsRng, pk).unwrap();
+        let ss_alice = decapper.try_decap(&ek).unwrap();
+        ss_alice.as_bytes() == ss_bob.as_bytes()
+    }
+
+    #[test]
+    fn test_kem_traits_roundtrip() -> Result<(), NtruError> {
+        let (pk, sk) = keypair(&mut AesState::new())?;
+        assert!(negotiate(&Encapsulator, &pk, &sk));
+        Ok(())
+    }
+
+    #[test]
+    fn test_kem_traits_agree_with_typed_api() -> Result<(), NtruError> {
+        let (pk, sk) = keypair(&mut AesState::new())?;
+        let (ct, ss_bob) = Encapsulator.try_encap(&mut OsRng, &pk).unwrap();
+
+        let ct =
+            <Ciphertext as EncappedKey>::from_bytes(GenericArray::from_slice(ct.as_ref())).unwrap();
+        assert_eq!(sk.decapsulate(&ct)?.as_bytes(), ss_bob.as_bytes());
+        Ok(())
+    }
+}
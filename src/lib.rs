@@ -3,7 +3,8 @@
 //!
 //! * NTRU is a lattice-based key encapsulation mechanism (KEM)
 //! * The implementation is based on the NTRU reference implementation of NIST round 3
-//! * The implementation does not utilize any concurrency techniques (SIMD/threading/…, except maybe auto-vectorization on your CPU)
+//! * The portable implementation does not use threads or explicit SIMD instructions. With the `avx2` feature,
+//!   AVX2 is used for the ring arithmetic if the CPU supports it (see below)
 //! * It passes the 100 testcases of the C reference implementation
 //! * It implements the NTRU-HPS (Hoffstein-Pipher-Silverman) scheme in three variants
 //! * It implements the NTRU-HRSS (Hülsing-Rijneveld-Schanck) scheme in one variant
@@ -19,22 +20,22 @@
 //! Add this to your `Cargo.toml`:
 //! ```toml
 //! [dependencies]
-//! ntrust-native = "1.0"
+//! ntrust-native = "2.0"
 //! ```
 //!
-//! To use a specific NTRU variant, you need to import it with the corresponding feature flag:
+//! All four NTRU variants are compiled into one build. Each of them lives in its own module
+//! (`ntruhps2048509`, `ntruhps2048677`, `ntruhps4096821`, `ntruhrss701`) with its own size constants.
+//! Version 1.x exported the functions and constants of the single selected variant at the crate
+//! root; import them from the variant module instead (`ntruhps2048509` was the default):
 //!
-//! ```toml
-//! [dependencies]
-//! ntrust-native = { version = "1.0", features = ["ntruhrss701"] }
-//! ```
-//!
-//!
-//! The `simple` example illustrates the API:
 //! ```rust
-//! use ntrust_native::{AesState, crypto_kem_keypair, crypto_kem_enc, crypto_kem_dec};
-//! use ntrust_native::{CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_BYTES};
+//! use ntrust_native::AesState;
+//! # #[cfg(feature = "ntruhps2048677")]
+//! use ntrust_native::ntruhps2048677::{crypto_kem_keypair, crypto_kem_enc, crypto_kem_dec};
+//! # #[cfg(feature = "ntruhps2048677")]
+//! use ntrust_native::ntruhps2048677::{CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_BYTES};
 //!
+//! # #[cfg(feature = "ntruhps2048677")]
 //! fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!   let mut rng = AesState::new();
 //!   let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
@@ -51,8 +52,133 @@
 //!   assert_eq!(ss_alice, ss_bob);
 //!   Ok(())
 //! }
+//! # #[cfg(not(feature = "ntruhps2048677"))]
+//! # fn main() {}
+//! ```
+//!
+//! The same negotiation can be written with the typed `PublicKey`, `SecretKey`, `Ciphertext`
+//! and `SharedSecret` wrappers which prevent mixing up the byte arrays:
+//!
+//! ```rust
+//! use ntrust_native::AesState;
+//! # #[cfg(feature = "ntruhps2048677")]
+//! use ntrust_native::ntruhps2048677::keypair;
+//!
+//! # #[cfg(feature = "ntruhps2048677")]
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!   let mut rng = AesState::new();
+//!   let (pk, sk) = keypair(&mut rng)?;
+//!   let (ct, ss_bob) = pk.encapsulate(&mut rng)?;
+//!   let ss_alice = sk.decapsulate(&ct)?;
+//!
+//!   assert_eq!(ss_alice, ss_bob);
+//!   Ok(())
+//! }
+//! # #[cfg(not(feature = "ntruhps2048677"))]
+//! # fn main() {}
+//! ```
+//!
+//! `SharedSecret` implements `subtle::ConstantTimeEq`, and `==` compares it in constant time too.
+//!
+//! Besides `AesState`, any RNG implementing `rand_core::RngCore + CryptoRng` (e.g. `rand_core::OsRng`)
+//! can be passed as `rng`. In turn, `AesState` implements `RngCore`, `CryptoRng` and `SeedableRng`.
+//!
+//! If the variant is only known at runtime, `Variant` provides the same functions
+//! on byte slices and checks their lengths. `Variant::ALL` lists the compiled variants,
+//! `Variant::from_name` looks one up by name (e.g. `"ntruhrss701"`):
+//!
+//! ```rust
+//! use ntrust_native::{AesState, Variant};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!   let mut rng = AesState::new();
+//!   for &variant in Variant::ALL {
+//!     let mut pk = vec![0u8; variant.public_key_len()];
+//!     let mut sk = vec![0u8; variant.secret_key_len()];
+//!     variant.keypair(&mut pk, &mut sk, &mut rng)?;
+//!
+//!     let mut ct = vec![0u8; variant.ciphertext_len()];
+//!     let mut ss_bob = vec![0u8; variant.shared_key_len()];
+//!     variant.encapsulate(&mut ct, &mut ss_bob, &pk, &mut rng)?;
+//!
+//!     let mut ss_alice = vec![0u8; variant.shared_key_len()];
+//!     variant.decapsulate(&mut ss_alice, &ct, &sk)?;
+//!
+//!     assert_eq!(ss_alice, ss_bob);
+//!     assert_eq!(Variant::from_name(variant.name()), Some(variant));
+//!   }
+//!   Ok(())
+//! }
 //! ```
 //!
+//! If you only need some of the variants, you can disable the default features and
+//! select the variants with the corresponding feature flags:
+//!
+//! ```toml
+//! [dependencies]
+//! ntrust-native = { version = "2.0", default-features = false, features = ["std", "ntruhrss701"] }
+//! ```
+//!
+//! The `kem` feature implements the `EncappedKey`, `Encapsulator` and `Decapsulator` traits
+//! of the RustCrypto `kem` crate (version 0.2) for `Ciphertext`, `Encapsulator` and `SecretKey`
+//! of each variant module. Thus, NTRU can be used by code written generically over these traits.
+//!
+//! The `zeroize` feature wipes secret keys, shared secrets, intermediate polynomials and the state
+//! of `AesState` from memory once they go out of scope.
+//!
+//! The `masked` feature adds `crypto_kem_dec_masked` to each variant module. It computes the
+//! same shared key as `crypto_kem_dec`, but keeps the secret polynomials and every value
+//! depending on them split into two random shares (first-order masking against power analysis).
+//! The masks are drawn from the RNG passed to it.
+//!
+//! The `fault-hardened` feature makes `crypto_kem_dec` decide the validity of the ciphertext
+//! three times (the regular checks, an independent implementation of them and a re-encryption)
+//! and return `NtruError::FaultDetected` instead of a key if the redundant results disagree.
+//! The secret key does not contain the public key, so the re-encryption recomputes it from `sk`
+//! by an inversion in Rq on every call. This makes `crypto_kem_dec` about four times slower.
+//!
+//! The `locked-memory` feature (Linux only) adds `LockedSecretKey` to each variant module.
+//! It stores the secret key in memory which is locked into RAM with `mlock`, excluded from core
+//! dumps and surrounded by guard pages. `crypto_kem_dec` can read the key from there directly
+//! via `as_bytes()`.
+//!
+//! The `ctgrind` feature marks secret keys, random bytes drawn from the RNG and secret
+//! polynomials as undefined for Valgrind's Memcheck (see `ct_poison`), and public keys and
+//! ciphertexts as defined again (see `ct_unpoison`). Running a program on Memcheck then reports
+//! every branch and memory access depending on a secret. The `ctgrind` example does this for
+//! all variants.
+//!
+//! The `avx2` feature (requires `std`) adds an AVX2 implementation of the multiplications
+//! in Rq, Sq and S3. It is used if `is_x86_feature_detected!("avx2")` reports support at
+//! runtime, otherwise the portable implementation is used. `set_backend` forces either
+//! implementation, `backend()` returns the one in use. Both compute identical results.
+//! Forcing `Backend::Avx2` on a CPU without AVX2 returns
+//! `NtruError::UnsupportedBackend`, `set_backend(None)` restores the runtime detection:
+//!
+//! ```rust
+//! # #[cfg(feature = "avx2")]
+//! # fn main() -> Result<(), ntrust_native::NtruError> {
+//! use ntrust_native::{backend, set_backend, Backend};
+//!
+//! set_backend(Some(Backend::Portable))?;
+//! assert_eq!(backend(), Backend::Portable);
+//! set_backend(None)?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "avx2"))]
+//! # fn main() {}
+//! ```
+//!
+//! `self_test()` reruns the first NIST known-answer test of every compiled variant and
+//! returns a per-variant pass/fail report, e.g. to check the library at startup:
+//!
+//! ```rust
+//! let report = ntrust_native::self_test();
+//! assert!(report.passed(), "self test failed: {}", report);
+//! ```
+//!
+//! Without the `std` feature, the crate is `#![no_std]` and does not allocate.
+//!
 //! ## How does one run it?
 //!
 //! This library comes with two examples:
@@ -64,37 +190,46 @@
 //! The output annotates messages with Alice/Bob to illustrate which data is processed by which party.
 //! The `katkem` example implements the classic request/response file structure which is part of the NIST PQC framework.
 //!
-//! ```bash
-//! $ cargo run --example katkem PQCkemKAT_935.req PQCkemKAT_935.rsp
-//! $ cargo run --example katkem PQCkemKAT_935.rsp
-//! ```
-//!
-//! The different variants (`ntruhps2048509, ntruhps2048677, ntruhps4096821, ntruhrss701`) can be enabled through feature flags:
+//! The first argument selects the variant (`ntruhps2048509, ntruhps2048677, ntruhps4096821, ntruhrss701`):
 //!
 //! ```bash
-//! $ cargo run --example katkem --features ntruhrss701 -- PQCkemKAT_1450.req PQCkemKAT_1450.rsp
+//! $ cargo run --example katkem ntruhps2048509 PQCkemKAT_935.req PQCkemKAT_935.rsp
+//! $ cargo run --example katkem ntruhps2048509 PQCkemKAT_935.rsp
+//! $ cargo run --example katkem ntruhrss701 PQCkemKAT_1450.req PQCkemKAT_1450.rsp
 //! ```
 //!
-//! `ntruhps2048509` is the default variant. You cannot enable two variants simultaneously.
-//!
-mod api;
-mod cmov;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// The variant modules compile the same implementation files with different parameters
+#![allow(clippy::duplicate_mod)]
+
+#[cfg(feature = "avx2")]
+mod backend;
 mod crypto_sort_int32;
-mod kem;
-mod owcpa;
-mod pack3;
-mod packq;
-mod params;
-mod poly;
-mod poly_lift;
-mod poly_mod;
-mod poly_r2_inv;
-mod poly_rq_mul;
-mod poly_s3_inv;
+mod ctgrind;
+mod error;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+mod locked;
+#[cfg(feature = "masked")]
+mod mask;
 mod rng;
-mod sample;
-mod sample_iid;
+mod self_test;
+mod variant;
+mod wipe;
+
+#[cfg(feature = "ntruhps2048509")]
+pub mod ntruhps2048509;
+#[cfg(feature = "ntruhps2048677")]
+pub mod ntruhps2048677;
+#[cfg(feature = "ntruhps4096821")]
+pub mod ntruhps4096821;
+#[cfg(feature = "ntruhrss701")]
+pub mod ntruhrss701;
 
-pub use crate::api::*;
-pub use crate::kem::*;
-pub use crate::rng::{AesState, RNGState};
+#[cfg(feature = "avx2")]
+pub use crate::backend::{backend, set_backend, Backend};
+#[cfg(feature = "ctgrind")]
+pub use crate::ctgrind::{ct_poison, ct_unpoison, running_on_valgrind};
+pub use crate::error::{InvalidLength, InvalidSecretKey, NtruError};
+pub use crate::rng::{AesSeed, AesState, RNGState};
+pub use crate::self_test::{self_test, SelfTestReport, SelfTestResult};
+pub use crate::variant::Variant;
@@ -0,0 +1,172 @@
+//! Locked and guarded memory for secret keys (`locked-memory` feature, Linux only).
+//!
+//! A `LockedBuffer` lives in its own anonymous memory mapping:
+//!
+//! ```text
+//! | guard page | data pages (locked, not dumped) | guard page |
+//! ```
+//!
+//! The data pages are locked with `mlock`, so they are never written to swap,
+//! and excluded from core dumps with `madvise(MADV_DONTDUMP)`. The guard pages
+//! are inaccessible, so reads or writes overrunning the buffer fault instead of
+//! leaking or corrupting neighbouring memory. The buffer ends right before the
+//! trailing guard page. On drop, the buffer is wiped before the pages are
+//! unlocked and unmapped.
+
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{compiler_fence, Ordering};
+
+use crate::error::NtruError;
+
+/// `N` bytes in locked memory surrounded by guard pages
+pub(crate) struct LockedBuffer<const N: usize> {
+    /// Start of the mapping, i.e. of the leading guard page
+    base: NonNull<u8>,
+    /// Size of the mapping including both guard pages
+    map_len: usize,
+    /// Size of the locked data pages
+    data_len: usize,
+    /// The buffer at the end of the data pages
+    data: NonNull<[u8; N]>,
+}
+
+// SAFETY: the buffer owns its mapping exclusively, like a `Box<[u8; N]>`
+unsafe impl<const N: usize> Send for LockedBuffer<N> {}
+// SAFETY: shared references only allow reading the buffer
+unsafe impl<const N: usize> Sync for LockedBuffer<N> {}
+
+impl<const N: usize> LockedBuffer<N> {
+    /// Map, lock and guard a new buffer of `N` zero bytes.
+    /// Returns `NtruError::LockedMemory` if one of the system calls fails,
+    /// e.g. if `RLIMIT_MEMLOCK` is exceeded.
+    pub(crate) fn new() -> Result<Self, NtruError> {
+        // SAFETY: sysconf has no preconditions
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+        if page <= 0 {
+            return Err(NtruError::LockedMemory);
+        }
+        let page = page as usize;
+        let data_len = N.div_ceil(page).max(1) * page;
+        let map_len = data_len + 2 * page;
+
+        // SAFETY: an anonymous private mapping at an address chosen by the kernel
+        // does not alias any existing memory
+        let base = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                map_len,
+                libc::PROT_NONE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            )
+        };
+        if base == libc::MAP_FAILED {
+            return Err(NtruError::LockedMemory);
+        }
+
+        // SAFETY: the data pages lie within the mapping
+        let data_pages = unsafe { base.cast::<u8>().add(page) };
+        // SAFETY: the data pages lie within the new mapping, which is unmapped
+        // again (unlocking it) if any of the calls fails
+        let failed = unsafe {
+            libc::mprotect(
+                data_pages.cast(),
+                data_len,
+                libc::PROT_READ | libc::PROT_WRITE,
+            ) != 0
+                || libc::mlock(data_pages.cast(), data_len) != 0
+                || libc::madvise(data_pages.cast(), data_len, libc::MADV_DONTDUMP) != 0
+        };
+        if failed {
+            // SAFETY: the mapping has not been handed out
+            unsafe { libc::munmap(base, map_len) };
+            return Err(NtruError::LockedMemory);
+        }
+
+        Ok(Self {
+            // SAFETY: `mmap` succeeded, so `base` is not null
+            base: unsafe { NonNull::new_unchecked(base.cast()) },
+            map_len,
+            data_len,
+            // SAFETY: the buffer ends at the end of the data pages
+            data: unsafe { NonNull::new_unchecked(data_pages.add(data_len - N).cast()) },
+        })
+    }
+
+    /// Returns the bytes of the buffer
+    pub(crate) fn as_bytes(&self) -> &[u8; N] {
+        // SAFETY: the data pages are readable and initialized (anonymous mappings are zeroed)
+        unsafe { self.data.as_ref() }
+    }
+
+    /// Returns the bytes of the buffer for writing
+    pub(crate) fn as_bytes_mut(&mut self) -> &mut [u8; N] {
+        // SAFETY: the data pages are writable and `&mut self` guarantees exclusive access
+        unsafe { self.data.as_mut() }
+    }
+
+    /// Start of the locked data pages
+    fn data_pages(&self) -> *mut u8 {
+        // SAFETY: the data pages lie between the two guard pages of the mapping
+        unsafe { self.base.as_ptr().add((self.map_len - self.data_len) / 2) }
+    }
+}
+
+impl<const N: usize> Drop for LockedBuffer<N> {
+    fn drop(&mut self) {
+        let data = self.data.as_ptr().cast::<u8>();
+        for i in 0..N {
+            // SAFETY: `data + i` lies within the buffer; volatile writes are not optimized away
+            unsafe { ptr::write_volatile(data.add(i), 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+
+        // SAFETY: the mapping is owned by `self` and not used afterwards
+        unsafe {
+            libc::munlock(self.data_pages().cast(), self.data_len);
+            libc::munmap(self.base.as_ptr().cast(), self.map_len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locked_buffer() -> Result<(), NtruError> {
+        let mut buffer = LockedBuffer::<1000>::new()?;
+        assert_eq!(buffer.as_bytes(), &[0u8; 1000]);
+
+        buffer.as_bytes_mut().fill(0x42);
+        assert_eq!(buffer.as_bytes(), &[0x42u8; 1000]);
+
+        // SAFETY: sysconf has no preconditions
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let end = buffer.as_bytes().as_ptr() as usize + 1000;
+        assert_eq!(end % page, 0, "buffer must end at the trailing guard page");
+        assert_eq!(
+            buffer.data_pages() as usize,
+            buffer.base.as_ptr() as usize + page
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_locked_buffer_flags() -> Result<(), NtruError> {
+        let buffer = LockedBuffer::<1000>::new()?;
+        let start = format!("{:x}-", buffer.data_pages() as usize);
+
+        // the VmFlags of the data pages include "lo" (locked) and "dd" (do not dump)
+        let smaps = std::fs::read_to_string("/proc/self/smaps").unwrap();
+        let flags = smaps
+            .split_once(&start)
+            .and_then(|(_, mapping)| mapping.lines().find(|l| l.starts_with("VmFlags:")))
+            .expect("mapping of the data pages");
+        let flags: Vec<&str> = flags.split_whitespace().collect();
+        assert!(flags.contains(&"lo"), "{:?}", flags);
+        assert!(flags.contains(&"dd"), "{:?}", flags);
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+//! Secret keys in locked memory (`locked-memory` feature, Linux only)
+
+use core::fmt;
+
+use super::api::{CRYPTO_BYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
+use super::kem::{crypto_kem_dec, crypto_kem_keypair};
+use super::types::{Ciphertext, PublicKey, SharedSecret};
+use crate::error::{InvalidLength, NtruError};
+use crate::locked::LockedBuffer;
+use crate::rng::RNGState;
+use crate::wipe::wipe;
+
+/// A secret key stored in memory which is locked into RAM (never swapped),
+/// excluded from core dumps and surrounded by inaccessible guard pages.
+/// It is wiped, unlocked and unmapped on drop.
+///
+/// `as_bytes()` references the locked memory itself, so `crypto_kem_dec`
+/// reads the key from there without copying it.
+pub struct LockedSecretKey(LockedBuffer<CRYPTO_SECRETKEYBYTES>);
+
+impl LockedSecretKey {
+    /// Allocate a secret key of zero bytes in locked memory.
+    /// Fails with `NtruError::LockedMemory` if the memory cannot be mapped or locked,
+    /// e.g. because `RLIMIT_MEMLOCK` is exceeded.
+    pub fn new() -> Result<Self, NtruError> {
+        Ok(Self(LockedBuffer::new()?))
+    }
+
+    /// Copy a secret key into locked memory.
+    /// Fails if `bytes` does not have the size required by this variant.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NtruError> {
+        if bytes.len() != CRYPTO_SECRETKEYBYTES {
+            return Err(InvalidLength {
+                expected: CRYPTO_SECRETKEYBYTES,
+                actual: bytes.len(),
+            }
+            .into());
+        }
+        let mut sk = Self::new()?;
+        sk.as_bytes_mut().copy_from_slice(bytes);
+        Ok(sk)
+    }
+
+    /// Returns the underlying bytes in locked memory
+    pub fn as_bytes(&self) -> &[u8; CRYPTO_SECRETKEYBYTES] {
+        self.0.as_bytes()
+    }
+
+    /// Returns the underlying bytes in locked memory for writing
+    pub fn as_bytes_mut(&mut self) -> &mut [u8; CRYPTO_SECRETKEYBYTES] {
+        self.0.as_bytes_mut()
+    }
+
+    /// Determine the shared secret from a ciphertext
+    pub fn decapsulate(&self, ct: &Ciphertext) -> Result<SharedSecret, NtruError> {
+        let mut ss = [0u8; CRYPTO_BYTES];
+        crypto_kem_dec(&mut ss, ct.as_bytes(), self.as_bytes())?;
+        let shared_secret = SharedSecret::from(ss);
+        wipe(&mut ss);
+        Ok(shared_secret)
+    }
+}
+
+/// Like `keypair`, but the secret key is generated directly into locked memory
+pub fn keypair_locked(rng: &mut impl RNGState) -> Result<(PublicKey, LockedSecretKey), NtruError> {
+    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut sk = LockedSecretKey::new()?;
+    crypto_kem_keypair(&mut pk, sk.as_bytes_mut(), rng)?;
+    Ok((PublicKey::from(pk), sk))
+}
+
+impl fmt::Debug for LockedSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LockedSecretKey([REDACTED])")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::keypair;
+    use super::*;
+    use crate::rng::AesState;
+
+    #[test]
+    fn test_locked_roundtrip() -> Result<(), NtruError> {
+        let mut rng = AesState::new();
+        let (pk, sk) = keypair_locked(&mut rng)?;
+        let (ct, ss_bob) = pk.encapsulate(&mut rng)?;
+        assert_eq!(sk.decapsulate(&ct)?, ss_bob);
+        Ok(())
+    }
+
+    #[test]
+    fn test_locked_from_bytes() -> Result<(), NtruError> {
+        let mut rng = AesState::new();
+        let (pk, sk) = keypair(&mut rng)?;
+        let (ct, ss_bob) = pk.encapsulate(&mut rng)?;
+
+        let locked = LockedSecretKey::from_bytes(sk.as_bytes())?;
+        assert_eq!(locked.as_bytes(), sk.as_bytes());
+        assert_eq!(locked.decapsulate(&ct)?, ss_bob);
+
+        let err = LockedSecretKey::from_bytes(&sk.as_bytes()[1..]).unwrap_err();
+        assert!(matches!(err, NtruError::InvalidLength(_)));
+        Ok(())
+    }
+}
@@ -0,0 +1,358 @@
+//! Gadgets for first-order masking with two shares, used by the `masked` feature.
+//!
+//! A Boolean sharing `[x0, x1]` represents `x0 ^ x1`, an arithmetic sharing
+//! represents `x0 + x1` (mod 2^16 for `u16`, mod 3 for trits). Conversions between
+//! Boolean and arithmetic masking follow Goubin, "A Sound Method for Switching
+//! between Boolean and Arithmetic Masking" (CHES 2001). Every gadget expects
+//! the shares of its inputs to be independent and draws fresh masks from `MaskRng`.
+
+use core::ops::{BitAnd, BitXor, Not};
+
+use tiny_keccak::{Hasher, Shake, Xof};
+
+use crate::error::NtruError;
+use crate::rng::RNGState;
+use crate::wipe::wipe;
+
+/// Generator for the masks, a SHAKE256 stream seeded by the caller's RNG
+pub(crate) struct MaskRng {
+    shake: Shake,
+}
+
+impl MaskRng {
+    pub fn new(rng: &mut impl RNGState) -> Result<MaskRng, NtruError> {
+        let mut seed = [0u8; 32];
+        rng.randombytes(&mut seed)?;
+        let mut shake = Shake::v256();
+        shake.update(&seed);
+        wipe(&mut seed);
+        Ok(MaskRng { shake })
+    }
+
+    pub fn u16(&mut self) -> u16 {
+        let mut buf = [0u8; 2];
+        self.shake.squeeze(&mut buf);
+        u16::from_le_bytes(buf)
+    }
+
+    pub fn u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.shake.squeeze(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    /// Uniform element of {0,1,2} (up to a bias of 2^-32)
+    pub fn trit(&mut self) -> u16 {
+        let mut buf = [0u8; 4];
+        self.shake.squeeze(&mut buf);
+        ((u32::from_le_bytes(buf) as u64 * 3) >> 32) as u16
+    }
+}
+
+/// `a & b` on Boolean sharings (domain-oriented masking with the fresh mask `r`)
+pub(crate) fn sec_and<T>(a: [T; 2], b: [T; 2], r: T) -> [T; 2]
+where
+    T: Copy + BitAnd<Output = T> + BitXor<Output = T>,
+{
+    /* the cross products must be blinded by r before they are combined */
+    let z0 = (a[0] & b[1]) ^ r;
+    let z1 = (a[1] & b[0]) ^ r;
+    [(a[0] & b[0]) ^ z0, (a[1] & b[1]) ^ z1]
+}
+
+/// `a | b` on Boolean sharings
+pub(crate) fn sec_or<T>(a: [T; 2], b: [T; 2], r: T) -> [T; 2]
+where
+    T: Copy + BitAnd<Output = T> + BitXor<Output = T> + Not<Output = T>,
+{
+    let z = sec_and([!a[0], a[1]], [!b[0], b[1]], r);
+    [!z[0], z[1]]
+}
+
+/// Convert the arithmetic sharing `x = a + r` into the Boolean sharing `x = y ^ r`.
+/// Returns `y`.
+pub(crate) fn a2b(a: u16, r: u16, rng: &mut MaskRng) -> u16 {
+    let mut g = rng.u16();
+    let mut t = g << 1;
+    let mut y = g ^ r;
+    let mut o = g & y;
+    y = t ^ a;
+    g ^= y;
+    g &= r;
+    o ^= g;
+    g = t & a;
+    o ^= g;
+    for _ in 1..16 {
+        g = t & r;
+        g ^= o;
+        t &= a;
+        g ^= t;
+        t = g << 1;
+    }
+    y ^ t
+}
+
+/// Convert the Boolean sharing `x = y ^ r` into the arithmetic sharing `x = a + r`.
+/// Returns `a`.
+pub(crate) fn b2a(y: u16, r: u16, rng: &mut MaskRng) -> u16 {
+    let mut g = rng.u16();
+    let mut t = y ^ g;
+    t = t.wrapping_sub(g);
+    t ^= y;
+    g ^= r;
+    let a = (y ^ g).wrapping_sub(g);
+    a ^ t
+}
+
+/// Convert the Boolean sharing of a bit `b = b0 ^ b1` into an arithmetic sharing mod 3.
+/// The returned shares are not reduced, but smaller than 9.
+pub(crate) fn bit_b2a_mod3(b0: u16, b1: u16, rng: &mut MaskRng) -> [u16; 2] {
+    /* b = b0 + s*b1 with s = 1 - 2*b0, where b1 is split into u + w first */
+    let u = rng.trit();
+    let w = b1 + 3 - u;
+    let s = 1 + b0;
+    [b0 + s * u, s * w]
+}
+
+/// Convert the arithmetic sharing mod 3 of a trit into Boolean sharings of
+/// the bits `l = (t == 1)` and `h = (t == 2)`. Both shares must be in {0,1,2}.
+pub(crate) fn trit_a2b(t0: u16, t1: u16, rng: &mut MaskRng) -> ([u16; 2], [u16; 2]) {
+    /* Mask the one-hot encodings of both shares independently */
+    let r = rng.u16();
+    let la = [(t0 & 1) ^ (r & 1), r & 1];
+    let ha = [(t0 >> 1) ^ ((r >> 1) & 1), (r >> 1) & 1];
+    let r = rng.u16();
+    let lb = [(t1 & 1) ^ (r & 1), r & 1];
+    let hb = [(t1 >> 1) ^ ((r >> 1) & 1), (r >> 1) & 1];
+
+    /* Add both trits: l = la ^ lb ^ ha&lb ^ la&hb ^ ha&hb */
+    /*                 h = ha ^ hb ^ la&hb ^ ha&lb ^ la&lb */
+    let r = rng.u16();
+    let ha_lb = sec_and(ha, lb, r & 1);
+    let la_hb = sec_and(la, hb, (r >> 1) & 1);
+    let ha_hb = sec_and(ha, hb, (r >> 2) & 1);
+    let la_lb = sec_and(la, lb, (r >> 3) & 1);
+
+    let mut l = [0u16; 2];
+    let mut h = [0u16; 2];
+    for i in 0..2 {
+        let cross = ha_lb[i] ^ la_hb[i];
+        l[i] = la[i] ^ lb[i] ^ cross ^ ha_hb[i];
+        h[i] = ha[i] ^ hb[i] ^ cross ^ la_lb[i];
+    }
+    (l, h)
+}
+
+/// Reduce the Boolean sharing of a `u16` to the Boolean sharing of the bit `x != 0`
+pub(crate) fn nonzero(mut x: [u16; 2], rng: &mut MaskRng) -> [u16; 2] {
+    for shift in [8, 4, 2, 1] {
+        let r = rng.u16();
+        let y = [(x[0] ^ r) >> shift, (x[1] ^ r) >> shift];
+        x = sec_or(x, y, rng.u16());
+    }
+    [x[0] & 1, x[1] & 1]
+}
+
+const KECCAK_RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+const KECCAK_RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const KECCAK_PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Keccak-f[1600] on a Boolean sharing of the state. Only chi is non-linear.
+fn keccakf_masked(state: &mut [[u64; 25]; 2], rng: &mut MaskRng) {
+    for rc in KECCAK_RC {
+        for a in state.iter_mut() {
+            /* theta */
+            let mut c = [0u64; 5];
+            for x in 0..5 {
+                for y in 0..5 {
+                    c[x] ^= a[x + 5 * y];
+                }
+            }
+            for x in 0..5 {
+                let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+                for y in 0..5 {
+                    a[x + 5 * y] ^= d;
+                }
+            }
+
+            /* rho and pi */
+            let mut last = a[1];
+            for i in 0..24 {
+                let j = KECCAK_PI[i];
+                let tmp = a[j];
+                a[j] = last.rotate_left(KECCAK_RHO[i]);
+                last = tmp;
+            }
+        }
+
+        /* chi: a[x] ^= !a[x+1] & a[x+2] */
+        for y in 0..5 {
+            let mut row = [[0u64; 5]; 2];
+            for i in 0..2 {
+                row[i].copy_from_slice(&state[i][5 * y..5 * y + 5]);
+            }
+            for x in 0..5 {
+                let b = [!row[0][(x + 1) % 5], row[1][(x + 1) % 5]];
+                let c = [row[0][(x + 2) % 5], row[1][(x + 2) % 5]];
+                let t = sec_and(b, c, rng.u64());
+                state[0][x + 5 * y] = row[0][x] ^ t[0];
+                state[1][x + 5 * y] = row[1][x] ^ t[1];
+            }
+            wipe(&mut row);
+        }
+
+        /* iota */
+        state[0][0] ^= rc;
+    }
+}
+
+/// SHA3-256 of the input given as Boolean sharing `input[0] ^ input[1]`.
+/// The input is absorbed in masked form, only the digest is unmasked.
+pub(crate) fn sha3_256_masked(output: &mut [u8; 32], input: [&[u8]; 2], rng: &mut MaskRng) {
+    const RATE: usize = 136;
+    debug_assert_eq!(input[0].len(), input[1].len());
+
+    let mut state = [[0u64; 25]; 2];
+    let len = input[0].len();
+    for offset in (0..=len).step_by(RATE) {
+        let end = if offset + RATE <= len {
+            offset + RATE
+        } else {
+            len
+        };
+        for (i, s) in state.iter_mut().enumerate() {
+            for (j, byte) in input[i][offset..end].iter().enumerate() {
+                s[j / 8] ^= (*byte as u64) << (8 * (j % 8));
+            }
+        }
+        if end - offset < RATE {
+            /* SHA3 domain separation and padding */
+            let j = end - offset;
+            state[0][j / 8] ^= 0x06 << (8 * (j % 8));
+            state[0][(RATE - 1) / 8] ^= 0x80 << (8 * ((RATE - 1) % 8));
+            keccakf_masked(&mut state, rng);
+            break;
+        }
+        keccakf_masked(&mut state, rng);
+    }
+
+    for (i, byte) in output.iter_mut().enumerate() {
+        *byte = ((state[0][i / 8] ^ state[1][i / 8]) >> (8 * (i % 8))) as u8;
+    }
+    wipe(&mut state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::AesState;
+    use tiny_keccak::Sha3;
+
+    fn mask_rng() -> MaskRng {
+        MaskRng::new(&mut AesState::new()).unwrap()
+    }
+
+    #[test]
+    fn test_a2b_b2a() {
+        let mut rng = mask_rng();
+        for _ in 0..10000 {
+            let x = rng.u16();
+            let r = rng.u16();
+            let y = a2b(x.wrapping_sub(r), r, &mut rng);
+            assert_eq!(y ^ r, x);
+            let a = b2a(x ^ r, r, &mut rng);
+            assert_eq!(a.wrapping_add(r), x);
+        }
+    }
+
+    #[test]
+    fn test_sec_and_or() {
+        let mut rng = mask_rng();
+        for _ in 0..1000 {
+            let (x, y, rx, ry) = (rng.u16(), rng.u16(), rng.u16(), rng.u16());
+            let a = [x ^ rx, rx];
+            let b = [y ^ ry, ry];
+            let z = sec_and(a, b, rng.u16());
+            assert_eq!(z[0] ^ z[1], x & y);
+            let z = sec_or(a, b, rng.u16());
+            assert_eq!(z[0] ^ z[1], x | y);
+            let z = nonzero(a, &mut rng);
+            assert_eq!(z[0] ^ z[1], (x != 0) as u16);
+        }
+    }
+
+    #[test]
+    fn test_trit_conversions() {
+        let mut rng = mask_rng();
+        for t0 in 0..3 {
+            for t1 in 0..3 {
+                let t = (t0 + t1) % 3;
+                let (l, h) = trit_a2b(t0, t1, &mut rng);
+                assert_eq!(l[0] ^ l[1], (t == 1) as u16);
+                assert_eq!(h[0] ^ h[1], (t == 2) as u16);
+            }
+        }
+        for b0 in 0..2 {
+            for b1 in 0..2 {
+                let a = bit_b2a_mod3(b0, b1, &mut rng);
+                assert_eq!((a[0] + a[1]) % 3, b0 ^ b1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sha3_256_masked() {
+        let mut rng = mask_rng();
+        let mut input = [0u8; 400];
+        let mut mask = [0u8; 400];
+        for i in 0..input.len() {
+            input[i] = i as u8;
+            mask[i] = rng.u16() as u8;
+        }
+        for len in [0, 1, 135, 136, 137, 272, 328, 400] {
+            let mut masked = [0u8; 400];
+            for i in 0..len {
+                masked[i] = input[i] ^ mask[i];
+            }
+            let mut expected = [0u8; 32];
+            let mut sha3 = Sha3::v256();
+            sha3.update(&input[..len]);
+            sha3.finalize(&mut expected);
+
+            let mut digest = [0u8; 32];
+            sha3_256_masked(&mut digest, [&masked[..len], &mask[..len]], &mut rng);
+            assert_eq!(digest, expected, "length {}", len);
+        }
+    }
+}
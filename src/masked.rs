@@ -0,0 +1,373 @@
+//! First-order masked OWCPA decryption (`masked` feature)
+//!
+//! The secret polynomials `f`, `fp` and `hq` are split into two shares right after
+//! they are unpacked from the secret key. Every intermediate value depending on them
+//! stays in shares: arithmetic shares mod q for Rq, arithmetic shares mod 3 for S3
+//! and Boolean shares for the conversions, checks and the packed message `rm`.
+//! Only the validity flag `fail` is unmasked.
+
+use subtle::Choice;
+
+use super::api::CRYPTO_SECRETKEYBYTES;
+use super::owcpa::owcpa_check_ciphertext;
+use super::pack3::poly_s3_frombytes;
+use super::packq::{poly_rq_sum_zero_frombytes, poly_sq_frombytes};
+use super::params::{
+    NTRU_HPS, NTRU_LOGQ, NTRU_N, NTRU_OWCPA_MSGBYTES, NTRU_PACK_DEG, NTRU_PACK_TRINARY_BYTES,
+    NTRU_Q, NTRU_WEIGHT,
+};
+use super::poly::{poly_s3_mul, poly_sq_mul, poly_z3_to_zq, Poly};
+use super::poly_lift::{poly_mul_x_minus_1, poly_s3_div_x_minus_1};
+use super::poly_mod::{mod3, poly_mod_3_phi_n};
+use super::poly_rq_mul::poly_rq_mul;
+use crate::mask::{a2b, b2a, bit_b2a_mod3, nonzero, sec_or, trit_a2b, MaskRng};
+use crate::wipe::wipe;
+
+/// Two shares of a polynomial
+type Shares = [Poly; 2];
+
+fn shares() -> Shares {
+    [Poly::new(), Poly::new()]
+}
+
+/// Re-randomize a Boolean sharing
+fn refresh(x: [u16; 2], rng: &mut MaskRng) -> [u16; 2] {
+    let r = rng.u16();
+    [x[0] ^ r, x[1] ^ r]
+}
+
+/// Split `a` into arithmetic shares mod q
+fn poly_split_q(r: &mut Shares, a: &Poly, rng: &mut MaskRng) {
+    for i in 0..NTRU_N {
+        r[1].coeffs[i] = rng.u16();
+        r[0].coeffs[i] = a.coeffs[i].wrapping_sub(r[1].coeffs[i]);
+    }
+}
+
+/// Split `a` with coefficients in {0,1,2} into arithmetic shares mod 3
+fn poly_split_3(r: &mut Shares, a: &Poly, rng: &mut MaskRng) {
+    for i in 0..NTRU_N {
+        r[1].coeffs[i] = rng.trit();
+        r[0].coeffs[i] = mod3(a.coeffs[i] + 3 - r[1].coeffs[i]);
+    }
+}
+
+/// Masked `poly_sq_mul`, both operands are shared
+fn poly_sq_mul_masked(r: &mut Shares, a: &Shares, b: &Shares, rng: &mut MaskRng) {
+    let t = &mut Poly::new();
+    let mask = &mut Poly::new();
+    for i in 0..NTRU_N {
+        mask.coeffs[i] = rng.u16();
+    }
+
+    /* The cross products are blinded by mask before the shares are combined */
+    poly_sq_mul(&mut r[0], &a[0], &b[0]);
+    poly_sq_mul(t, &a[0], &b[1]);
+    for i in 0..NTRU_N {
+        r[0].coeffs[i] = r[0].coeffs[i].wrapping_add(mask.coeffs[i]);
+        r[1].coeffs[i] = t.coeffs[i].wrapping_sub(mask.coeffs[i]);
+    }
+    poly_sq_mul(t, &a[1], &b[0]);
+    for i in 0..NTRU_N {
+        r[1].coeffs[i] = r[1].coeffs[i].wrapping_add(t.coeffs[i]);
+    }
+    poly_sq_mul(t, &a[1], &b[1]);
+    for i in 0..NTRU_N {
+        r[1].coeffs[i] = r[1].coeffs[i].wrapping_add(t.coeffs[i]);
+    }
+}
+
+/// Masked `poly_s3_mul`, both operands are shared
+fn poly_s3_mul_masked(r: &mut Shares, a: &Shares, b: &Shares, rng: &mut MaskRng) {
+    let t = &mut Poly::new();
+    let mask = &mut Poly::new();
+    for i in 0..NTRU_N {
+        mask.coeffs[i] = rng.trit();
+    }
+
+    /* The cross products are blinded by mask before the shares are combined */
+    poly_s3_mul(&mut r[0], &a[0], &b[0]);
+    poly_s3_mul(t, &a[0], &b[1]);
+    for i in 0..NTRU_N {
+        r[0].coeffs[i] = mod3(r[0].coeffs[i] + mask.coeffs[i]);
+        r[1].coeffs[i] = mod3(t.coeffs[i] + 2 * mask.coeffs[i]);
+    }
+    poly_s3_mul(t, &a[1], &b[0]);
+    for i in 0..NTRU_N {
+        r[1].coeffs[i] = mod3(r[1].coeffs[i] + t.coeffs[i]);
+    }
+    poly_s3_mul(t, &a[1], &b[1]);
+    for i in 0..NTRU_N {
+        r[1].coeffs[i] = mod3(r[1].coeffs[i] + t.coeffs[i]);
+    }
+}
+
+/// Masked `poly_rq_to_s3`: the coefficients are converted to Boolean shares and
+/// the centered representative mod 3 is accumulated bit by bit
+fn poly_rq_to_s3_masked(r: &mut Shares, a: &Shares, rng: &mut MaskRng) {
+    for i in 0..NTRU_N {
+        let x = [a2b(a[0].coeffs[i], a[1].coeffs[i], rng), a[1].coeffs[i]];
+        let mut s = [0u16; 2];
+        for j in 0..NTRU_LOGQ {
+            let t = bit_b2a_mod3((x[0] >> j) & 1, (x[1] >> j) & 1, rng);
+            /* 2^j mod 3, the top bit has weight -q/2 = -2^(logq-1) */
+            let mut w = 1 + (j as u16 & 1);
+            if j == NTRU_LOGQ - 1 {
+                w = 3 - w;
+            }
+            s[0] += w * t[0];
+            s[1] += w * t[1];
+        }
+        r[0].coeffs[i] = mod3(s[0]);
+        r[1].coeffs[i] = mod3(s[1]);
+    }
+    poly_mod_3_phi_n(&mut r[0]);
+    poly_mod_3_phi_n(&mut r[1]);
+}
+
+/// Arithmetic shares mod 2^16 of the indicators `l[i] = (a[i] == 1)` and
+/// `h[i] = (a[i] == 2)` of `a` given in arithmetic shares mod 3
+fn poly_s3_indicators_masked(l: &mut Shares, h: &mut Shares, a: &Shares, rng: &mut MaskRng) {
+    for i in 0..NTRU_N {
+        let (bl, bh) = trit_a2b(a[0].coeffs[i], a[1].coeffs[i], rng);
+        l[0].coeffs[i] = b2a(bl[0], bl[1], rng);
+        l[1].coeffs[i] = bl[1];
+        h[0].coeffs[i] = b2a(bh[0], bh[1], rng);
+        h[1].coeffs[i] = bh[1];
+    }
+}
+
+/// Masked `poly_lift` of `m` given in arithmetic shares mod 3
+fn poly_lift_masked(r: &mut Shares, m: &Shares, rng: &mut MaskRng) {
+    let l = &mut shares();
+    let h = &mut shares();
+    let b = &mut shares();
+    if NTRU_HPS {
+        poly_s3_indicators_masked(l, h, m, rng);
+    } else {
+        poly_s3_div_x_minus_1(&mut b[0], &m[0]);
+        poly_s3_div_x_minus_1(&mut b[1], &m[1]);
+        poly_s3_indicators_masked(l, h, b, rng);
+    }
+
+    /* z3_to_zq maps 1 to 1 and 2 to -1 */
+    for s in 0..2 {
+        for i in 0..NTRU_N {
+            b[s].coeffs[i] = l[s].coeffs[i].wrapping_sub(h[s].coeffs[i]);
+        }
+    }
+
+    if NTRU_HPS {
+        r[0].coeffs.copy_from_slice(&b[0].coeffs);
+        r[1].coeffs.copy_from_slice(&b[1].coeffs);
+    } else {
+        poly_mul_x_minus_1(&mut r[0], &b[0]);
+        poly_mul_x_minus_1(&mut r[1], &b[1]);
+    }
+}
+
+/// Masked `poly_s3_tobytes` of the trits `t` given in arithmetic shares mod 2^16.
+/// The packed bytes are returned as Boolean shares.
+fn poly_s3_tobytes_masked(msg: [&mut [u8]; 2], t: &Shares, rng: &mut MaskRng) {
+    let [msg0, msg1] = msg;
+    for (i, (m0, m1)) in msg0.iter_mut().zip(msg1.iter_mut()).enumerate() {
+        let mut p = [0u16; 2];
+        for j in (0..5).rev() {
+            if 5 * i + j < NTRU_PACK_DEG {
+                for s in 0..2 {
+                    p[s] = p[s].wrapping_mul(3).wrapping_add(t[s].coeffs[5 * i + j]);
+                }
+            }
+        }
+        *m0 = a2b(p[0], p[1], rng) as u8;
+        *m1 = p[1] as u8;
+    }
+}
+
+/// Masked `owcpa_check_r`, returns the Boolean shares of the failure bit
+fn owcpa_check_r_masked(r: &Shares, rng: &mut MaskRng) -> [u16; 2] {
+    let mut t = [0u16; 2];
+    for i in 0..NTRU_N - 1 {
+        let (c0, c1) = (r[0].coeffs[i], r[1].coeffs[i]);
+        let x = a2b(c0.wrapping_add(1), c1, rng);
+        let x = [x & (NTRU_Q as u16 - 4), c1 & (NTRU_Q as u16 - 4)];
+        t = sec_or(t, refresh(x, rng), rng.u16());
+        let y = a2b(c0.wrapping_add(2), c1, rng);
+        let y = [y & 4, c1 & 4];
+        t = sec_or(t, refresh(y, rng), rng.u16());
+    }
+    let (c0, c1) = (r[0].coeffs[NTRU_N - 1], r[1].coeffs[NTRU_N - 1]);
+    let x = [a2b(c0, c1, rng), c1];
+    t = sec_or(t, refresh(x, rng), rng.u16());
+
+    nonzero(t, rng)
+}
+
+/// Masked `owcpa_check_m` on the indicators of `m` (see `poly_s3_indicators_masked`),
+/// returns the Boolean shares of the failure bit
+fn owcpa_check_m_masked(l: &Shares, h: &Shares, rng: &mut MaskRng) -> [u16; 2] {
+    let mut ps = [0u16; 2];
+    let mut ms = [0u16; 2];
+    for s in 0..2 {
+        for i in 0..NTRU_N {
+            ps[s] = ps[s].wrapping_add(l[s].coeffs[i]);
+            ms[s] = ms[s].wrapping_add(h[s].coeffs[i] << 1);
+        }
+    }
+    let ps = [a2b(ps[0], ps[1], rng), ps[1]];
+    let ms = [a2b(ms[0], ms[1], rng), ms[1]];
+
+    let t1 = [ps[0] ^ (ms[0] >> 1), ps[1] ^ (ms[1] >> 1)];
+    let t2 = [ms[0] ^ NTRU_WEIGHT as u16, ms[1]];
+    let t = sec_or(t1, refresh(t2, rng), rng.u16());
+
+    nonzero(t, rng)
+}
+
+/// Masked `owcpa_dec`. The packed message `rm` is returned as Boolean shares,
+/// the result is the unmasked failure flag (0 on success).
+pub fn owcpa_dec_masked(
+    rm: &mut [[u8; NTRU_OWCPA_MSGBYTES]; 2],
+    ciphertext: &[u8],
+    secretkey: &[u8; CRYPTO_SECRETKEYBYTES],
+    rng: &mut MaskRng,
+) -> Choice {
+    let c = &mut Poly::new();
+    let x = &mut Poly::new();
+    let f = &mut shares();
+    let cf = &mut shares();
+    let mf = &mut shares();
+    let fp = &mut shares();
+    let m = &mut shares();
+    let l = &mut shares();
+    let h = &mut shares();
+    let b = &mut shares();
+    let hq = &mut shares();
+    let r = &mut shares();
+
+    poly_rq_sum_zero_frombytes(c, ciphertext);
+
+    let mut sk_msgbytes = [0u8; NTRU_OWCPA_MSGBYTES];
+    sk_msgbytes.copy_from_slice(&secretkey[0..NTRU_OWCPA_MSGBYTES]);
+    poly_s3_frombytes(x, sk_msgbytes);
+    poly_z3_to_zq(x);
+    poly_split_q(f, x, rng);
+
+    /* mf = c*f mod (3, Phi_n) */
+    poly_rq_mul(&mut cf[0], c, &f[0]);
+    poly_rq_mul(&mut cf[1], c, &f[1]);
+    poly_rq_to_s3_masked(mf, cf, rng);
+
+    /* m = mf*fp mod (3, Phi_n) */
+    sk_msgbytes.copy_from_slice(
+        &secretkey[NTRU_PACK_TRINARY_BYTES..NTRU_PACK_TRINARY_BYTES + NTRU_OWCPA_MSGBYTES],
+    );
+    poly_s3_frombytes(x, sk_msgbytes);
+    wipe(&mut sk_msgbytes);
+    poly_split_3(fp, x, rng);
+    poly_s3_mul_masked(m, mf, fp, rng);
+
+    poly_s3_indicators_masked(l, h, m, rng);
+    for s in 0..2 {
+        for i in 0..NTRU_N {
+            b[s].coeffs[i] = l[s].coeffs[i].wrapping_add(h[s].coeffs[i] << 1);
+        }
+    }
+    let [rm0, rm1] = rm;
+    poly_s3_tobytes_masked(
+        [
+            &mut rm0[NTRU_PACK_TRINARY_BYTES..],
+            &mut rm1[NTRU_PACK_TRINARY_BYTES..],
+        ],
+        b,
+        rng,
+    );
+
+    let mut fail = [0u16; 2];
+    if NTRU_HPS {
+        fail = owcpa_check_m_masked(l, h, rng);
+    }
+
+    /* b = c - Lift(m) mod (q, x^n - 1) */
+    poly_lift_masked(l, m, rng);
+    for i in 0..NTRU_N {
+        b[0].coeffs[i] = c.coeffs[i].wrapping_sub(l[0].coeffs[i]);
+        b[1].coeffs[i] = 0u16.wrapping_sub(l[1].coeffs[i]);
+    }
+
+    /* r = b / h mod (q, Phi_n) */
+    poly_sq_frombytes(x, &secretkey[2 * NTRU_PACK_TRINARY_BYTES..]);
+    poly_split_q(hq, x, rng);
+    wipe(&mut x.coeffs);
+    poly_sq_mul_masked(r, b, hq, rng);
+
+    let fail_r = owcpa_check_r_masked(r, rng);
+    fail = sec_or(fail, refresh(fail_r, rng), rng.u16());
+
+    /* poly_trinary_zq_to_z3 is linear on Boolean shares */
+    for i in 0..NTRU_N {
+        let y = [a2b(r[0].coeffs[i], r[1].coeffs[i], rng), r[1].coeffs[i]];
+        let mut v = [0u16; 2];
+        for s in 0..2 {
+            let z = y[s] & (NTRU_Q as u16 - 1);
+            v[s] = 3 & (z ^ (z >> (NTRU_LOGQ - 1)));
+        }
+        b[0].coeffs[i] = b2a(v[0], v[1], rng);
+        b[1].coeffs[i] = v[1];
+    }
+    poly_s3_tobytes_masked(
+        [
+            &mut rm0[..NTRU_PACK_TRINARY_BYTES],
+            &mut rm1[..NTRU_PACK_TRINARY_BYTES],
+        ],
+        b,
+        rng,
+    );
+
+    owcpa_check_ciphertext(ciphertext) | Choice::from(((fail[0] ^ fail[1]) & 1) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::api::{CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES};
+    use super::super::kem::{crypto_kem_enc, crypto_kem_keypair};
+    use super::super::owcpa::owcpa_dec;
+    use super::*;
+    use crate::rng::AesState;
+
+    fn check_dec(
+        ct: &[u8; CRYPTO_CIPHERTEXTBYTES],
+        sk: &[u8; CRYPTO_SECRETKEYBYTES],
+        rng: &mut MaskRng,
+    ) -> u8 {
+        let mut rm = [0u8; NTRU_OWCPA_MSGBYTES];
+        let fail = owcpa_dec(&mut rm, ct, sk);
+
+        let mut rm_shares = [[0u8; NTRU_OWCPA_MSGBYTES]; 2];
+        let fail_masked = owcpa_dec_masked(&mut rm_shares, ct, sk, rng);
+        for i in 0..NTRU_OWCPA_MSGBYTES {
+            assert_eq!(rm_shares[0][i] ^ rm_shares[1][i], rm[i]);
+        }
+        assert_eq!(fail_masked.unwrap_u8(), fail.unwrap_u8());
+        fail.unwrap_u8()
+    }
+
+    #[test]
+    fn test_owcpa_dec_masked() {
+        let mut rng = AesState::new();
+        let mut mask_rng = MaskRng::new(&mut rng).unwrap();
+        let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+        let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+        let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+        let mut ss = [0u8; 32];
+        crypto_kem_keypair(&mut pk, &mut sk, &mut rng).unwrap();
+        crypto_kem_enc(&mut ct, &mut ss, &pk, &mut rng).unwrap();
+
+        assert_eq!(check_dec(&ct, &sk, &mut mask_rng), 0);
+        for i in [0, 1, CRYPTO_CIPHERTEXTBYTES / 2, CRYPTO_CIPHERTEXTBYTES - 1] {
+            let mut mutated = ct;
+            mutated[i] ^= 0x04;
+            assert_eq!(check_dec(&mutated, &sk, &mut mask_rng), 1);
+        }
+    }
+}
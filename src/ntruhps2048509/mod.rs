@@ -0,0 +1,104 @@
+//! NTRU-HPS (Hoffstein-Pipher-Silverman) variant with n = 509 and q = 2048
+
+/// Constants distinguishing this variant from the other ones
+mod variant {
+    /// Name of the variant
+    pub const CRYPTO_ALGNAME: &str = "ntruhps2048509";
+    pub const NTRU_HPS: bool = true;
+    pub const NTRU_N: usize = 509;
+    pub const NTRU_LOGQ: usize = 11;
+
+    /// SHA3-256 digest of `pk` of the first NIST KAT (`count = 0`)
+    pub const KAT_PK_SHA3: [u8; 32] = [
+        0xFB, 0xCB, 0x8F, 0x45, 0xEB, 0xD7, 0x59, 0x61, 0x37, 0x2C, 0x8C, 0x62, 0x6F, 0x77, 0x64,
+        0xF8, 0x48, 0x6D, 0x2D, 0x75, 0x88, 0xAF, 0xDA, 0x39, 0xCC, 0x6E, 0xD9, 0x92, 0xFF, 0x74,
+        0x8B, 0x62,
+    ];
+    /// SHA3-256 digest of `sk` of the first NIST KAT
+    pub const KAT_SK_SHA3: [u8; 32] = [
+        0xE9, 0x1C, 0x0B, 0xCF, 0x9A, 0x48, 0x1B, 0xE2, 0x85, 0x6C, 0xDC, 0x6B, 0x7A, 0xC4, 0xC6,
+        0x59, 0x4F, 0xB4, 0xF0, 0xDC, 0x57, 0xDA, 0xFA, 0x60, 0xA6, 0xF2, 0x71, 0xBD, 0xE0, 0x3F,
+        0x11, 0x22,
+    ];
+    /// SHA3-256 digest of `ct` of the first NIST KAT
+    pub const KAT_CT_SHA3: [u8; 32] = [
+        0xCA, 0xA5, 0x41, 0xFC, 0x51, 0x5C, 0x61, 0x1A, 0x07, 0x41, 0x66, 0xC4, 0xA6, 0x33, 0x98,
+        0x11, 0x0F, 0x66, 0xC9, 0xF3, 0x62, 0xBA, 0xDF, 0xA1, 0xA1, 0xD8, 0x1A, 0xC7, 0x26, 0x42,
+        0x09, 0xA2,
+    ];
+    /// `ss` of the first NIST KAT
+    pub const KAT_SS: [u8; 32] = [
+        0x17, 0x6F, 0xDB, 0xB0, 0x09, 0xDD, 0x3F, 0x84, 0x8B, 0x36, 0x5A, 0xB7, 0xF1, 0x8D, 0x9C,
+        0x0C, 0x91, 0x72, 0x19, 0x31, 0xC8, 0x59, 0x4C, 0x2C, 0x6F, 0x04, 0x3C, 0x86, 0x00, 0x79,
+        0x1A, 0x6C,
+    ];
+
+    /// `CRYPTO_CIPHERTEXTBYTES` as type-level integer
+    #[cfg(feature = "kem")]
+    pub type CiphertextSize = kem::generic_array::typenum::U699;
+}
+
+#[path = "../api.rs"]
+mod api;
+#[path = "../cmov.rs"]
+mod cmov;
+#[path = "../kat.rs"]
+mod kat;
+#[path = "../kem.rs"]
+mod kem;
+#[cfg(feature = "kem")]
+#[path = "../kem_traits.rs"]
+mod kem_traits;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+#[path = "../locked_sk.rs"]
+mod locked_sk;
+#[cfg(feature = "masked")]
+#[path = "../masked.rs"]
+mod masked;
+#[path = "../owcpa.rs"]
+mod owcpa;
+#[path = "../pack3.rs"]
+mod pack3;
+#[path = "../packq.rs"]
+mod packq;
+#[path = "../params.rs"]
+mod params;
+#[path = "../poly.rs"]
+mod poly;
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+#[path = "../poly_avx2.rs"]
+mod poly_avx2;
+#[path = "../poly_divstep.rs"]
+mod poly_divstep;
+#[path = "../poly_lift.rs"]
+mod poly_lift;
+#[path = "../poly_mod.rs"]
+mod poly_mod;
+#[path = "../poly_r2_inv.rs"]
+mod poly_r2_inv;
+#[path = "../poly_rq_mul.rs"]
+mod poly_rq_mul;
+#[path = "../poly_s3_bitsliced.rs"]
+mod poly_s3_bitsliced;
+#[path = "../poly_s3_inv.rs"]
+mod poly_s3_inv;
+#[path = "../poly_ternary.rs"]
+mod poly_ternary;
+#[path = "../sample.rs"]
+mod sample;
+#[path = "../sample_iid.rs"]
+mod sample_iid;
+#[path = "../types.rs"]
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::api::*;
+pub use self::kat::*;
+pub use self::kem::*;
+#[cfg(feature = "kem")]
+pub use self::kem_traits::*;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+pub use self::locked_sk::*;
+pub use self::types::*;
@@ -0,0 +1,422 @@
+use std::error;
+
+use super::packq::poly_sq_tobytes;
+use super::params::{NTRU_N, NTRU_PACK_DEG};
+use super::poly::Poly;
+use super::*;
+use crate::rng::AesState;
+
+type R = Result<(), Box<dyn error::Error>>;
+
+/// Run a known-answer test with every implementation of the ring multiplications
+fn each_backend(test: fn() -> R) -> R {
+    #[cfg(feature = "avx2")]
+    return crate::backend::for_each_backend(test);
+    #[cfg(not(feature = "avx2"))]
+    test()
+}
+
+#[test]
+fn test_api_sizes() {
+    assert_eq!(CRYPTO_PUBLICKEYBYTES, 699);
+    assert_eq!(CRYPTO_SECRETKEYBYTES, 935);
+    assert_eq!(CRYPTO_CIPHERTEXTBYTES, 699);
+    assert_eq!(CRYPTO_BYTES, 32);
+}
+
+#[test]
+fn test_keypair_zeroed_rng() -> R {
+    each_backend(keypair_zeroed_rng)
+}
+
+fn keypair_zeroed_rng() -> R {
+    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+    let mut rng = AesState::new();
+
+    crypto_kem_keypair(&mut pk, &mut sk, &mut rng)?;
+
+    let pk_ref = [
+        0x73, 0xD3, 0xFD, 0x5B, 0x9C, 0xA4, 0xB1, 0xD4, 0x14, 0x8F, 0x1A, 0xF0, 0x38, 0xFD, 0xD7,
+        0xF2, 0x2F, 0xD3, 0x8D, 0x95, 0xAD, 0x88, 0x8E, 0xE1, 0xF8, 0x9A, 0xF7, 0x23, 0xAD, 0x9F,
+        0x59, 0x16, 0x92, 0x5F, 0x7E, 0x21, 0xE7, 0x71, 0xD1, 0xBB, 0x39, 0x4D, 0x5B, 0x10, 0x09,
+        0x33, 0xF5, 0x60, 0x16, 0xC1, 0x15, 0x30, 0x02, 0x5B, 0xB4, 0x2A, 0xE9, 0x89, 0x19, 0x25,
+        0x6D, 0xF3, 0x9D, 0xAB, 0xC2, 0x4B, 0x93, 0x32, 0x3F, 0xBE, 0x53, 0xD6, 0x73, 0xC4, 0xA8,
+        0xD8, 0x11, 0x2C, 0xF2, 0x55, 0xE3, 0xF4, 0xA5, 0xE1, 0x26, 0xCD, 0xA8, 0xDD, 0x84, 0x07,
+        0x8C, 0x69, 0x51, 0x0A, 0x6F, 0x77, 0x99, 0x09, 0xD4, 0x79, 0x7A, 0x90, 0xB1, 0x56, 0x17,
+        0x64, 0xC9, 0x2E, 0xEE, 0x0C, 0x5F, 0x66, 0x04, 0x33, 0x72, 0x43, 0x42, 0xE8, 0x64, 0x82,
+        0xF1, 0x87, 0xA9, 0xC2, 0x97, 0xB4, 0x54, 0x6B, 0x70, 0x08, 0x46, 0x58, 0x75, 0x00, 0x1D,
+        0x5A, 0x53, 0x82, 0xBE, 0xA8, 0x39, 0x29, 0xF5, 0x51, 0x46, 0xD9, 0x23, 0x37, 0x74, 0xD5,
+        0xA5, 0x68, 0x02, 0x75, 0xF5, 0x4B, 0x78, 0x86, 0x72, 0x5C, 0xDE, 0x11, 0x75, 0x7C, 0xF9,
+        0xD0, 0x19, 0xA9, 0xE2, 0x8C, 0x4A, 0x22, 0xA3, 0xE9, 0xA1, 0xBB, 0x80, 0x23, 0x6C, 0xCC,
+        0x4B, 0x55, 0xE2, 0x10, 0x03, 0xF7, 0x2C, 0x0A, 0xAF, 0x1B, 0x10, 0xB9, 0x90, 0xD1, 0xF3,
+        0xA6, 0xE7, 0x82, 0x74, 0x66, 0x3E, 0xEB, 0xD4, 0xBE, 0x35, 0x07, 0x4C, 0xD3, 0x07, 0x0C,
+        0x45, 0xAB, 0x4D, 0xF2, 0x1C, 0xBF, 0x6B, 0xEC, 0xEA, 0x75, 0x00, 0x8C, 0x19, 0xB7, 0x36,
+        0x97, 0x00, 0x56, 0xD8, 0xFA, 0x15, 0x2E, 0x8E, 0x27, 0x7A, 0xF2, 0xF6, 0x07, 0x75, 0xF5,
+        0xA6, 0x3D, 0x38, 0xCB, 0xD7, 0x50, 0x22, 0xE3, 0x1E, 0x6F, 0xDE, 0xA1, 0xE8, 0x42, 0x8D,
+        0x6B, 0xF8, 0x7F, 0x99, 0xA1, 0x74, 0xA9, 0xC6, 0xA7, 0x8F, 0xD8, 0xDE, 0x84, 0xA3, 0xA4,
+        0xDF, 0xE4, 0x15, 0xB6, 0x19, 0xBC, 0x23, 0x3E, 0xBC, 0x89, 0xD8, 0x8C, 0x58, 0xDA, 0xE7,
+        0x8F, 0xE3, 0x44, 0xE7, 0x7D, 0x55, 0x10, 0x7F, 0xA2, 0x77, 0x8B, 0x27, 0x35, 0xAC, 0x13,
+        0x6A, 0xCC, 0xEB, 0x68, 0xC3, 0x2E, 0x82, 0xF5, 0xEA, 0x9B, 0xE3, 0x27, 0x3B, 0x6F, 0x70,
+        0x83, 0x55, 0xA3, 0x9B, 0x98, 0x32, 0x0D, 0x1A, 0x83, 0x80, 0xB7, 0xF8, 0x96, 0x74, 0xC4,
+        0xE8, 0xAD, 0x98, 0x64, 0x8E, 0x86, 0x87, 0x77, 0x99, 0x61, 0xE0, 0x13, 0xE4, 0x4C, 0x39,
+        0x83, 0x5E, 0x8A, 0xA8, 0x7A, 0x54, 0x8B, 0xE2, 0x99, 0x3F, 0x25, 0x8A, 0x20, 0x0E, 0x4A,
+        0xCC, 0xCC, 0x51, 0xF0, 0x7C, 0xD7, 0xEA, 0x4A, 0xAF, 0xD4, 0x26, 0x2C, 0xE0, 0xB5, 0x2C,
+        0xFC, 0x74, 0x21, 0xFE, 0x91, 0xB2, 0x1C, 0x44, 0x00, 0x02, 0x07, 0x61, 0x0B, 0x7E, 0x58,
+        0x71, 0x20, 0xBC, 0x79, 0x59, 0x98, 0xEC, 0xD2, 0x09, 0x1F, 0x40, 0x88, 0x36, 0x98, 0x40,
+        0xC4, 0x69, 0x21, 0xE6, 0xE1, 0x1F, 0x86, 0x84, 0x64, 0xFA, 0xCF, 0xE3, 0x1F, 0xF8, 0x70,
+        0xC7, 0xDE, 0x54, 0x34, 0x1E, 0xBE, 0x68, 0x27, 0x62, 0xC7, 0x63, 0x4D, 0x3F, 0x52, 0xDA,
+        0xA5, 0x57, 0x24, 0x22, 0xEB, 0x09, 0x0E, 0x8B, 0xB1, 0xB7, 0xB1, 0x33, 0x40, 0x1E, 0x66,
+        0xC8, 0xFB, 0x70, 0xDB, 0x39, 0xB4, 0xCF, 0x0E, 0x51, 0x91, 0x52, 0x71, 0xE1, 0x03, 0x70,
+        0x9D, 0x42, 0x02, 0xC0, 0x8F, 0x94, 0x0D, 0x24, 0x8F, 0xE8, 0x6F, 0x20, 0xA1, 0x3B, 0x60,
+        0xF5, 0xA8, 0x81, 0x36, 0x81, 0xB0, 0xC7, 0x52, 0x35, 0x7B, 0x7B, 0x4C, 0x65, 0x30, 0xA1,
+        0xA4, 0x41, 0xD6, 0xDE, 0x5B, 0xBD, 0xD9, 0x09, 0xE1, 0x6E, 0xBC, 0xB4, 0xAC, 0x72, 0xB8,
+        0xCF, 0x1D, 0x79, 0xD1, 0x3A, 0x0D, 0x00, 0xA1, 0x81, 0xA8, 0x01, 0x8B, 0xB3, 0xB3, 0x84,
+        0x19, 0x10, 0xAB, 0xA3, 0xE2, 0xB7, 0x18, 0x73, 0xCB, 0x54, 0xCC, 0x72, 0xA7, 0x1C, 0xFE,
+        0x16, 0xC5, 0xE2, 0xC9, 0xE8, 0x44, 0xFA, 0x29, 0xBE, 0x4C, 0x37, 0x79, 0x0B, 0xC0, 0x92,
+        0x98, 0xA9, 0x91, 0x0F, 0xE3, 0x94, 0xC4, 0xB5, 0x8C, 0x2B, 0xDE, 0xC2, 0x9C, 0xCD, 0x37,
+        0x83, 0x8C, 0x9F, 0x1D, 0x44, 0xDA, 0x07, 0xD7, 0x72, 0x03, 0xB2, 0x6E, 0x4C, 0xA7, 0x81,
+        0x2F, 0x5E, 0xFC, 0xE8, 0xA1, 0x81, 0x95, 0x65, 0xF7, 0x95, 0x38, 0x07, 0xAE, 0xD0, 0x97,
+        0xD6, 0x8E, 0x1A, 0xD3, 0x32, 0xB2, 0x2B, 0x14, 0xC5, 0x95, 0xEA, 0xFB, 0x39, 0xAB, 0x86,
+        0xF2, 0x0D, 0xB4, 0xD3, 0xFA, 0xF5, 0x31, 0xDE, 0xFD, 0x8F, 0xD8, 0x86, 0xD3, 0xC6, 0xCF,
+        0xDD, 0x8C, 0x0F, 0x0A, 0xF4, 0x22, 0xAA, 0x4B, 0x47, 0x97, 0xC4, 0x34, 0x08, 0xA5, 0xC9,
+        0x83, 0xF9, 0x87, 0x13, 0xBA, 0xD3, 0x52, 0x82, 0x43, 0x0C, 0x9F, 0xD2, 0x27, 0x6E, 0xC3,
+        0x5B, 0xD0, 0xFB, 0x18, 0x01, 0x6E, 0x50, 0x50, 0xEF, 0xEB, 0x19, 0xAF, 0x69, 0x05, 0x17,
+        0xA1, 0xCE, 0x78, 0x6B, 0x07, 0x87, 0xC3, 0xBF, 0xF1, 0x5F, 0x68, 0xF5, 0x13, 0xDD, 0x9A,
+        0x86, 0xF1, 0xF6, 0x4A, 0x62, 0x63, 0x4A, 0x36, 0x0C,
+    ];
+    assert_eq!(pk_ref, pk);
+    let sk_ref = [
+        0x89, 0x2D, 0x93, 0x85, 0x9B, 0x0F, 0x01, 0xAF, 0x2F, 0xE7, 0x4B, 0x45, 0xBF, 0x59, 0xC7,
+        0x6E, 0x31, 0xAB, 0x85, 0xCD, 0xB1, 0x56, 0xC5, 0x46, 0x8F, 0x65, 0xC6, 0xA2, 0x57, 0x11,
+        0x5B, 0xC4, 0x59, 0x72, 0x3B, 0x1F, 0xBD, 0xEE, 0x8C, 0x61, 0xEE, 0x6D, 0x51, 0x7B, 0xB7,
+        0xA5, 0x26, 0x81, 0x5C, 0x8B, 0x74, 0xC8, 0x31, 0x35, 0x66, 0xCC, 0x51, 0xC2, 0x5D, 0xD5,
+        0x87, 0x69, 0x10, 0x62, 0x4F, 0xCF, 0xE0, 0x37, 0xDB, 0x7B, 0x10, 0xC9, 0x94, 0x98, 0x2B,
+        0x95, 0xD0, 0xAA, 0x20, 0x89, 0x3E, 0x4F, 0x67, 0x39, 0x74, 0xC5, 0x7E, 0xA9, 0x33, 0xD8,
+        0x8C, 0x8F, 0x3D, 0xBD, 0x9E, 0x98, 0x70, 0xD2, 0x09, 0x1B, 0x32, 0x08, 0xE9, 0x51, 0x27,
+        0x15, 0x1E, 0x0D, 0x40, 0x29, 0x33, 0xC2, 0xD8, 0xE5, 0xA4, 0x0E, 0x6C, 0x4F, 0x33, 0x0D,
+        0x60, 0xA8, 0x5F, 0x8C, 0x15, 0xCA, 0x37, 0xD8, 0xC9, 0x69, 0x5D, 0xD2, 0x14, 0x9A, 0x49,
+        0xE9, 0xEF, 0x1B, 0x29, 0x65, 0x2C, 0xEB, 0x46, 0xF2, 0x00, 0x92, 0xE3, 0x87, 0x10, 0x47,
+        0x82, 0xDA, 0x94, 0xB7, 0xBA, 0x59, 0xD2, 0x96, 0x4A, 0xE8, 0x6F, 0x3A, 0x23, 0x24, 0x57,
+        0x0F, 0xE8, 0x03, 0x49, 0x4D, 0x7A, 0xD0, 0x41, 0x8C, 0x6B, 0x31, 0xDB, 0x6A, 0x04, 0x4E,
+        0x59, 0x8E, 0xB4, 0x54, 0x4A, 0xE6, 0x03, 0x49, 0x52, 0xD5, 0x48, 0xAC, 0x2D, 0x45, 0x71,
+        0x03, 0x0E, 0xDC, 0x0D, 0x28, 0xB9, 0xB5, 0x71, 0x12, 0x97, 0xED, 0xCD, 0x21, 0x23, 0x42,
+        0x89, 0xA2, 0x0E, 0x49, 0x85, 0x56, 0x2F, 0x9C, 0xF4, 0x04, 0x6E, 0x66, 0x12, 0xD1, 0x0D,
+        0xA8, 0x96, 0xBC, 0x5C, 0x86, 0x87, 0xF6, 0xEB, 0xEE, 0xD4, 0x08, 0x93, 0x57, 0x4D, 0xC0,
+        0x46, 0x3A, 0xA2, 0x3B, 0xCE, 0xC7, 0x2C, 0x8C, 0xDE, 0xF4, 0xBF, 0x2C, 0x50, 0xC6, 0xA3,
+        0x4F, 0x05, 0xA1, 0x71, 0x52, 0x51, 0xF8, 0x74, 0x2F, 0x52, 0xD0, 0x7D, 0x44, 0xA4, 0x55,
+        0x35, 0x73, 0xD9, 0x6E, 0x79, 0x1D, 0x9F, 0x79, 0xB7, 0x61, 0x72, 0x26, 0x7C, 0x8C, 0xDF,
+        0x27, 0x5C, 0xDC, 0x01, 0x03, 0x59, 0xA4, 0x39, 0x7E, 0xEB, 0x57, 0x37, 0xA5, 0xD0, 0x6F,
+        0xE1, 0xC0, 0x7C, 0x67, 0xFD, 0xD4, 0xCF, 0x05, 0xF6, 0x5F, 0x21, 0x96, 0x18, 0xE9, 0xAA,
+        0x8D, 0x7E, 0xD1, 0x46, 0xA2, 0x9B, 0xCA, 0xBB, 0x8D, 0x23, 0xE0, 0x26, 0x6B, 0x10, 0x09,
+        0x1F, 0x0C, 0xF0, 0xC5, 0x81, 0x89, 0x4A, 0x02, 0x41, 0x3B, 0xF3, 0x5D, 0xD3, 0x69, 0x38,
+        0xC6, 0xF5, 0xB4, 0x58, 0x78, 0x17, 0x25, 0x18, 0x33, 0x27, 0x71, 0x71, 0x06, 0x8A, 0x75,
+        0x5B, 0x3C, 0x77, 0xFC, 0x8B, 0x8F, 0x1B, 0x8C, 0xF7, 0xE0, 0xE2, 0x93, 0xBD, 0x79, 0x75,
+        0x8F, 0x5B, 0x8F, 0xFD, 0x4A, 0xC1, 0x42, 0xA6, 0x0C, 0x6A, 0xA4, 0x5F, 0xEC, 0x1D, 0x71,
+        0x85, 0x01, 0x8F, 0x9F, 0x99, 0x26, 0x97, 0x85, 0x7C, 0x9C, 0x99, 0x09, 0x11, 0xA3, 0x04,
+        0x97, 0x16, 0xB4, 0xE4, 0xEC, 0x96, 0x59, 0x7F, 0x71, 0x61, 0x7A, 0xDD, 0x60, 0xE4, 0xCC,
+        0x96, 0xE8, 0xD9, 0x0A, 0x7C, 0x7D, 0x6C, 0xA1, 0x09, 0xB0, 0x4F, 0x4B, 0x68, 0x12, 0xBD,
+        0xA2, 0x33, 0x01, 0xCB, 0xA6, 0xE0, 0xD0, 0xCF, 0xF3, 0x98, 0x41, 0x47, 0x35, 0x60, 0xE8,
+        0xFE, 0x80, 0xE5, 0xB1, 0xF0, 0x45, 0x98, 0x12, 0x36, 0x98, 0xDD, 0x34, 0xB9, 0x56, 0xC6,
+        0x69, 0x5D, 0x66, 0x5D, 0x61, 0x21, 0x0D, 0xCC, 0x57, 0xC7, 0xEB, 0x3B, 0xEC, 0x31, 0x9E,
+        0x9D, 0xD7, 0x04, 0x99, 0x2A, 0x3C, 0x8A, 0xE2, 0x41, 0x78, 0x5E, 0x02, 0xC5, 0xED, 0xE4,
+        0x54, 0x21, 0x7D, 0xA1, 0xE7, 0x7F, 0xD5, 0x0E, 0xDB, 0x56, 0xFD, 0x28, 0x38, 0x0D, 0xA7,
+        0x0E, 0x05, 0xBE, 0x1E, 0x20, 0xBE, 0x09, 0x88, 0xCB, 0x62, 0xA9, 0xC0, 0xAE, 0x78, 0xD0,
+        0xE2, 0xF0, 0x1A, 0x80, 0x8B, 0xF6, 0x59, 0x03, 0xA4, 0x1C, 0x7C, 0x78, 0x04, 0x40, 0x21,
+        0x00, 0xE6, 0xB4, 0xC5, 0x8B, 0x5F, 0x92, 0x93, 0x96, 0xA0, 0xC5, 0x87, 0x84, 0x7A, 0xF6,
+        0x68, 0xCC, 0xB3, 0x66, 0xC8, 0x30, 0xC9, 0xD4, 0x40, 0xDF, 0x1E, 0xCB, 0xE9, 0x98, 0x96,
+        0x71, 0xC2, 0x60, 0xCB, 0xCE, 0xEA, 0x94, 0x64, 0x00, 0x78, 0x13, 0x2F, 0xEB, 0x40, 0xAC,
+        0xE4, 0xA4, 0xF4, 0x0A, 0x05, 0x0A, 0x11, 0x8C, 0x6D, 0x8F, 0x5E, 0xCF, 0x94, 0x4A, 0x56,
+        0x8C, 0x6D, 0x9A, 0x74, 0x1A, 0x77, 0xC1, 0xAB, 0x0E, 0x42, 0x69, 0xC2, 0x8B, 0x52, 0xAF,
+        0xD1, 0x10, 0x4B, 0x29, 0xF2, 0x0D, 0x6E, 0x65, 0x1F, 0x6B, 0xB3, 0xBD, 0x41, 0xFA, 0xE2,
+        0xF6, 0x0C, 0x81, 0xD5, 0xF1, 0x73, 0xAE, 0xD8, 0xB9, 0x1C, 0xD7, 0x2E, 0x84, 0xC9, 0x7B,
+        0x7A, 0x62, 0x01, 0xD9, 0xFA, 0x65, 0xCB, 0xD7, 0x1D, 0x56, 0x25, 0xEE, 0x22, 0x6E, 0x03,
+        0x09, 0x77, 0xEB, 0x0B, 0x27, 0x36, 0x78, 0x48, 0xAE, 0x2B, 0x86, 0xA3, 0x63, 0xCF, 0x3C,
+        0x78, 0xF4, 0x6E, 0x42, 0x91, 0x07, 0xDE, 0x9B, 0xF7, 0x79, 0x57, 0xE0, 0x10, 0x07, 0x02,
+        0xA1, 0x66, 0xD1, 0x76, 0x97, 0x21, 0xD9, 0x0F, 0x7C, 0x8C, 0x17, 0xEE, 0x4E, 0x7C, 0x3E,
+        0x95, 0x50, 0xEA, 0x3A, 0x35, 0x17, 0xA3, 0x43, 0x04, 0x74, 0x4B, 0xFD, 0x94, 0xB4, 0x85,
+        0x98, 0xF6, 0xCA, 0x22, 0x2A, 0x9C, 0x62, 0xAF, 0x53, 0xD2, 0xA4, 0x07, 0x32, 0x84, 0xCC,
+        0x01, 0x7A, 0xD4, 0xCC, 0x63, 0xFF, 0xE1, 0x23, 0x9F, 0xEC, 0x0F, 0x56, 0x98, 0x86, 0x6C,
+        0x2B, 0xC1, 0x85, 0x3F, 0xE5, 0x7E, 0x83, 0x84, 0x80, 0xAC, 0x5A, 0x03, 0x7C, 0x79, 0x98,
+        0x3E, 0x4A, 0x16, 0x71, 0x29, 0xC5, 0xB2, 0x63, 0x69, 0x3D, 0x10, 0xDC, 0xF7, 0x7B, 0x94,
+        0x5D, 0x61, 0x06, 0xE0, 0x25, 0x08, 0x7E, 0x1E, 0xE8, 0x7B, 0xED, 0x85, 0xE5, 0x0F, 0x2A,
+        0xC9, 0x93, 0xDF, 0xBA, 0x36, 0xE7, 0xED, 0x32, 0xF9, 0x6B, 0xC3, 0xD3, 0x9B, 0xC2, 0x9E,
+        0x7E, 0x76, 0x90, 0xE4, 0xBF, 0x5C, 0x19, 0x68, 0x9A, 0x32, 0x84, 0x96, 0x4C, 0x36, 0x02,
+        0xB4, 0x16, 0x1D, 0xC5, 0x9F, 0x9E, 0xBD, 0x18, 0xB9, 0x6D, 0x9C, 0xCA, 0x88, 0x03, 0x9E,
+        0x1F, 0xAA, 0x47, 0x26, 0x63, 0xB2, 0xB4, 0x4C, 0xB4, 0xB9, 0xA2, 0x44, 0xE4, 0x02, 0x75,
+        0x8A, 0xB3, 0xB9, 0x1C, 0xEA, 0x02, 0xA3, 0xE4, 0xBF, 0x0C, 0x08, 0x4E, 0xD5, 0x64, 0xB8,
+        0xB8, 0x44, 0x48, 0x5A, 0xCD, 0x7A, 0x0C, 0x62, 0x0A, 0x96, 0x05, 0x3E, 0xD7, 0x40, 0x57,
+        0xF2, 0xF7, 0x63, 0xB1, 0x9A, 0x21, 0xE0, 0x9F, 0xD0, 0xA4, 0x12, 0x78, 0xE1, 0x79, 0xCE,
+        0xAD, 0xBF, 0x03, 0xF0, 0xE7, 0x27, 0x63, 0x3A, 0xD1, 0x99, 0xAC, 0x5B, 0x47, 0xFE, 0x8A,
+        0x70, 0xBD, 0x4D, 0xB4, 0x0E, 0x3F, 0x4C, 0xE5, 0xEF, 0x10, 0x8D, 0xF8, 0x55, 0x58, 0xDC,
+        0xCA, 0x3B, 0xE8, 0x59, 0x84,
+    ];
+    assert_eq!(sk_ref, sk);
+
+    Ok(())
+}
+
+#[test]
+fn test_enc_kat1() -> R {
+    each_backend(enc_kat1)
+}
+
+fn enc_kat1() -> R {
+    let pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut rng = AesState::new();
+    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+    let mut ss = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_enc(&mut ct, &mut ss, &pk, &mut rng)?;
+
+    let ct_ref = [
+        0x00, 0xF8, 0x3F, 0x00, 0xFE, 0xFF, 0xFF, 0x00, 0x00, 0x20, 0x00, 0x01, 0x08, 0x00, 0x00,
+        0x02, 0x00, 0x80, 0xFF, 0x03, 0x20, 0x00, 0xFF, 0xFF, 0x3F, 0x00, 0xFE, 0x0F, 0x80, 0xFF,
+        0x03, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x40, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x40, 0x00, 0xFE,
+        0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x40, 0x00, 0xFE, 0x0F, 0x80, 0x00, 0xFC,
+        0x1F, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xF8,
+        0x7F, 0x00, 0x00, 0x10, 0x80, 0xFF, 0x03, 0x00, 0x00, 0x00, 0xF8, 0x3F, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0xFF, 0xFF, 0x3F, 0x00, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x3F,
+        0x00, 0x00, 0x08, 0xC0, 0xFF, 0x01, 0xF0, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0xC0,
+        0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x00, 0xF8, 0x3F, 0x00, 0x00, 0xF0, 0x7F,
+        0x00, 0x04, 0x20, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x01, 0xF0, 0xFF, 0x00, 0x04, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x00, 0xF8, 0x7F, 0x00,
+        0x00, 0x10, 0x80, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x10, 0x80, 0xFF,
+        0x03, 0x00, 0x00, 0x01, 0x08, 0x40, 0x00, 0x00, 0x10, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x01,
+        0xF8, 0xFF, 0xFF, 0xFF, 0x0F, 0x80, 0xFF, 0xFF, 0x1F, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0x01,
+        0x00, 0x00, 0x00, 0x04, 0x20, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x1F, 0x00, 0x01, 0x00,
+        0xC0, 0xFF, 0x01, 0x00, 0x80, 0xFF, 0xFF, 0x1F, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0x01, 0x10,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x01, 0xF8, 0xFF, 0xFF, 0x01, 0x10, 0x80, 0xFF, 0x03, 0x20,
+        0x00, 0xFF, 0xFF, 0x3F, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x01, 0xF8, 0x3F,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x04, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xF0, 0xFF,
+        0x00, 0x00, 0x00, 0x00, 0xFF, 0x07, 0xC0, 0xFF, 0xFF, 0x0F, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xF0, 0xFF, 0xFF, 0x03, 0x20, 0x00, 0xFF, 0x0F, 0xC0, 0xFF, 0xFF, 0x0F, 0x80, 0xFF,
+        0x07, 0x00, 0x00, 0x01, 0x00, 0x40, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xF8, 0x7F, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0x00, 0x20, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0x01,
+        0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0xFC,
+        0x1F, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x01, 0xF8,
+        0x7F, 0x00, 0x00, 0x00, 0x80, 0xFF, 0x03, 0x20, 0x00, 0x00, 0x08, 0x00, 0x00, 0x02, 0x10,
+        0x80, 0xFF, 0x03, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0xFC, 0x3F,
+        0x00, 0xFF, 0xFF, 0x3F, 0x00, 0xFE, 0x0F, 0x80, 0xFF, 0x03, 0x20, 0x00, 0x00, 0x08, 0xC0,
+        0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x01, 0xF8, 0x3F, 0x00, 0x02, 0x00, 0x80,
+        0xFF, 0x03, 0xE0, 0xFF, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0x07, 0x40, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x07, 0x00, 0x00,
+        0x00, 0x00, 0x80, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x80, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03, 0x00, 0x80, 0xFF, 0x03, 0x00, 0x00, 0xFF,
+        0xFF, 0x7F, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x03,
+        0xF0, 0xFF, 0x00, 0x04, 0xE0, 0xFF, 0x00, 0x08, 0x40, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x01, 0x08, 0xC0, 0xFF, 0x03, 0x00, 0x80, 0xFF, 0x07, 0x00, 0x00, 0x00, 0xF8,
+        0x7F, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x80, 0x00, 0x04, 0xE0, 0xFF, 0x00, 0x00, 0x40, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0xC0, 0xFF, 0x01, 0x10, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x00, 0x00, 0x40,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x80,
+        0xFF, 0x03, 0x20, 0x00, 0xFF, 0xFF, 0x3F, 0x00, 0x02, 0x00, 0x80, 0xFF, 0x07, 0xE0, 0xFF,
+        0xFF, 0x0F, 0x00, 0x00, 0xFE, 0xFF, 0xFF, 0xFF, 0x03, 0x20, 0x00, 0x00, 0x08, 0x40, 0x00,
+        0xFE, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x02, 0xF0, 0x7F, 0x00,
+        0x04, 0xE0, 0xFF, 0x01, 0x08, 0x00, 0x00, 0x00, 0x00,
+    ];
+    assert_eq!(ct_ref, ct);
+    let ss_ref = [
+        0x81, 0x67, 0xD3, 0x46, 0x01, 0x94, 0xC1, 0x16, 0xD9, 0xD5, 0xBF, 0x74, 0x9C, 0x34, 0x35,
+        0x4F, 0x57, 0x98, 0xA6, 0xEA, 0x15, 0xD1, 0xCD, 0x6C, 0x4B, 0x67, 0xC0, 0x81, 0x4A, 0xE8,
+        0xB4, 0x1C,
+    ];
+    assert_eq!(ss_ref, ss);
+
+    Ok(())
+}
+
+#[test]
+fn test_dec_kat1() -> R {
+    each_backend(dec_kat1)
+}
+
+fn dec_kat1() -> R {
+    let ct = [
+        0xB9, 0x34, 0x87, 0x2B, 0x04, 0x69, 0x34, 0x9C, 0xC3, 0xC8, 0x8A, 0xE5, 0x7A, 0xF9, 0x8D,
+        0x6E, 0x23, 0x30, 0xE6, 0x66, 0xCE, 0x88, 0x9E, 0x37, 0x07, 0x52, 0x32, 0x24, 0x89, 0x1C,
+        0x06, 0x85, 0x93, 0x3F, 0x03, 0x14, 0xB2, 0x8E, 0x1D, 0x6E, 0x1C, 0x35, 0x59, 0xC8, 0x69,
+        0x5E, 0xF2, 0x6A, 0xB6, 0xBA, 0x3A, 0xF7, 0x01, 0xF6, 0x3F, 0xE2, 0xB0, 0x64, 0x2E, 0x89,
+        0x6D, 0x17, 0xF7, 0x1E, 0xEA, 0x86, 0xF8, 0xC1, 0xAD, 0x18, 0xC2, 0xB4, 0xB5, 0xF7, 0xF4,
+        0xD2, 0xF4, 0xEC, 0xA4, 0x91, 0x55, 0x0D, 0x09, 0x97, 0x17, 0x78, 0x35, 0x60, 0xDF, 0xC4,
+        0xCA, 0xE9, 0xB7, 0x43, 0x51, 0xE7, 0x1A, 0x4B, 0x1A, 0x2B, 0xE0, 0x01, 0xB8, 0xC5, 0xFF,
+        0x69, 0xAC, 0xF2, 0x5E, 0x8A, 0xEB, 0x01, 0x03, 0x9C, 0x15, 0xC7, 0xA2, 0x9B, 0xAE, 0x63,
+        0xBA, 0x67, 0x49, 0xDD, 0x6C, 0xB8, 0x3B, 0x3C, 0x26, 0x25, 0x8F, 0x55, 0xF1, 0x14, 0x29,
+        0xD9, 0x1F, 0xE9, 0x0C, 0x43, 0x88, 0xB5, 0x23, 0x7B, 0x0A, 0x2E, 0xB7, 0x14, 0x3A, 0x31,
+        0xA9, 0xE5, 0x0C, 0xA1, 0x60, 0x82, 0xC6, 0x1D, 0x1B, 0x53, 0x1A, 0xEA, 0xC9, 0x06, 0x0D,
+        0x5D, 0x7D, 0xB7, 0x5B, 0x0D, 0xFB, 0x7A, 0x58, 0x69, 0x67, 0xB0, 0x47, 0x4F, 0x9E, 0x54,
+        0xD6, 0x91, 0x67, 0xF9, 0xF1, 0xC1, 0x46, 0x11, 0x89, 0x94, 0x1C, 0x6F, 0xBC, 0xB0, 0xFB,
+        0xEA, 0x0F, 0xC9, 0x75, 0x5E, 0x3D, 0xE6, 0x09, 0xD7, 0x85, 0xC9, 0xB8, 0xD0, 0x71, 0xFD,
+        0x6A, 0x90, 0xC8, 0x27, 0xED, 0x0D, 0x83, 0x50, 0xB5, 0x07, 0x05, 0x2C, 0x8C, 0x2C, 0x43,
+        0x66, 0x3F, 0x81, 0xCF, 0x36, 0x84, 0xD4, 0x28, 0x41, 0xB5, 0x1D, 0xC7, 0x07, 0x85, 0x98,
+        0x18, 0xBC, 0xBB, 0x53, 0x46, 0x2D, 0x1F, 0x13, 0xEC, 0xA5, 0x41, 0xD1, 0x06, 0xCA, 0x5B,
+        0x75, 0x6A, 0x6F, 0xF4, 0x82, 0xE6, 0xE0, 0xF6, 0x55, 0xA0, 0xDF, 0x7F, 0xBB, 0x55, 0x84,
+        0x30, 0xE9, 0x5F, 0xBB, 0xB6, 0xD5, 0x8E, 0xF0, 0x7A, 0xE6, 0xBE, 0x2A, 0x24, 0xB0, 0x77,
+        0x19, 0x43, 0xB9, 0xE6, 0x81, 0x39, 0x94, 0xA0, 0xA0, 0x3D, 0x4C, 0xF7, 0x60, 0x29, 0xAB,
+        0xA1, 0xCB, 0x1E, 0xA3, 0x90, 0x46, 0x9F, 0x94, 0x4D, 0x7A, 0x23, 0xB5, 0x0B, 0x81, 0x63,
+        0xE0, 0xB4, 0x28, 0x1E, 0x2B, 0x76, 0x9E, 0x8E, 0x36, 0xA3, 0xC1, 0x63, 0x85, 0xCE, 0xAF,
+        0x94, 0x81, 0xB6, 0xC1, 0x5C, 0xF6, 0x02, 0xC7, 0x9A, 0xED, 0xA0, 0xCB, 0xC2, 0x62, 0x78,
+        0xCC, 0x90, 0x44, 0x48, 0xB3, 0x60, 0xB1, 0xB3, 0x2C, 0x59, 0x5E, 0xA7, 0xE1, 0x3E, 0xA7,
+        0x2D, 0x5D, 0xEE, 0x3A, 0x0B, 0xBF, 0xE8, 0x01, 0x48, 0x1E, 0x27, 0x61, 0x39, 0xB1, 0x66,
+        0x3A, 0xC1, 0xF7, 0xE7, 0xA2, 0xAD, 0x87, 0xF6, 0x17, 0xF9, 0x03, 0xDD, 0x4C, 0xA3, 0xD2,
+        0x4D, 0x88, 0x77, 0x02, 0x43, 0x5F, 0xBD, 0xEB, 0x14, 0x9F, 0x55, 0x95, 0xF2, 0x76, 0xA5,
+        0xF1, 0x6C, 0x6E, 0x3B, 0x5B, 0xFD, 0x63, 0xF1, 0xFC, 0xE5, 0xF8, 0x32, 0x19, 0x77, 0x9C,
+        0xCB, 0x14, 0xB3, 0xA3, 0x4C, 0xEE, 0x18, 0xC5, 0xDA, 0x18, 0x63, 0x59, 0x6B, 0x40, 0x54,
+        0xFA, 0x26, 0x3A, 0x6C, 0x94, 0x64, 0xEC, 0xD2, 0x46, 0xE8, 0xEC, 0xEE, 0x8A, 0x9D, 0x21,
+        0x42, 0x00, 0x42, 0x55, 0xBA, 0xFB, 0x05, 0x9D, 0x68, 0x7E, 0x82, 0xC7, 0xF5, 0xB2, 0x50,
+        0xE0, 0xAF, 0x38, 0x8C, 0xF6, 0x5F, 0x35, 0x8E, 0xC5, 0x38, 0x33, 0xB8, 0xDF, 0xF6, 0xA0,
+        0x95, 0x2D, 0x44, 0x3C, 0x18, 0x92, 0x9A, 0x58, 0x89, 0xF4, 0xA0, 0x35, 0x2F, 0x3F, 0x01,
+        0xBF, 0x51, 0x2F, 0xE3, 0x73, 0x60, 0xB5, 0xEC, 0xD7, 0xCA, 0xF4, 0x05, 0x53, 0x01, 0xB6,
+        0x6B, 0x35, 0x2A, 0x58, 0xA1, 0x99, 0xE7, 0x93, 0x64, 0xB6, 0x95, 0x71, 0xAC, 0xD6, 0x44,
+        0x02, 0xDC, 0x82, 0xF0, 0xDB, 0x00, 0x2B, 0xC5, 0x67, 0x59, 0x57, 0x87, 0xE9, 0x66, 0xAC,
+        0xBD, 0x8E, 0xB0, 0xD7, 0x3A, 0x49, 0x4A, 0x66, 0x21, 0x79, 0x67, 0xEC, 0xA7, 0xC1, 0x1F,
+        0xE4, 0x80, 0xFB, 0xB3, 0x42, 0xD2, 0xFD, 0xA7, 0x3C, 0xCC, 0x2C, 0xBD, 0xB9, 0x15, 0x81,
+        0x5A, 0x4F, 0x81, 0x5F, 0x61, 0x74, 0x9D, 0x4F, 0x78, 0x12, 0xE8, 0x92, 0x48, 0xAA, 0x84,
+        0xE9, 0xFC, 0x23, 0x30, 0x11, 0x33, 0x81, 0xC0, 0x5D, 0x05, 0xD7, 0x81, 0x8E, 0xB8, 0x88,
+        0xCD, 0x60, 0x31, 0x15, 0xBF, 0x60, 0x9A, 0xA0, 0xDF, 0x47, 0xBD, 0x13, 0x62, 0x45, 0x64,
+        0x93, 0x27, 0x2F, 0xAF, 0xB2, 0xDF, 0xF0, 0x5F, 0x3B, 0xFA, 0x9B, 0xCC, 0x59, 0x1D, 0x2A,
+        0x98, 0x93, 0x54, 0xE4, 0x02, 0x2F, 0x61, 0x1D, 0x03, 0xFB, 0x00, 0x1F, 0xA2, 0x38, 0x78,
+        0xC4, 0x23, 0xE2, 0x79, 0x4A, 0x97, 0x4D, 0xDC, 0x34, 0x88, 0xCD, 0x97, 0x93, 0x80, 0x52,
+        0xFE, 0x71, 0xCF, 0xC5, 0xF9, 0x3E, 0xAF, 0x96, 0xF6, 0xB6, 0x70, 0x09, 0x4C, 0x0D, 0x3B,
+        0x29, 0x43, 0x40, 0xE5, 0xC7, 0x3A, 0x3C, 0xBA, 0xB8, 0x06, 0xA9, 0x80, 0x3A, 0x8A, 0x40,
+        0xDA, 0x78, 0x8C, 0x7E, 0x99, 0x7B, 0xD2, 0x1B, 0x02,
+    ];
+    let sk = [
+        0xD0, 0x67, 0xD9, 0x8F, 0x00, 0x55, 0xE2, 0xC3, 0xDE, 0xEF, 0x10, 0x76, 0xBB, 0xB7, 0x55,
+        0xAF, 0xD0, 0x65, 0x11, 0x2C, 0x85, 0xC4, 0x6E, 0x6C, 0x35, 0x06, 0x55, 0xD9, 0x62, 0x50,
+        0x73, 0xE9, 0x4E, 0x45, 0x28, 0xC0, 0x53, 0xE7, 0x20, 0x20, 0x6C, 0xDB, 0x78, 0x0A, 0x61,
+        0xAD, 0x51, 0x20, 0xC4, 0x98, 0xA4, 0xCD, 0x3E, 0x60, 0xC3, 0x34, 0xD8, 0x70, 0x2F, 0x0F,
+        0x79, 0xD8, 0x14, 0x18, 0xAF, 0x95, 0x9C, 0xAB, 0x77, 0x22, 0xEB, 0xDD, 0x30, 0xDF, 0x0A,
+        0x47, 0x9E, 0x14, 0x03, 0xD3, 0x37, 0xAD, 0x92, 0x3E, 0x5D, 0xBC, 0xED, 0x4E, 0xE0, 0x48,
+        0x39, 0x02, 0x0F, 0x77, 0x51, 0xC0, 0x75, 0xA4, 0x89, 0x2A, 0xAB, 0x19, 0xDC, 0x7B, 0xD2,
+        0xAE, 0x91, 0x27, 0xB2, 0x41, 0x83, 0x1D, 0x18, 0x37, 0xB9, 0xE2, 0x24, 0x30, 0xCE, 0x19,
+        0x25, 0x9F, 0x78, 0xA5, 0xC5, 0xBF, 0xD0, 0xE5, 0x36, 0x5E, 0x5B, 0xAE, 0xE5, 0x29, 0x18,
+        0xCC, 0xAB, 0xE5, 0x83, 0xB5, 0x0F, 0x00, 0x12, 0x6D, 0x04, 0x2C, 0xBC, 0xDC, 0x38, 0x62,
+        0x99, 0xEE, 0x68, 0xEE, 0x4E, 0xEC, 0xD8, 0x60, 0xE6, 0x13, 0x14, 0x22, 0xAE, 0xE6, 0xB2,
+        0x00, 0x73, 0x37, 0x58, 0xC8, 0x2D, 0xC4, 0xBE, 0x23, 0x4F, 0x6C, 0x46, 0x35, 0x6F, 0x01,
+        0x7E, 0x7D, 0x07, 0xE4, 0x5B, 0xDC, 0xC9, 0xAC, 0xD1, 0xAF, 0xDD, 0xDD, 0x5C, 0x91, 0x38,
+        0x80, 0x52, 0xCE, 0x0A, 0x53, 0x6B, 0x92, 0x16, 0x06, 0xB5, 0xEA, 0xA6, 0x90, 0x2C, 0xA4,
+        0xEF, 0x39, 0x14, 0x26, 0x11, 0xF9, 0xBB, 0x56, 0x6D, 0x4F, 0xD7, 0x54, 0x14, 0x43, 0x55,
+        0x0D, 0x8C, 0x60, 0xB2, 0xF6, 0x99, 0x10, 0x73, 0xC9, 0xA1, 0xF8, 0x04, 0x3A, 0xA4, 0x69,
+        0xE9, 0x12, 0x7B, 0x6A, 0x2F, 0x5E, 0xBD, 0x96, 0x80, 0x0D, 0x24, 0xB8, 0xB9, 0xD5, 0x86,
+        0x1F, 0x11, 0x96, 0xB1, 0xC8, 0x05, 0x67, 0x6E, 0xA4, 0x67, 0x8B, 0x12, 0x43, 0x73, 0x8F,
+        0x9A, 0xE9, 0x7A, 0xC5, 0x68, 0xBC, 0x5B, 0x6A, 0xE0, 0x78, 0xC7, 0xBD, 0x4F, 0x61, 0x56,
+        0x81, 0x72, 0x08, 0x61, 0x3C, 0x8B, 0x59, 0xCF, 0x7B, 0x7A, 0xF2, 0xA8, 0xFB, 0xE9, 0x63,
+        0x7C, 0x5B, 0x76, 0x43, 0x4E, 0x65, 0xF1, 0xB3, 0x61, 0xA4, 0xF6, 0xBB, 0xF7, 0xE1, 0xFD,
+        0xE4, 0x7D, 0xD6, 0xDD, 0x2A, 0x53, 0x20, 0x1D, 0x91, 0x0E, 0x62, 0xC3, 0xE1, 0x1A, 0xB0,
+        0xC2, 0x3D, 0x88, 0x77, 0xDE, 0xC7, 0x1D, 0x27, 0xF7, 0x81, 0x46, 0xEE, 0xD3, 0x99, 0xA4,
+        0xE0, 0x63, 0xBE, 0xC0, 0xC1, 0x75, 0xE3, 0xAB, 0x41, 0x8E, 0x08, 0xED, 0x21, 0x87, 0x5D,
+        0xA2, 0x5F, 0xA6, 0x7B, 0xC0, 0x36, 0x43, 0xD3, 0xB7, 0x0B, 0x7C, 0x1A, 0x11, 0x8C, 0x15,
+        0x3B, 0x3A, 0xF5, 0x8E, 0xD5, 0xC4, 0xCD, 0x1A, 0x7F, 0x32, 0x36, 0x4D, 0x13, 0x34, 0xA4,
+        0x4D, 0x34, 0x99, 0x10, 0x4F, 0xAA, 0xDA, 0x55, 0x55, 0xB8, 0x66, 0x94, 0x77, 0x2E, 0xF0,
+        0x06, 0x5F, 0xC6, 0x73, 0xB0, 0x75, 0x76, 0xB5, 0x30, 0x85, 0xC2, 0x71, 0xAD, 0xB3, 0x06,
+        0x5A, 0x6C, 0x2A, 0x5D, 0x9E, 0x77, 0x4A, 0xBE, 0x94, 0xAF, 0xBE, 0xB8, 0xB6, 0x01, 0xAB,
+        0x6F, 0x0B, 0x61, 0x9A, 0x7F, 0x85, 0x58, 0xD1, 0xA8, 0x24, 0xFF, 0x4F, 0xB9, 0x46, 0xE8,
+        0x5C, 0x6D, 0x1B, 0x9C, 0x8F, 0x55, 0x64, 0x4F, 0xD1, 0xB3, 0x2F, 0x8E, 0x8C, 0x8F, 0x9C,
+        0xE5, 0x47, 0x5D, 0x90, 0x4F, 0x1B, 0x1E, 0x01, 0x76, 0x31, 0x11, 0xF4, 0xD2, 0x0D, 0x22,
+        0x92, 0x79, 0x55, 0xCE, 0x6C, 0xF2, 0xAD, 0xC2, 0x93, 0xCA, 0x4B, 0x86, 0x9F, 0x95, 0x10,
+        0x7E, 0x95, 0x0E, 0xED, 0x95, 0x08, 0x8A, 0xF8, 0x62, 0xA6, 0x1E, 0xC9, 0xCD, 0xD2, 0xFA,
+        0x33, 0xE2, 0xC3, 0x27, 0x0C, 0xB8, 0xC8, 0x9C, 0x3A, 0x42, 0xD7, 0xFB, 0x2D, 0x9C, 0xE3,
+        0xDA, 0xFD, 0x7B, 0x6B, 0xE9, 0xAC, 0x16, 0xCB, 0xFD, 0xDA, 0x05, 0x0B, 0x25, 0x5B, 0xC5,
+        0x06, 0x4D, 0x4F, 0xEA, 0x8E, 0x80, 0x22, 0x8A, 0x53, 0x3C, 0xE5, 0x3D, 0x6C, 0x49, 0xFF,
+        0x8F, 0xDA, 0xCB, 0xE1, 0x1D, 0xB8, 0xE1, 0x62, 0xC0, 0xA1, 0xFA, 0xB9, 0x24, 0xFB, 0xBA,
+        0x41, 0xBA, 0xC4, 0xF8, 0x7E, 0x59, 0xCB, 0xF9, 0xEC, 0x28, 0xB2, 0xCF, 0xE3, 0xC8, 0x5E,
+        0xE1, 0x4C, 0x1E, 0xED, 0x15, 0xAE, 0x08, 0x24, 0xFD, 0x6B, 0xC3, 0x9F, 0xB4, 0x58, 0xF3,
+        0x9A, 0x8B, 0xD0, 0x6B, 0xB0, 0x52, 0xF7, 0xA0, 0xE3, 0x7A, 0xA7, 0x3A, 0x50, 0xF1, 0x63,
+        0x0F, 0xEA, 0x41, 0x8A, 0x9D, 0x70, 0x91, 0x6C, 0xF3, 0x68, 0xA5, 0x51, 0xC2, 0xFB, 0x47,
+        0x41, 0x50, 0x55, 0x85, 0xA8, 0x6C, 0x31, 0x2A, 0xE7, 0x89, 0xC7, 0xDD, 0x90, 0x23, 0x12,
+        0x48, 0x16, 0xF9, 0x20, 0x93, 0x17, 0xF3, 0xBC, 0xDE, 0x00, 0x8A, 0x9F, 0x30, 0xC7, 0xDC,
+        0x9B, 0xBD, 0x04, 0x7E, 0x53, 0x58, 0xF7, 0xBB, 0xB6, 0x87, 0xD6, 0xD6, 0x1C, 0x49, 0xE9,
+        0x56, 0xD6, 0xF7, 0x16, 0xDB, 0xB8, 0xDC, 0xF8, 0x44, 0x59, 0x76, 0x56, 0x6F, 0x3A, 0x30,
+        0x80, 0x8F, 0xBA, 0x5F, 0x58, 0x40, 0x64, 0x19, 0x8A, 0x9D, 0x8A, 0xCC, 0xB0, 0x42, 0x0D,
+        0x5B, 0x0E, 0x51, 0xA2, 0x1A, 0x1A, 0xED, 0x64, 0xDF, 0xB3, 0x76, 0x62, 0xC6, 0x37, 0xF8,
+        0x3E, 0xFC, 0x54, 0x75, 0x29, 0x58, 0x8F, 0x5D, 0x34, 0x6B, 0xA5, 0x98, 0xFD, 0xF3, 0x70,
+        0xA4, 0xD5, 0xFF, 0x8C, 0xA9, 0xBC, 0x2A, 0x9E, 0x34, 0x05, 0xEF, 0x5A, 0xDC, 0xE1, 0x4E,
+        0xDC, 0x2A, 0x9A, 0x5B, 0x9F, 0x50, 0x21, 0x6B, 0x37, 0x70, 0x6D, 0x56, 0x1D, 0x7E, 0x4C,
+        0xF6, 0x29, 0xEB, 0xAD, 0x71, 0x2B, 0xE2, 0xCA, 0x57, 0x5F, 0x0C, 0x15, 0xB6, 0x5B, 0x46,
+        0xA7, 0x43, 0xD6, 0xDB, 0xC5, 0x56, 0x7F, 0x54, 0x5B, 0x48, 0x5E, 0x2F, 0x09, 0x06, 0xA2,
+        0xD0, 0x5A, 0xA2, 0xEA, 0x56, 0xFD, 0x3D, 0x97, 0x65, 0x96, 0x0B, 0xDA, 0x0D, 0x3A, 0x5B,
+        0x1A, 0x05, 0x38, 0x26, 0x70, 0xD9, 0x01, 0xB5, 0x3B, 0x6F, 0x44, 0xAB, 0x92, 0x40, 0xDA,
+        0x7F, 0x1B, 0xEA, 0xE8, 0x1C, 0xA8, 0xC2, 0x79, 0x0D, 0x1D, 0xF2, 0xA8, 0xB1, 0x88, 0x12,
+        0xF1, 0xB2, 0xF7, 0x7C, 0x83, 0x8E, 0xC1, 0x78, 0x97, 0x58, 0xA5, 0xB6, 0xFF, 0x68, 0x16,
+        0x21, 0xFB, 0x73, 0x58, 0x9A, 0xED, 0xEF, 0x43, 0x06, 0x2C, 0x0F, 0x99, 0xC1, 0x5F, 0xB7,
+        0x79, 0xF6, 0x9E, 0xBC, 0xA3, 0x94, 0x44, 0xEB, 0x97, 0xBC, 0x88, 0x02, 0xF6, 0x26, 0xC8,
+        0xB1, 0x5D, 0xE6, 0x66, 0xCC, 0xB5, 0xCA, 0x52, 0x61, 0x2E, 0xA1, 0xD5, 0x94, 0xE8, 0xAE,
+        0xD6, 0x2C, 0x04, 0x1D, 0xAB, 0x03, 0x17, 0xF0, 0xCE, 0x5A, 0x41, 0xCE, 0x76, 0x72, 0x95,
+        0x3D, 0x30, 0x1C, 0xFF, 0xD7, 0x10, 0xF8, 0x0B, 0xEA, 0xC3, 0xF1, 0x9C, 0x0E, 0x96, 0xE6,
+        0x8C, 0xF8, 0xFD, 0xAB, 0x81,
+    ];
+    let mut ss1 = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_dec(&mut ss1, &ct, &sk)?;
+
+    let ss_ref = [
+        0x17, 0x6F, 0xDB, 0xB0, 0x09, 0xDD, 0x3F, 0x84, 0x8B, 0x36, 0x5A, 0xB7, 0xF1, 0x8D, 0x9C,
+        0x0C, 0x91, 0x72, 0x19, 0x31, 0xC8, 0x59, 0x4C, 0x2C, 0x6F, 0x04, 0x3C, 0x86, 0x00, 0x79,
+        0x1A, 0x6C,
+    ];
+    assert_eq!(ss_ref, ss1);
+
+    Ok(())
+}
+
+#[test]
+fn test_poly_sq_tobytes() {
+    let mut a = Poly::new();
+    a.coeffs = [7; NTRU_N];
+    let mut r = [0u8; 1 + 11 * NTRU_PACK_DEG / 8];
+    poly_sq_tobytes(&mut r, &a);
+    let expected_r = [
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x00,
+    ];
+    assert_eq!(r, expected_r);
+}
@@ -0,0 +1,104 @@
+//! NTRU-HPS (Hoffstein-Pipher-Silverman) variant with n = 677 and q = 2048
+
+/// Constants distinguishing this variant from the other ones
+mod variant {
+    /// Name of the variant
+    pub const CRYPTO_ALGNAME: &str = "ntruhps2048677";
+    pub const NTRU_HPS: bool = true;
+    pub const NTRU_N: usize = 677;
+    pub const NTRU_LOGQ: usize = 11;
+
+    /// SHA3-256 digest of `pk` of the first NIST KAT (`count = 0`)
+    pub const KAT_PK_SHA3: [u8; 32] = [
+        0xE2, 0x50, 0xC1, 0x8A, 0x8E, 0xD5, 0x70, 0x74, 0x72, 0x46, 0xEA, 0x4A, 0x37, 0x17, 0x6F,
+        0x66, 0x0E, 0x1B, 0xDC, 0x8E, 0xE5, 0x8D, 0xA0, 0x8C, 0xDF, 0x02, 0x15, 0xA6, 0xD7, 0xA8,
+        0xEF, 0x0D,
+    ];
+    /// SHA3-256 digest of `sk` of the first NIST KAT
+    pub const KAT_SK_SHA3: [u8; 32] = [
+        0x3F, 0x20, 0xEF, 0xF7, 0x83, 0x69, 0xE8, 0x19, 0x5D, 0x33, 0x5F, 0x3E, 0xB7, 0xAB, 0xCD,
+        0x49, 0x58, 0xC4, 0x12, 0x7D, 0x47, 0x95, 0xB1, 0x3C, 0xD1, 0x61, 0x31, 0xEF, 0x03, 0x2D,
+        0x4C, 0xBC,
+    ];
+    /// SHA3-256 digest of `ct` of the first NIST KAT
+    pub const KAT_CT_SHA3: [u8; 32] = [
+        0xEE, 0xC4, 0x36, 0x90, 0xD8, 0x9D, 0xC6, 0xB8, 0x9D, 0x71, 0xD2, 0xDD, 0xC7, 0xE5, 0xCB,
+        0x0B, 0x9F, 0xA7, 0x94, 0xBC, 0x80, 0x37, 0x63, 0x58, 0x99, 0x20, 0x3B, 0xE1, 0xA9, 0x1A,
+        0x91, 0x84,
+    ];
+    /// `ss` of the first NIST KAT
+    pub const KAT_SS: [u8; 32] = [
+        0x49, 0xAC, 0x4D, 0x5D, 0x16, 0x34, 0xC6, 0xAF, 0xFA, 0x5A, 0x08, 0xC2, 0xB2, 0x28, 0xEC,
+        0x80, 0x6D, 0x78, 0x70, 0xB1, 0x51, 0x79, 0x90, 0x72, 0x86, 0x63, 0xD2, 0xD8, 0xBB, 0xC1,
+        0x84, 0xF2,
+    ];
+
+    /// `CRYPTO_CIPHERTEXTBYTES` as type-level integer
+    #[cfg(feature = "kem")]
+    pub type CiphertextSize = kem::generic_array::typenum::U930;
+}
+
+#[path = "../api.rs"]
+mod api;
+#[path = "../cmov.rs"]
+mod cmov;
+#[path = "../kat.rs"]
+mod kat;
+#[path = "../kem.rs"]
+mod kem;
+#[cfg(feature = "kem")]
+#[path = "../kem_traits.rs"]
+mod kem_traits;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+#[path = "../locked_sk.rs"]
+mod locked_sk;
+#[cfg(feature = "masked")]
+#[path = "../masked.rs"]
+mod masked;
+#[path = "../owcpa.rs"]
+mod owcpa;
+#[path = "../pack3.rs"]
+mod pack3;
+#[path = "../packq.rs"]
+mod packq;
+#[path = "../params.rs"]
+mod params;
+#[path = "../poly.rs"]
+mod poly;
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+#[path = "../poly_avx2.rs"]
+mod poly_avx2;
+#[path = "../poly_divstep.rs"]
+mod poly_divstep;
+#[path = "../poly_lift.rs"]
+mod poly_lift;
+#[path = "../poly_mod.rs"]
+mod poly_mod;
+#[path = "../poly_r2_inv.rs"]
+mod poly_r2_inv;
+#[path = "../poly_rq_mul.rs"]
+mod poly_rq_mul;
+#[path = "../poly_s3_bitsliced.rs"]
+mod poly_s3_bitsliced;
+#[path = "../poly_s3_inv.rs"]
+mod poly_s3_inv;
+#[path = "../poly_ternary.rs"]
+mod poly_ternary;
+#[path = "../sample.rs"]
+mod sample;
+#[path = "../sample_iid.rs"]
+mod sample_iid;
+#[path = "../types.rs"]
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::api::*;
+pub use self::kat::*;
+pub use self::kem::*;
+#[cfg(feature = "kem")]
+pub use self::kem_traits::*;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+pub use self::locked_sk::*;
+pub use self::types::*;
@@ -0,0 +1,522 @@
+use std::error;
+
+use super::packq::poly_sq_tobytes;
+use super::params::{NTRU_N, NTRU_PACK_DEG};
+use super::poly::Poly;
+use super::*;
+use crate::rng::AesState;
+
+type R = Result<(), Box<dyn error::Error>>;
+
+/// Run a known-answer test with every implementation of the ring multiplications
+fn each_backend(test: fn() -> R) -> R {
+    #[cfg(feature = "avx2")]
+    return crate::backend::for_each_backend(test);
+    #[cfg(not(feature = "avx2"))]
+    test()
+}
+
+#[test]
+fn test_api_sizes() {
+    assert_eq!(CRYPTO_PUBLICKEYBYTES, 930);
+    assert_eq!(CRYPTO_SECRETKEYBYTES, 1234);
+    assert_eq!(CRYPTO_CIPHERTEXTBYTES, 930);
+    assert_eq!(CRYPTO_BYTES, 32);
+}
+
+#[test]
+fn test_keypair_zeroed_rng() -> R {
+    each_backend(keypair_zeroed_rng)
+}
+
+fn keypair_zeroed_rng() -> R {
+    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+    let mut rng = AesState::new();
+
+    crypto_kem_keypair(&mut pk, &mut sk, &mut rng)?;
+
+    let pk_ref = [
+        0x2A, 0xAC, 0xFE, 0x8F, 0x2E, 0xCB, 0xB0, 0xB8, 0x73, 0x5B, 0x2C, 0x48, 0x97, 0xDD, 0xE4,
+        0xB7, 0x9C, 0xD7, 0x82, 0x30, 0x3F, 0x66, 0xB1, 0xCB, 0xFF, 0xF6, 0xB6, 0x97, 0x56, 0x04,
+        0x0C, 0x42, 0xB2, 0xB3, 0xA5, 0xAD, 0x82, 0xA6, 0x82, 0x0E, 0x2D, 0x91, 0xBE, 0xFB, 0x5B,
+        0xD4, 0xA2, 0x8C, 0x0B, 0x66, 0x0A, 0x28, 0xA7, 0x1F, 0x66, 0xBD, 0x99, 0x4A, 0x7A, 0x6E,
+        0xC2, 0x1D, 0xEE, 0xB1, 0x26, 0xA4, 0x0A, 0xBF, 0xE8, 0x30, 0x1E, 0xA6, 0x3B, 0x90, 0x1F,
+        0xEB, 0x26, 0x99, 0xC5, 0xF6, 0x68, 0xF8, 0xCA, 0x97, 0x4A, 0x71, 0x2B, 0x00, 0xCA, 0x0A,
+        0x13, 0xEA, 0x59, 0x92, 0xDC, 0xC0, 0xD1, 0xD5, 0x0C, 0x78, 0x21, 0xE0, 0xEB, 0x92, 0xAE,
+        0x78, 0xB6, 0x30, 0x2B, 0x8F, 0x32, 0x37, 0x83, 0xF0, 0x8D, 0xA3, 0xA3, 0x5C, 0xBD, 0x10,
+        0xBD, 0x5D, 0xEC, 0x50, 0xB5, 0x16, 0x93, 0x8E, 0xAB, 0x9E, 0x6B, 0x55, 0x9D, 0x35, 0x6C,
+        0x3E, 0xA1, 0xBD, 0x6A, 0x61, 0x18, 0x4B, 0x0D, 0xAB, 0x1F, 0xBD, 0x57, 0x3C, 0x65, 0x33,
+        0x3C, 0x50, 0x2E, 0xF0, 0x09, 0xB0, 0x21, 0x66, 0xB0, 0x37, 0x88, 0x96, 0xB6, 0x32, 0x54,
+        0xBC, 0x18, 0x70, 0x62, 0x52, 0xCF, 0x89, 0xC7, 0xC4, 0x7A, 0x7B, 0xB9, 0x0D, 0x5C, 0x01,
+        0x1E, 0x6C, 0xFD, 0xE0, 0xD9, 0x03, 0xDE, 0xF5, 0x0A, 0xBA, 0x5C, 0x1F, 0xF7, 0x2F, 0x82,
+        0x38, 0xDB, 0x4B, 0xFA, 0x42, 0x05, 0x88, 0x7E, 0xFC, 0xF9, 0x3C, 0xE4, 0xC7, 0xC6, 0x19,
+        0xEB, 0x03, 0xE3, 0xA6, 0xC3, 0x06, 0x41, 0xDA, 0x85, 0x3C, 0x42, 0xD2, 0xDD, 0x63, 0x34,
+        0x04, 0xC2, 0xE3, 0xB2, 0xAE, 0xCC, 0x3C, 0x25, 0xF1, 0x95, 0x7E, 0x70, 0x38, 0x81, 0x3A,
+        0x9C, 0x4F, 0x00, 0x23, 0xCD, 0xCF, 0xD0, 0xDA, 0xF9, 0x78, 0xF6, 0x57, 0x46, 0x2F, 0xDC,
+        0x8D, 0x8C, 0xDB, 0x6C, 0x09, 0x79, 0xBC, 0xA3, 0xFC, 0x7F, 0x41, 0x39, 0xB3, 0x58, 0x4F,
+        0xF0, 0x39, 0x01, 0x67, 0x7E, 0x4B, 0x47, 0x49, 0x9B, 0xD5, 0x8B, 0x0B, 0xBB, 0xBB, 0xEC,
+        0xB2, 0x95, 0xD8, 0xDF, 0x0D, 0xE0, 0x47, 0x14, 0x65, 0xE0, 0x7F, 0xEA, 0x8A, 0x15, 0x61,
+        0x5A, 0xB8, 0xD4, 0x90, 0xD4, 0x4C, 0x7F, 0xF3, 0x88, 0x47, 0xAF, 0x67, 0x51, 0x1D, 0x66,
+        0x90, 0xEC, 0x02, 0x12, 0xB2, 0xB4, 0x8C, 0x04, 0xB3, 0x38, 0xF5, 0x78, 0x01, 0x5B, 0x1C,
+        0x5C, 0x4F, 0xF3, 0x7B, 0xC1, 0xFA, 0x2C, 0x15, 0x6D, 0xED, 0x2D, 0x3B, 0xE6, 0x7E, 0xCF,
+        0xE7, 0xF8, 0x60, 0x13, 0x86, 0x4D, 0x7F, 0xAE, 0x2C, 0x22, 0xBF, 0x42, 0xFC, 0x02, 0x6A,
+        0x6F, 0xCA, 0x15, 0x00, 0x1A, 0x0B, 0x06, 0x0E, 0x85, 0xF2, 0x11, 0x51, 0x80, 0x75, 0x5F,
+        0x7A, 0x42, 0x4A, 0x09, 0x2A, 0x95, 0x2F, 0xA0, 0x9F, 0x66, 0xBD, 0xFE, 0xE0, 0x2C, 0x8F,
+        0xA0, 0xCA, 0x44, 0xFE, 0xFA, 0x6A, 0x1D, 0x03, 0xC0, 0x60, 0x75, 0x47, 0x9D, 0x54, 0xD9,
+        0xAE, 0xF7, 0x9B, 0xFC, 0x10, 0xEB, 0xD5, 0xD0, 0x94, 0x7A, 0x54, 0x11, 0xE9, 0x62, 0xAA,
+        0xE6, 0xB0, 0xAF, 0x62, 0x4C, 0x43, 0x36, 0x95, 0x52, 0xDA, 0x24, 0x54, 0xBA, 0x1E, 0x37,
+        0x51, 0xCF, 0x88, 0xE6, 0x0E, 0xBC, 0x62, 0x38, 0x49, 0x79, 0xDD, 0xEE, 0x15, 0x17, 0x2C,
+        0xB7, 0x5E, 0x27, 0xA0, 0x51, 0x08, 0xEC, 0x5D, 0xEB, 0xFE, 0xCF, 0x26, 0xE9, 0x21, 0xDD,
+        0x06, 0xB0, 0x5A, 0xBD, 0x5B, 0x62, 0xFF, 0xB2, 0x6A, 0x22, 0xC7, 0x49, 0xE5, 0x39, 0x65,
+        0xD5, 0xC9, 0x9F, 0x0D, 0x05, 0x72, 0x24, 0x8C, 0x27, 0x1B, 0x40, 0xDC, 0x04, 0xF4, 0x85,
+        0xEA, 0x6F, 0x05, 0x4C, 0x85, 0x27, 0x5E, 0x9C, 0xFF, 0xC0, 0xA5, 0x8F, 0x61, 0x3B, 0xA0,
+        0xFF, 0x18, 0x9B, 0x62, 0xD3, 0xED, 0x74, 0x9D, 0xBD, 0xAA, 0x9D, 0xAD, 0xC4, 0x9A, 0xB4,
+        0x3B, 0xC5, 0xE4, 0x7C, 0x37, 0xB9, 0x9D, 0x63, 0x4A, 0x88, 0xA7, 0x5D, 0x81, 0x73, 0x4E,
+        0x4A, 0x5D, 0x62, 0x71, 0x96, 0x63, 0x75, 0xD5, 0x6A, 0x28, 0x3D, 0x79, 0x03, 0x81, 0x31,
+        0x00, 0xE0, 0xCD, 0x60, 0x82, 0xC8, 0xEC, 0x0E, 0x29, 0x92, 0x67, 0xD6, 0x61, 0x3B, 0x10,
+        0xF4, 0x66, 0x56, 0xAE, 0x86, 0xED, 0xE5, 0x1C, 0x5D, 0x88, 0x35, 0xE4, 0x6A, 0x7D, 0xBE,
+        0xEE, 0xED, 0xD0, 0xC4, 0xB5, 0x02, 0xDF, 0x47, 0x6A, 0x8F, 0x5B, 0x03, 0x01, 0x79, 0x72,
+        0xA8, 0x1E, 0x9E, 0x6F, 0x87, 0xB6, 0xA2, 0xDF, 0x58, 0x85, 0x9C, 0x11, 0xA8, 0x92, 0x5E,
+        0x87, 0xC7, 0x51, 0x5D, 0xE3, 0x73, 0xA1, 0xF7, 0xA9, 0x82, 0x7D, 0xD6, 0xFB, 0xAE, 0xF0,
+        0x0B, 0xBE, 0x68, 0xAF, 0xF8, 0x51, 0x51, 0xF6, 0xC8, 0x4E, 0xE0, 0x31, 0x77, 0x4B, 0x98,
+        0xD7, 0x15, 0x1F, 0x3A, 0x94, 0x6C, 0xD0, 0x59, 0x34, 0x0E, 0x6E, 0x63, 0xC3, 0x0F, 0x67,
+        0xBC, 0xCA, 0x84, 0x11, 0xEB, 0x8D, 0x2F, 0xDB, 0x68, 0xE0, 0xAF, 0x96, 0x3D, 0xEA, 0x8C,
+        0xAF, 0xB6, 0x37, 0x69, 0x0C, 0xCE, 0xD3, 0xAA, 0x24, 0xFE, 0xAD, 0x64, 0xF3, 0x4D, 0x99,
+        0xB0, 0x01, 0x50, 0xB9, 0xA2, 0xFD, 0x58, 0xEB, 0x8E, 0x6D, 0xA2, 0x8B, 0x15, 0x37, 0xB4,
+        0xC0, 0x01, 0x44, 0x16, 0xB7, 0x34, 0x3B, 0x15, 0xDA, 0x36, 0x81, 0xC2, 0xB2, 0x6A, 0x86,
+        0x72, 0x34, 0x06, 0x5A, 0xF4, 0x6C, 0x93, 0x6F, 0x39, 0xA7, 0xEE, 0xC8, 0xF9, 0x84, 0x92,
+        0x5A, 0x59, 0xAF, 0xE1, 0xC7, 0x88, 0x6A, 0x78, 0x5E, 0xB2, 0x50, 0x1F, 0x9A, 0xCC, 0xEF,
+        0x63, 0x99, 0xFD, 0x5B, 0xFE, 0xA6, 0x1F, 0x37, 0x68, 0x69, 0xF4, 0xD1, 0x84, 0x7B, 0x8E,
+        0x4F, 0xAB, 0xFA, 0x86, 0x84, 0xD2, 0x8D, 0x71, 0xAF, 0x57, 0x65, 0x25, 0xEC, 0xC9, 0x78,
+        0x33, 0x7D, 0xE6, 0xFE, 0x88, 0x58, 0x07, 0xE5, 0x42, 0x46, 0xAE, 0x95, 0x89, 0x1F, 0x6F,
+        0x56, 0x5D, 0xA4, 0x33, 0x50, 0x42, 0x1C, 0xBC, 0x08, 0xA7, 0x8B, 0xC4, 0x9B, 0x13, 0x9D,
+        0x5A, 0x6C, 0x90, 0x5F, 0xBC, 0x7D, 0x83, 0x6E, 0x0E, 0xE6, 0xD6, 0x9B, 0x76, 0xE6, 0xC7,
+        0x8D, 0xC3, 0xAB, 0xF8, 0xB8, 0xA0, 0xF6, 0x9A, 0x3E, 0x44, 0x75, 0xB0, 0xE7, 0x35, 0x7E,
+        0x14, 0x6D, 0x7B, 0xEE, 0xAC, 0x0F, 0xE5, 0x63, 0x13, 0xB1, 0xA0, 0x1A, 0xB8, 0x56, 0x81,
+        0xF7, 0x2C, 0x63, 0x56, 0x94, 0xAD, 0x0E, 0x97, 0xA2, 0xAF, 0x2F, 0xDE, 0x50, 0x84, 0xD7,
+        0x7E, 0xEB, 0x60, 0x40, 0x71, 0x1E, 0x7B, 0xC8, 0x91, 0xC0, 0x87, 0xD1, 0x3F, 0x70, 0x36,
+        0xCB, 0xB3, 0x30, 0x01, 0x8D, 0x0B, 0xB5, 0x20, 0x50, 0x76, 0x8B, 0xA3, 0x99, 0x7B, 0x83,
+        0x31, 0x60, 0x23, 0xDF, 0x7C, 0x37, 0xD4, 0x9E, 0x7E, 0x7E, 0xF2, 0x72, 0xA0, 0x1F, 0xB6,
+        0x85, 0x98, 0xE8, 0x26, 0x56, 0x0E, 0xEC, 0x66, 0xFA, 0xF1, 0x3A, 0x14, 0x58, 0x05, 0x02,
+    ];
+    assert_eq!(pk_ref, pk);
+    let sk_ref = [
+        0x89, 0x2D, 0x93, 0x85, 0x9B, 0x0F, 0x01, 0xAF, 0x2F, 0xE7, 0x4B, 0x45, 0xBF, 0x59, 0xC7,
+        0x6E, 0x31, 0xAB, 0x85, 0xCD, 0xB1, 0x56, 0xC5, 0x46, 0x8F, 0x65, 0xC6, 0xA2, 0x57, 0x11,
+        0x5B, 0xC4, 0x59, 0x72, 0x3B, 0x1F, 0xBD, 0xEE, 0x8C, 0x61, 0xEE, 0x6D, 0x51, 0x7B, 0xB7,
+        0xA5, 0x26, 0x81, 0x5C, 0x8B, 0x74, 0xC8, 0x31, 0x35, 0x66, 0xCC, 0x51, 0xC2, 0x5D, 0xD5,
+        0x87, 0x69, 0x10, 0x62, 0x4F, 0xCF, 0xE0, 0x37, 0xDB, 0x7B, 0x10, 0xC9, 0x94, 0x98, 0x2B,
+        0x95, 0xD0, 0xAA, 0x20, 0x89, 0x3E, 0x4F, 0x67, 0x39, 0x74, 0xC5, 0x7E, 0xA9, 0x33, 0xD8,
+        0x8C, 0x8F, 0x3D, 0xBD, 0x9E, 0x98, 0x70, 0xD2, 0x09, 0x1B, 0x32, 0x3E, 0xD1, 0x10, 0x3E,
+        0xDA, 0x4E, 0x3F, 0xA1, 0x96, 0xEE, 0x29, 0x29, 0x06, 0xDC, 0xC3, 0x8D, 0x59, 0x42, 0x62,
+        0x1B, 0x74, 0xBF, 0xB3, 0x99, 0xB6, 0x7C, 0x74, 0x74, 0x60, 0x83, 0x3F, 0xEF, 0x0F, 0xE5,
+        0x02, 0xD5, 0x91, 0x53, 0x8A, 0xA4, 0x7C, 0x9E, 0xC7, 0x3B, 0x29, 0x94, 0x5E, 0xDA, 0x98,
+        0x54, 0xE0, 0xD3, 0xB8, 0x9E, 0x96, 0x8B, 0x0E, 0x66, 0xB0, 0x00, 0x5C, 0xD2, 0xCA, 0x42,
+        0x19, 0x6F, 0x13, 0x20, 0xE2, 0x55, 0x3B, 0x6A, 0xDD, 0x31, 0x43, 0xD5, 0x9C, 0x35, 0xC0,
+        0xEE, 0xDB, 0x1D, 0x74, 0x4B, 0x4C, 0x5B, 0x58, 0xD0, 0xC3, 0xCE, 0x66, 0x7C, 0x30, 0x3E,
+        0xEE, 0x3E, 0x42, 0x78, 0x2C, 0x97, 0x67, 0x7C, 0xD8, 0x6E, 0x09, 0x31, 0x00, 0x8D, 0xCD,
+        0x2B, 0xB6, 0x0A, 0xB2, 0x61, 0xDD, 0x96, 0x89, 0x8F, 0x27, 0x3B, 0x5A, 0x91, 0x0C, 0xE8,
+        0x02, 0x23, 0xBE, 0xBF, 0x23, 0xA5, 0xA3, 0xD6, 0x29, 0xE4, 0x03, 0xDE, 0x03, 0xD7, 0x02,
+        0xD5, 0x4A, 0xD4, 0xB5, 0xC5, 0x2D, 0x5E, 0x97, 0xCF, 0x3C, 0xC1, 0x9F, 0x1F, 0x3B, 0xCC,
+        0x66, 0x75, 0xBF, 0x63, 0x68, 0xA7, 0xDD, 0x5F, 0x2D, 0xD7, 0x53, 0xEB, 0x84, 0xAF, 0x34,
+        0x12, 0x02, 0xFB, 0xCB, 0x49, 0x45, 0x47, 0x5B, 0x7B, 0xEA, 0x46, 0xAD, 0xCF, 0x4F, 0x16,
+        0x2F, 0x6D, 0x8E, 0xF5, 0xF5, 0x46, 0x4E, 0xE9, 0x54, 0x2D, 0x2A, 0x8A, 0xC1, 0x8B, 0x86,
+        0xCE, 0xFF, 0x35, 0x49, 0x55, 0x84, 0x6A, 0xD3, 0xBA, 0xCE, 0xB6, 0xD7, 0x1E, 0xBF, 0x90,
+        0x29, 0xD5, 0x25, 0x67, 0xC9, 0x2E, 0x04, 0xA9, 0x81, 0x19, 0x46, 0x52, 0x2E, 0xD3, 0x21,
+        0x3B, 0xC6, 0x3B, 0x6C, 0x12, 0xB0, 0x55, 0x4F, 0x80, 0x65, 0xC9, 0xA8, 0xD9, 0x65, 0xF4,
+        0x29, 0x40, 0x42, 0xBD, 0xB8, 0xC2, 0x8C, 0xE1, 0xCC, 0xDC, 0x95, 0xFB, 0xF6, 0x6C, 0x7D,
+        0x72, 0x0C, 0x48, 0x83, 0xE4, 0x24, 0x9B, 0xFB, 0xDF, 0xD9, 0x01, 0x9E, 0x9B, 0x98, 0x78,
+        0xAE, 0xF3, 0x7C, 0x6D, 0x49, 0xD7, 0x19, 0x29, 0xC4, 0xEF, 0x1B, 0x91, 0x8B, 0x6A, 0x0D,
+        0xF6, 0x7D, 0x74, 0xB0, 0x3D, 0x43, 0x33, 0xEC, 0x96, 0x2F, 0xA8, 0x6E, 0xFB, 0xD5, 0x74,
+        0xE5, 0x52, 0xB9, 0xD6, 0x5D, 0x6D, 0x44, 0xE3, 0xAF, 0xB1, 0xFC, 0x30, 0x79, 0xAE, 0x40,
+        0x1D, 0x93, 0x13, 0xEE, 0xC6, 0xD2, 0x7D, 0x48, 0x87, 0x2F, 0xE4, 0xEA, 0x82, 0x7C, 0xA9,
+        0xC5, 0x03, 0x1B, 0xDF, 0xC5, 0x50, 0xEB, 0x28, 0x4B, 0x7B, 0x0E, 0x76, 0xBF, 0x36, 0x79,
+        0x98, 0xE6, 0x95, 0x20, 0x56, 0xC2, 0xFC, 0x7D, 0xB7, 0x83, 0x41, 0x44, 0x8F, 0xF8, 0xE2,
+        0x0B, 0x96, 0x8C, 0xEE, 0x81, 0xE1, 0x95, 0xC5, 0xD6, 0x97, 0x90, 0xF6, 0x2B, 0x7D, 0x12,
+        0x8A, 0x81, 0x22, 0x9E, 0x42, 0x51, 0x59, 0x89, 0x1C, 0x2B, 0x24, 0x18, 0x96, 0x07, 0x1C,
+        0xD8, 0x4E, 0xB9, 0x12, 0x88, 0x8E, 0xCA, 0xAF, 0xA8, 0x3B, 0xF2, 0x59, 0x0C, 0xFA, 0x77,
+        0xB8, 0x87, 0x73, 0x3A, 0x2A, 0x7C, 0xCD, 0xC0, 0xBE, 0x2B, 0x24, 0x9A, 0x2D, 0x44, 0x32,
+        0x4D, 0xA9, 0x04, 0x55, 0xB0, 0xC5, 0xD7, 0x5B, 0x6F, 0xB9, 0x2E, 0x79, 0x2C, 0xD2, 0x45,
+        0xA1, 0xF8, 0xCA, 0xD3, 0x6A, 0xAF, 0xE0, 0x73, 0xFA, 0xD7, 0x77, 0x3A, 0xA8, 0x90, 0xAA,
+        0x9A, 0x70, 0xF5, 0x57, 0x21, 0x49, 0x13, 0xC9, 0xEA, 0xEA, 0x26, 0x10, 0xD7, 0x9B, 0x1D,
+        0x38, 0xE9, 0x3A, 0x05, 0x58, 0xB1, 0x2E, 0x3A, 0xC8, 0x05, 0x7B, 0x57, 0x37, 0xC3, 0x0F,
+        0x78, 0x7D, 0x58, 0x0D, 0xF0, 0xDB, 0x05, 0x59, 0x3E, 0xEA, 0x88, 0x7C, 0x70, 0x50, 0xED,
+        0x44, 0xA7, 0x97, 0xDD, 0x14, 0x9E, 0x3C, 0x78, 0xBC, 0xAA, 0x70, 0x20, 0xCD, 0x44, 0xA4,
+        0x9A, 0x4F, 0xBD, 0x0F, 0x95, 0x88, 0xC0, 0xFA, 0xBB, 0x2C, 0xB8, 0xF6, 0xBD, 0x16, 0x57,
+        0x7D, 0xD4, 0x1C, 0x6C, 0x90, 0xD5, 0x77, 0x7B, 0x3F, 0x33, 0x3A, 0xB0, 0x36, 0x1A, 0x50,
+        0xF1, 0x97, 0xD0, 0x79, 0xCB, 0x9F, 0xF5, 0xC4, 0x53, 0x51, 0xFB, 0xFF, 0x64, 0xBB, 0x84,
+        0x1A, 0x0B, 0xFD, 0x6E, 0xE5, 0x25, 0x78, 0xBF, 0xD4, 0x6E, 0xFA, 0x2C, 0xA3, 0x49, 0xF7,
+        0x21, 0xF1, 0x1D, 0xB1, 0x52, 0xBE, 0x1B, 0x5B, 0x04, 0x09, 0x95, 0x54, 0x28, 0x85, 0x12,
+        0xD0, 0xBE, 0xC8, 0x9E, 0xF3, 0x7B, 0x5E, 0xBB, 0x37, 0x16, 0x15, 0xC8, 0xBC, 0x06, 0xE1,
+        0xD5, 0xBC, 0x8A, 0xDB, 0x26, 0xBC, 0xBB, 0x46, 0x7F, 0xBB, 0x69, 0xFE, 0x85, 0x6E, 0x52,
+        0xD8, 0x68, 0x3D, 0x88, 0xE1, 0x5C, 0x74, 0x89, 0x9C, 0xC2, 0x4E, 0xD8, 0x84, 0x1D, 0xAD,
+        0x2F, 0xAE, 0x68, 0x0E, 0x16, 0x0E, 0x42, 0x7E, 0x33, 0xB7, 0xD6, 0x8A, 0x99, 0xFE, 0xC8,
+        0x76, 0xC0, 0x65, 0xDD, 0xDD, 0x20, 0x30, 0x00, 0xF8, 0x5C, 0x10, 0x2C, 0x09, 0x17, 0xB4,
+        0x8D, 0x42, 0xEC, 0xAC, 0x13, 0x28, 0x63, 0x66, 0x83, 0xA7, 0xC1, 0x7A, 0xED, 0x2B, 0x0A,
+        0x3A, 0x8A, 0x72, 0x7C, 0x6D, 0x78, 0xED, 0x52, 0x8F, 0x02, 0x23, 0xD4, 0xC6, 0x9E, 0xB3,
+        0x26, 0xBB, 0x04, 0x69, 0xF1, 0x98, 0x3A, 0x77, 0xAC, 0x15, 0xA9, 0x67, 0x7A, 0x41, 0x73,
+        0xE8, 0x40, 0xCC, 0x61, 0x0F, 0xDB, 0x1A, 0x40, 0xC7, 0xA8, 0x2E, 0x10, 0xC3, 0x18, 0x62,
+        0x56, 0x11, 0x7F, 0x48, 0xB5, 0x26, 0x65, 0x52, 0x48, 0xDF, 0xA3, 0x9F, 0xD2, 0xAE, 0x06,
+        0xB2, 0x90, 0x2A, 0x25, 0xF6, 0xC4, 0x8D, 0xD5, 0x90, 0x4F, 0xD3, 0x33, 0xBC, 0xF0, 0x92,
+        0xCB, 0x93, 0xFD, 0x61, 0x27, 0x74, 0xA3, 0xDD, 0x0E, 0x47, 0x21, 0x14, 0xE7, 0xD1, 0x2B,
+        0x12, 0x7A, 0xE2, 0x4C, 0x49, 0x5E, 0x20, 0x56, 0x67, 0x91, 0x30, 0xC3, 0x12, 0xB1, 0xBA,
+        0x66, 0xE1, 0x50, 0x0C, 0x70, 0x91, 0xF9, 0x5C, 0xA1, 0x56, 0x42, 0x33, 0xE0, 0x8B, 0xB8,
+        0x87, 0x79, 0x68, 0x3C, 0x3C, 0x45, 0x38, 0x10, 0xA2, 0xE1, 0x7C, 0xF9, 0x20, 0xB1, 0xE8,
+        0xC8, 0x29, 0x32, 0x1F, 0x06, 0xBA, 0xC0, 0xB7, 0xB8, 0x95, 0xA5, 0xF3, 0x90, 0x91, 0xE5,
+        0x6F, 0x6F, 0x46, 0x80, 0xE0, 0xFE, 0x75, 0x4F, 0xC6, 0x71, 0x82, 0xD6, 0x02, 0x84, 0x2B,
+        0xDB, 0x36, 0x24, 0x94, 0x60, 0x42, 0xC3, 0x6C, 0x93, 0xE0, 0xDF, 0x50, 0x49, 0xEA, 0x68,
+        0xA7, 0xC4, 0xA3, 0x55, 0xB1, 0xA4, 0x54, 0x95, 0x15, 0x1B, 0x88, 0xC0, 0x59, 0xFF, 0x57,
+        0xA7, 0xA7, 0x23, 0x97, 0x15, 0x57, 0x61, 0x09, 0xF4, 0x4F, 0x2B, 0x4C, 0x66, 0xA7, 0x67,
+        0x8C, 0x0B, 0x37, 0x25, 0x9B, 0xA6, 0x19, 0x60, 0x91, 0xA4, 0x2F, 0x12, 0x81, 0x85, 0xAA,
+        0x10, 0x3C, 0x1A, 0x6B, 0x8A, 0x6C, 0x45, 0x28, 0x32, 0x15, 0x94, 0x4E, 0x0C, 0xA3, 0x46,
+        0xAC, 0x76, 0x5D, 0xC0, 0x1F, 0x00, 0x6D, 0xC6, 0x0F, 0x64, 0x1E, 0xF5, 0x4E, 0x70, 0xC9,
+        0xC0, 0x49, 0x1F, 0xB6, 0xC6, 0x70, 0xA5, 0x6B, 0x73, 0xF4, 0xDB, 0xF9, 0xCE, 0xE1, 0x2F,
+        0xB8, 0x64, 0x6D, 0x43, 0xB6, 0xC2, 0x1E, 0x72, 0x7E, 0x34, 0xD4, 0xDE, 0xA3, 0x82, 0xFC,
+        0x66, 0xEF, 0xB5, 0x59, 0xC1, 0xA9, 0x57, 0x4B, 0xBC, 0xFC, 0x8F, 0x35, 0xA0, 0xA4, 0x10,
+        0x61, 0x02, 0xDC, 0x14, 0xD6, 0xC0, 0xBA, 0x22, 0x22, 0xF7, 0x8A, 0x14, 0x35, 0xC4, 0x87,
+        0xB9, 0xE5, 0x89, 0x15, 0xA4, 0xC4, 0x42, 0x26, 0x8D, 0x3E, 0xE6, 0x17, 0x21, 0x7D, 0x5D,
+        0x3F, 0x03, 0x98, 0x1F, 0x36, 0x92, 0x55, 0x6D, 0x5E, 0xAB, 0x0E, 0xC5, 0xF4, 0x84, 0x35,
+        0xDF, 0xDC, 0x0D, 0x4F, 0x02, 0x28, 0x58, 0xC5, 0xE4, 0x91, 0x6D, 0xAD, 0x34, 0x0C, 0x55,
+        0xE3, 0xBF, 0x29, 0xBA, 0x3B, 0x89, 0xEE, 0x47, 0xCD, 0x1B, 0xB8, 0xBF, 0x79, 0x79, 0x74,
+        0xA3, 0x0D, 0xC2, 0x28, 0x84, 0x90, 0x55, 0x46, 0xAD, 0x40, 0x79, 0x9A, 0x15, 0x04, 0xB2,
+        0x8A, 0x9E, 0x10, 0xFD, 0x7C, 0x3F, 0xF3, 0xBD, 0xB2, 0x5E, 0xF6, 0xED, 0x3C, 0x5C, 0x80,
+        0x44, 0x0D, 0x5F, 0x47, 0x5A, 0x55, 0x89, 0x34, 0xB7, 0x8B, 0x48, 0x8C, 0x87, 0x55, 0x25,
+        0xA5, 0x06, 0xC7, 0x05, 0x81, 0xE8, 0xD3, 0x43, 0x78, 0x0C, 0xA1, 0xEC, 0x49, 0x8E, 0x4F,
+        0x93, 0x98, 0xE1, 0x8A, 0xE9, 0x89, 0xA3, 0x00, 0xE5, 0xE0, 0xF9, 0x78, 0x87, 0xC1, 0x0B,
+        0xE9, 0x26, 0xB1, 0xF6,
+    ];
+    assert_eq!(sk_ref, sk);
+
+    Ok(())
+}
+
+#[test]
+fn test_enc_kat1() -> R {
+    each_backend(enc_kat1)
+}
+
+fn enc_kat1() -> R {
+    let pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut rng = AesState::new();
+    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+    let mut ss = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_enc(&mut ct, &mut ss, &pk, &mut rng)?;
+
+    let ct_ref = [
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0x03, 0x20, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0xFF, 0x07, 0x00, 0x00, 0x00, 0x10, 0x80, 0xFF,
+        0x03, 0xE0, 0xFF, 0x01, 0xF8, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFC, 0x1F, 0x00, 0x00,
+        0x00, 0xC0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0xFF, 0x07, 0x00, 0x00, 0x00,
+        0xF0, 0x7F, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xFF, 0x07, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x3F, 0x00, 0x01, 0x00, 0x40, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xFC, 0x1F, 0x00, 0x01, 0x08, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFC, 0x1F,
+        0x00, 0x00, 0xF8, 0xFF, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x07, 0x00,
+        0x00, 0x00, 0x10, 0x80, 0x00, 0x00, 0x20, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x10, 0x80, 0xFF, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0xFC, 0x1F, 0x00, 0x00, 0xF8, 0x3F, 0x00,
+        0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x01, 0x08, 0xC0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0xFC, 0x1F, 0x00, 0x00,
+        0xF8, 0x3F, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00,
+        0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x03,
+        0x20, 0x00, 0xFF, 0xFF, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+        0xC0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x00, 0xF8, 0x3F, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x80, 0xFF, 0x07, 0xE0,
+        0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x03, 0xE0, 0xFF, 0x00, 0xF8, 0x3F, 0x00, 0x00, 0x10, 0x80,
+        0xFF, 0x03, 0xE0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0xF8, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0xFF, 0x07, 0xC0, 0xFF,
+        0x01, 0x00, 0x00, 0x00, 0xFC, 0x1F, 0x00, 0xFF, 0xFF, 0x3F, 0x00, 0x00, 0x00, 0x80, 0xFF,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0xF8, 0x3F, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0xFC, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+        0x00, 0x00, 0x00, 0xFC, 0x1F, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0xFE, 0xFF, 0xFF, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x03, 0x20, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x01, 0x08, 0xC0, 0xFF, 0x01, 0x00,
+        0x80, 0xFF, 0x03, 0x20, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xFC, 0x1F,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x20, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xFE, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x07, 0x40, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0x00, 0xE0, 0xFF,
+        0x00, 0x00, 0x40, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0xFC, 0x3F, 0x00, 0xFF, 0x07, 0x40, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF8, 0x3F, 0x00, 0x00, 0x00, 0x80, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x00,
+        0x00, 0x40, 0x00, 0xFE, 0x0F, 0x80, 0x00, 0x00, 0xE0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFC,
+        0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0xFF, 0x0F,
+        0x40, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF8, 0x3F, 0x00, 0x00, 0x00,
+        0x80, 0xFF, 0xFF, 0x1F, 0x00, 0x01, 0x00, 0xC0, 0xFF, 0x01, 0xF0, 0x7F, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x40,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x07, 0xC0, 0xFF, 0xFF, 0x0F, 0x00,
+        0x00, 0xFC, 0xFF, 0xFF, 0x00, 0x08, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x80, 0xFF, 0x03, 0x00, 0x00, 0xFF, 0x07, 0x00, 0x00, 0xFE, 0xFF, 0xFF, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x10, 0x80, 0x00, 0xFC, 0x3F, 0x00, 0x00,
+        0xF8, 0x3F, 0x00, 0x02, 0xF0, 0x7F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x01, 0x08, 0x00, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00, 0x00, 0xE0, 0xFF, 0x00, 0x00,
+        0x00, 0x00, 0xFE, 0xFF, 0x7F, 0x00, 0x00, 0x20, 0x00, 0x00, 0x08, 0xC0, 0xFF, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0xE0, 0xFF, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0xF8, 0x3F, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x80, 0xFF, 0x03, 0xE0, 0xFF, 0x00, 0x00, 0x40, 0x00, 0x02, 0xF0, 0xFF,
+        0x00, 0x04, 0xE0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x01, 0x08, 0x00, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xF0, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xFC, 0x1F, 0x00, 0xFF, 0x07, 0x00, 0x00, 0x02, 0x00, 0x80, 0xFF, 0xFF, 0x3F, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x04, 0x00, 0x00, 0xFF, 0x07, 0x40, 0x00, 0xFE,
+        0x0F, 0x80, 0xFF, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0x04,
+        0xE0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0xE0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+    ];
+    assert_eq!(ct_ref, ct);
+    let ss_ref = [
+        0xEA, 0x5A, 0x85, 0x2E, 0x7A, 0x1B, 0x6F, 0x6C, 0x9F, 0xE8, 0x59, 0x97, 0xD4, 0x6D, 0x51,
+        0x82, 0x27, 0xCD, 0x7A, 0x1E, 0x0C, 0x81, 0xA4, 0x72, 0x38, 0x55, 0x19, 0x33, 0x03, 0x98,
+        0x19, 0x0E,
+    ];
+    assert_eq!(ss_ref, ss);
+
+    Ok(())
+}
+
+#[test]
+fn test_dec_kat1() -> R {
+    each_backend(dec_kat1)
+}
+
+fn dec_kat1() -> R {
+    let ct = [
+        0xA1, 0xD9, 0xCE, 0x59, 0x58, 0xDA, 0xCA, 0x0F, 0x97, 0x99, 0xC9, 0xAE, 0x5D, 0x43, 0x95,
+        0xCB, 0x63, 0x68, 0x37, 0x1B, 0xB9, 0xF9, 0x3F, 0x90, 0x6D, 0xAE, 0x55, 0x2F, 0x52, 0x9B,
+        0x1C, 0xA5, 0xDF, 0xEF, 0x9B, 0xFC, 0xBC, 0xED, 0x6C, 0x74, 0xF3, 0xF1, 0x89, 0xF1, 0xA1,
+        0x65, 0xEE, 0x30, 0xA9, 0x0B, 0x24, 0x6D, 0xC7, 0x29, 0xFA, 0x08, 0xD1, 0x4D, 0xC8, 0x26,
+        0x47, 0xE2, 0xED, 0xDC, 0xFB, 0x9B, 0xB2, 0x42, 0xDB, 0x2E, 0xDF, 0xBD, 0x70, 0xF5, 0x55,
+        0x24, 0x10, 0x28, 0xA8, 0x0A, 0x66, 0x6A, 0xDF, 0xE7, 0xE5, 0x97, 0xE7, 0x1D, 0x4B, 0x43,
+        0xC0, 0x72, 0xED, 0xB5, 0x43, 0x90, 0xE9, 0xD8, 0xF5, 0xA7, 0x6E, 0xB8, 0x5F, 0xB1, 0xCF,
+        0xE5, 0x62, 0xDC, 0xCF, 0xE3, 0x33, 0xC3, 0xFA, 0x8E, 0xD2, 0x22, 0x29, 0x98, 0xA5, 0x83,
+        0x28, 0x4E, 0x75, 0xA6, 0x5E, 0x5F, 0xFF, 0x9D, 0xF5, 0x1F, 0x05, 0x49, 0xB5, 0xC8, 0xC0,
+        0xC1, 0x2D, 0x40, 0x89, 0xA2, 0x12, 0x1D, 0x9D, 0xA1, 0xDA, 0xCF, 0xEB, 0xA4, 0xE6, 0x91,
+        0x9A, 0xBB, 0xC5, 0x80, 0xAA, 0xB4, 0xEF, 0xB9, 0xFA, 0x34, 0x1A, 0x07, 0x3C, 0xFF, 0xA4,
+        0x0E, 0x5E, 0xBC, 0xCB, 0x7E, 0xD8, 0x4C, 0x85, 0x97, 0x14, 0x7B, 0xB8, 0x88, 0xB5, 0x0E,
+        0xBC, 0xB4, 0xA0, 0x61, 0x8E, 0x81, 0xD7, 0x3A, 0x64, 0xBB, 0xC5, 0x46, 0x5D, 0xA6, 0x4A,
+        0x52, 0x50, 0x98, 0xE2, 0x76, 0x47, 0x62, 0x7B, 0xFD, 0x0F, 0x91, 0xD5, 0xD6, 0x36, 0x75,
+        0x4A, 0x43, 0x10, 0xF5, 0xD3, 0x4E, 0x3B, 0xBE, 0x35, 0x02, 0xBA, 0xDA, 0xAF, 0x0A, 0x58,
+        0x98, 0x55, 0xF3, 0xAB, 0x64, 0xE1, 0xF2, 0xDB, 0xF7, 0xBE, 0x8F, 0x7A, 0x25, 0x7B, 0x55,
+        0x3F, 0x4A, 0x1B, 0xC1, 0x2D, 0xAB, 0xA1, 0x63, 0x42, 0x0D, 0x17, 0x82, 0xFE, 0x48, 0x30,
+        0xE0, 0x13, 0xE1, 0x73, 0x97, 0xFF, 0x01, 0x2F, 0x5F, 0x69, 0x88, 0xBA, 0xF8, 0x9F, 0xDB,
+        0xE6, 0x1E, 0xB1, 0x15, 0x03, 0x00, 0x30, 0x9C, 0x00, 0xDE, 0xF1, 0xF0, 0x55, 0xB9, 0x0D,
+        0x1F, 0x59, 0xAC, 0x34, 0x95, 0x28, 0xFE, 0x94, 0xEB, 0xFA, 0x2D, 0xF6, 0xDB, 0xDF, 0xB8,
+        0x3A, 0x61, 0x78, 0xF5, 0xF2, 0x2E, 0x7C, 0xAD, 0x8F, 0xC4, 0x4E, 0xD4, 0x6A, 0x9C, 0x58,
+        0x22, 0x35, 0xD6, 0x2A, 0xB5, 0xD7, 0x50, 0xF8, 0x2C, 0xD7, 0x31, 0xAC, 0x5E, 0x30, 0xED,
+        0x69, 0x1D, 0xAF, 0xD0, 0xBF, 0x05, 0x12, 0x57, 0x1C, 0x5A, 0x39, 0xC1, 0xF6, 0x27, 0x20,
+        0x2F, 0x46, 0xCB, 0x5E, 0xE7, 0xF6, 0xCD, 0x8C, 0xD4, 0xD4, 0xFD, 0x3A, 0x68, 0xE6, 0x99,
+        0x6B, 0x9A, 0xB7, 0x7F, 0xA6, 0x86, 0xAB, 0x59, 0x65, 0x70, 0xAE, 0xE2, 0x3A, 0xBB, 0x05,
+        0x21, 0xCD, 0x04, 0x18, 0x57, 0x31, 0x14, 0x1A, 0xE1, 0xFC, 0xFB, 0xE6, 0x18, 0x92, 0x4A,
+        0xC7, 0x62, 0xBF, 0xD0, 0x52, 0x4A, 0x51, 0x08, 0xD1, 0x44, 0x7A, 0xA4, 0x8A, 0xCC, 0x95,
+        0x6F, 0xD0, 0x69, 0x70, 0xF8, 0xD7, 0xBF, 0x90, 0xC4, 0x24, 0xE6, 0xF1, 0x43, 0x79, 0x03,
+        0x23, 0x3A, 0x91, 0xFE, 0xC3, 0xBE, 0x10, 0x64, 0xF8, 0x53, 0xC4, 0x7D, 0xF5, 0xE5, 0x62,
+        0x2F, 0x68, 0xA3, 0x17, 0xD8, 0x4B, 0x77, 0x0A, 0x11, 0x49, 0x42, 0xDC, 0xF7, 0x7C, 0x62,
+        0x00, 0xCE, 0x8D, 0x75, 0x57, 0x63, 0x3D, 0xD9, 0x60, 0x55, 0x81, 0xCC, 0x3B, 0xFC, 0x79,
+        0x17, 0x00, 0x44, 0xF7, 0xE2, 0x12, 0xFF, 0xA6, 0x4A, 0xD8, 0x30, 0x7D, 0x73, 0xF6, 0xD8,
+        0x92, 0x9A, 0x38, 0x54, 0xFA, 0xA3, 0x21, 0x29, 0x96, 0x2C, 0x9F, 0xCF, 0xAF, 0x3E, 0xB1,
+        0xF2, 0xF3, 0xB4, 0xB8, 0x3D, 0xD7, 0xEB, 0x28, 0x25, 0x92, 0x0D, 0x0B, 0xC9, 0xC9, 0xC9,
+        0xE6, 0xC8, 0xE1, 0x29, 0xCC, 0x09, 0x73, 0x0D, 0x42, 0x1E, 0x40, 0x3A, 0xC5, 0xA9, 0x84,
+        0x3B, 0xE7, 0xF4, 0x0E, 0xEC, 0x25, 0xDE, 0x14, 0x4B, 0xAF, 0x75, 0x69, 0x04, 0xFD, 0xF8,
+        0x6C, 0x86, 0x74, 0x20, 0xB9, 0xD6, 0xF0, 0x68, 0x72, 0x03, 0x69, 0x52, 0x4F, 0x4C, 0xAA,
+        0x53, 0xCE, 0xA8, 0x5A, 0xF1, 0x7F, 0x50, 0xCF, 0xCC, 0x55, 0x4D, 0xB2, 0x87, 0xB1, 0x5F,
+        0x13, 0x4E, 0x64, 0x2F, 0xB1, 0x4B, 0x43, 0x1A, 0x00, 0x89, 0x08, 0x7C, 0xFA, 0x47, 0xAC,
+        0xD8, 0x98, 0x5B, 0x56, 0x34, 0x6E, 0xB8, 0x59, 0xCD, 0x3D, 0x2C, 0x9F, 0x9B, 0x14, 0xE0,
+        0xF9, 0xE5, 0xC8, 0x24, 0x8D, 0x75, 0x34, 0xD8, 0xDC, 0x50, 0x29, 0xFE, 0xD0, 0x1A, 0xB2,
+        0x01, 0xB2, 0x7B, 0xF1, 0xB9, 0x03, 0xEA, 0x19, 0x85, 0x92, 0xDA, 0xA2, 0x7A, 0xCA, 0x9C,
+        0xE9, 0xBD, 0xF2, 0x8B, 0x48, 0x31, 0xB2, 0x2C, 0x9E, 0x22, 0x0E, 0xB2, 0x5D, 0x55, 0x62,
+        0x15, 0xC2, 0x34, 0x76, 0x1F, 0x0A, 0x96, 0xB0, 0x85, 0x6A, 0xE2, 0x6F, 0xD9, 0x2E, 0x4B,
+        0x40, 0xF7, 0xE1, 0x3C, 0x3C, 0x35, 0x87, 0xC6, 0x0A, 0xF3, 0xD5, 0xC4, 0x94, 0x30, 0xF7,
+        0x68, 0x94, 0x9D, 0x6E, 0x0C, 0x24, 0x39, 0xED, 0xE3, 0x40, 0x98, 0x58, 0xCA, 0x40, 0x55,
+        0xE5, 0xCA, 0xC3, 0x25, 0xE2, 0x1C, 0xE1, 0xBF, 0xBB, 0x2B, 0xBD, 0x3B, 0xBA, 0x13, 0x6E,
+        0x59, 0xC7, 0xE1, 0xDA, 0x25, 0x98, 0x97, 0x51, 0x89, 0x11, 0x1F, 0x07, 0x30, 0xB6, 0x9E,
+        0x7E, 0x1B, 0x54, 0x77, 0xD1, 0x38, 0x84, 0xAD, 0xD7, 0x0B, 0x3C, 0xA8, 0xA9, 0x55, 0x75,
+        0x62, 0x03, 0x47, 0xCF, 0x50, 0xAF, 0xA7, 0x2E, 0xDE, 0x63, 0x5E, 0xE7, 0x0C, 0x62, 0xB5,
+        0x66, 0xF5, 0xB6, 0xD2, 0x03, 0x1F, 0x3C, 0xDA, 0xBB, 0xE2, 0xDC, 0x95, 0x00, 0xDD, 0xEB,
+        0xE7, 0x60, 0xB4, 0xD8, 0x01, 0x58, 0x83, 0x5C, 0x2B, 0x00, 0xE6, 0xBC, 0x51, 0x4F, 0x2A,
+        0x76, 0x2B, 0xD6, 0x99, 0xEA, 0x97, 0xE7, 0x32, 0x4C, 0xFA, 0x5A, 0x9E, 0xEC, 0x11, 0x42,
+        0x63, 0x96, 0xDC, 0x2E, 0x93, 0xC9, 0x43, 0xC2, 0x09, 0xAF, 0x15, 0x25, 0x49, 0x0E, 0xF3,
+        0xF1, 0xF0, 0x5E, 0xFE, 0x79, 0x77, 0x31, 0xC9, 0xAF, 0xF6, 0x18, 0x58, 0xFB, 0x92, 0xF4,
+        0xD0, 0xC9, 0xB3, 0x4E, 0xA6, 0xAA, 0x18, 0x87, 0x50, 0x1D, 0x7D, 0x96, 0x1F, 0x1E, 0x7D,
+        0x12, 0x56, 0xF7, 0x7E, 0x84, 0xBB, 0xBF, 0x53, 0x81, 0xEF, 0xD5, 0x76, 0x10, 0xEC, 0x4E,
+        0xCC, 0x39, 0x00, 0xB3, 0xA3, 0x77, 0xBE, 0x30, 0x2F, 0x0F, 0x63, 0x3F, 0x1C, 0x6C, 0x0E,
+        0x38, 0xED, 0x93, 0x27, 0xC7, 0x43, 0xD2, 0xEC, 0x4D, 0x7A, 0x2B, 0x06, 0x44, 0x9F, 0xAA,
+        0x5F, 0x9D, 0x5D, 0x15, 0x29, 0x08, 0x32, 0x5D, 0xC7, 0x7F, 0x7C, 0x26, 0x74, 0x97, 0x5B,
+        0x66, 0x2D, 0x8A, 0xC7, 0xF6, 0x7C, 0x78, 0xD5, 0x8F, 0x74, 0xC8, 0xC0, 0x18, 0xD7, 0x7B,
+        0xFA, 0xF4, 0x47, 0xCF, 0x55, 0xA4, 0x6F, 0x79, 0x05, 0x99, 0xE9, 0x9C, 0xCA, 0xF4, 0x0E,
+    ];
+    let sk = [
+        0xD0, 0x67, 0xD9, 0x8F, 0x00, 0x55, 0xE2, 0xC3, 0xDE, 0xEF, 0x10, 0x76, 0xBB, 0xB7, 0x55,
+        0xAF, 0xD0, 0x65, 0x11, 0x2C, 0x85, 0xC4, 0x6E, 0x6C, 0x35, 0x06, 0x55, 0xD9, 0x62, 0x50,
+        0x73, 0xE9, 0x4E, 0x45, 0x28, 0xC0, 0x53, 0xE7, 0x20, 0x20, 0x6C, 0xDB, 0x78, 0x0A, 0x61,
+        0xAD, 0x51, 0x20, 0xC4, 0x98, 0xA4, 0xCD, 0x3E, 0x60, 0xC3, 0x34, 0xD8, 0x70, 0x2F, 0x0F,
+        0x79, 0xD8, 0x14, 0x18, 0xAF, 0x95, 0x9C, 0xAB, 0x77, 0x22, 0xEB, 0xDD, 0x30, 0xDF, 0x0A,
+        0x47, 0x9E, 0x14, 0x03, 0xD3, 0x37, 0xAD, 0x92, 0x3E, 0x5D, 0xBC, 0xED, 0x4E, 0xE0, 0x48,
+        0x39, 0x02, 0x0F, 0x77, 0x51, 0xC0, 0x75, 0xA4, 0x89, 0x2A, 0xAB, 0xBB, 0x0E, 0x00, 0x1A,
+        0xBA, 0x95, 0x51, 0x5E, 0x1B, 0x65, 0xC6, 0x3C, 0x50, 0x3D, 0x97, 0xE5, 0x1E, 0xAF, 0xB9,
+        0xAC, 0x93, 0x0F, 0x5A, 0x1D, 0xBC, 0xB7, 0xE9, 0x38, 0x8B, 0x5E, 0xF1, 0xE7, 0x5E, 0x89,
+        0x02, 0x0D, 0x1E, 0x51, 0x81, 0xB4, 0xD1, 0x20, 0x60, 0xB6, 0xA8, 0xA8, 0x14, 0x3F, 0x27,
+        0x5E, 0x38, 0xD5, 0x59, 0xD5, 0xF1, 0x8A, 0x15, 0x0F, 0x49, 0xE0, 0xCB, 0x73, 0xA2, 0x97,
+        0x2D, 0xB8, 0x4F, 0x57, 0xDA, 0x46, 0xEF, 0x4D, 0x8A, 0xD1, 0x1F, 0x2B, 0x4F, 0x5A, 0x95,
+        0x26, 0xA7, 0x79, 0x01, 0xBA, 0x60, 0x0F, 0xC6, 0xE9, 0xC9, 0xE6, 0xF0, 0x7A, 0xC2, 0xD9,
+        0xAD, 0x24, 0x40, 0xBC, 0x2B, 0xB1, 0x71, 0x43, 0x9A, 0xED, 0x32, 0x96, 0x43, 0x5D, 0x3B,
+        0x7F, 0x10, 0x2C, 0xE3, 0x13, 0x5D, 0x0D, 0x51, 0xC4, 0xDA, 0x05, 0x2E, 0x91, 0x63, 0xC6,
+        0xA1, 0x64, 0x6E, 0xD8, 0x0F, 0xCA, 0x39, 0x09, 0x60, 0xE3, 0xC4, 0x02, 0x24, 0x1F, 0x5F,
+        0xC0, 0x53, 0xBC, 0x57, 0x03, 0x55, 0x8A, 0xD9, 0xE9, 0xCC, 0xDB, 0x5A, 0xC4, 0x28, 0x4B,
+        0x6A, 0x69, 0xD3, 0x6C, 0x04, 0x2E, 0x29, 0x7C, 0x9C, 0x48, 0xC6, 0x1A, 0x85, 0x73, 0x5D,
+        0x8C, 0x02, 0x0D, 0x2F, 0x43, 0x77, 0x43, 0x68, 0xCC, 0x18, 0x35, 0x38, 0xFF, 0xE6, 0xD7,
+        0x02, 0x10, 0x29, 0xA3, 0xE5, 0xF1, 0xEC, 0x56, 0xF5, 0xEE, 0x9E, 0xCB, 0xC0, 0x9F, 0x5F,
+        0xE0, 0x92, 0xF8, 0x76, 0x57, 0xD3, 0x41, 0x2B, 0x3F, 0x66, 0xF0, 0x5C, 0x99, 0xCA, 0x6F,
+        0x27, 0x04, 0xB4, 0x3F, 0xE5, 0x42, 0x2D, 0x34, 0x2B, 0x48, 0x17, 0xCF, 0x3A, 0x51, 0xE6,
+        0x2F, 0xAE, 0x28, 0x21, 0xD7, 0xA0, 0xCB, 0xA8, 0x35, 0x18, 0x79, 0x45, 0x11, 0x20, 0x05,
+        0x8B, 0xD8, 0x63, 0xD1, 0x89, 0xEC, 0x80, 0x75, 0x04, 0x89, 0xF8, 0xDE, 0xB4, 0x35, 0xD3,
+        0x9E, 0xA1, 0x03, 0x47, 0xF7, 0xD0, 0xD0, 0x41, 0x9D, 0x4A, 0x9A, 0xDD, 0x5B, 0x24, 0x40,
+        0x2D, 0xAA, 0x99, 0xB5, 0x5C, 0x94, 0xC5, 0xB7, 0x1C, 0x5B, 0x35, 0xDD, 0x7F, 0xAC, 0x20,
+        0x4B, 0x98, 0x4D, 0xE3, 0x39, 0x69, 0x0D, 0x6D, 0xF8, 0x9E, 0x27, 0x6E, 0x85, 0x5A, 0x63,
+        0x56, 0x77, 0xBB, 0xEE, 0x38, 0x0A, 0x1C, 0x21, 0x75, 0x1B, 0x6D, 0x0F, 0x13, 0x14, 0x5F,
+        0xE4, 0x76, 0x9A, 0x01, 0x03, 0x72, 0x0A, 0xFF, 0x6F, 0x14, 0x18, 0x1A, 0x42, 0x49, 0x23,
+        0x03, 0x55, 0x3E, 0xFF, 0x46, 0x90, 0xBC, 0x39, 0x7D, 0x4D, 0x43, 0xBF, 0xBF, 0x30, 0x9A,
+        0x58, 0x90, 0x6F, 0x32, 0xFD, 0x54, 0x9A, 0x6B, 0xE5, 0x5A, 0xAD, 0x7E, 0x12, 0x29, 0xCA,
+        0xAA, 0xD7, 0x18, 0x21, 0x4C, 0x6F, 0xEE, 0x36, 0x90, 0xA4, 0x55, 0x32, 0xB2, 0xC4, 0xE8,
+        0x2B, 0xC4, 0xB5, 0x4F, 0x2D, 0x8C, 0xA4, 0x30, 0x20, 0xA2, 0xF1, 0x66, 0x30, 0x68, 0x60,
+        0xD3, 0x01, 0xD5, 0x63, 0xF0, 0x1C, 0xA3, 0xBD, 0xE5, 0x9A, 0x3F, 0xC7, 0x9D, 0xBC, 0x7A,
+        0x62, 0x20, 0xFF, 0x77, 0x93, 0x3E, 0x2E, 0x5B, 0x59, 0xBC, 0x93, 0x8C, 0xB8, 0x6B, 0x31,
+        0xD7, 0x41, 0x63, 0xB4, 0xF3, 0x7A, 0x0B, 0xD9, 0xC5, 0x80, 0x82, 0xF1, 0xED, 0xC8, 0x9F,
+        0xD5, 0x2D, 0xDC, 0xB6, 0x33, 0xC0, 0x01, 0x0C, 0x01, 0xE0, 0x99, 0x3D, 0xF8, 0x67, 0xFE,
+        0x63, 0x29, 0x9C, 0x01, 0x0A, 0xF0, 0x76, 0x78, 0x99, 0xB4, 0x5B, 0x05, 0xB9, 0xBD, 0x8A,
+        0x76, 0x48, 0x7D, 0x58, 0x28, 0x39, 0x1D, 0xE2, 0x57, 0x9B, 0x7B, 0x65, 0x3C, 0xC0, 0xCC,
+        0x4C, 0x1E, 0x69, 0x4F, 0x71, 0x80, 0xF3, 0xB1, 0x20, 0x9F, 0x27, 0xC6, 0xE7, 0xEB, 0xE3,
+        0x67, 0xE6, 0x27, 0x16, 0x67, 0xB2, 0xE1, 0x64, 0xC9, 0x4D, 0x0D, 0xDE, 0x36, 0xE3, 0x25,
+        0x52, 0xDD, 0xC0, 0x13, 0x55, 0xB3, 0x6D, 0x86, 0x57, 0x2D, 0x82, 0x08, 0x57, 0x3F, 0x0A,
+        0x2C, 0x30, 0x91, 0x21, 0x32, 0x8E, 0x56, 0xA6, 0xA5, 0x0F, 0x0B, 0x6A, 0xCE, 0x20, 0x2C,
+        0x37, 0x6C, 0x93, 0x73, 0x76, 0xF6, 0x42, 0xF2, 0x69, 0x27, 0x99, 0x23, 0x37, 0x36, 0x81,
+        0xDE, 0x9F, 0x9F, 0xC7, 0x37, 0x5E, 0x75, 0xF3, 0x43, 0x33, 0x33, 0xD5, 0x8D, 0x72, 0x1F,
+        0xBB, 0xEC, 0x08, 0xCB, 0x99, 0xDD, 0x82, 0x97, 0x7F, 0x4B, 0x3D, 0xF9, 0x07, 0xD6, 0x40,
+        0xEF, 0xDC, 0x19, 0xDD, 0xD5, 0xC2, 0xC6, 0x37, 0x4B, 0x6F, 0xB2, 0xC6, 0x73, 0x4B, 0x85,
+        0x08, 0xDD, 0xBC, 0xB8, 0x2E, 0x69, 0x26, 0xAD, 0x12, 0x6F, 0x34, 0x73, 0x9A, 0x62, 0xE5,
+        0xB0, 0xF6, 0x6F, 0x3C, 0xA2, 0x0C, 0x42, 0xE8, 0xDC, 0x0E, 0xDF, 0x4B, 0xB9, 0x72, 0x27,
+        0x33, 0x4E, 0x5C, 0x3A, 0x90, 0xD1, 0xA5, 0xA3, 0x92, 0xD7, 0x34, 0x1B, 0x37, 0x07, 0xF0,
+        0x24, 0x59, 0xD1, 0x2F, 0xFF, 0x12, 0x08, 0xD0, 0xC8, 0x8A, 0x2B, 0x7C, 0x61, 0x78, 0x88,
+        0x7E, 0x42, 0xD8, 0x2A, 0xD7, 0x02, 0x1C, 0x09, 0x30, 0xCF, 0x67, 0x23, 0x33, 0x2D, 0xD2,
+        0x0B, 0xB4, 0xAB, 0x81, 0x42, 0xBB, 0xF5, 0xD7, 0x96, 0x9F, 0x2B, 0x96, 0x20, 0x58, 0xE7,
+        0x8F, 0xC8, 0x71, 0xA3, 0x3E, 0x53, 0x00, 0x26, 0xDE, 0x24, 0x90, 0x40, 0x5D, 0xD4, 0x40,
+        0x84, 0xD1, 0x30, 0x0C, 0xF9, 0x87, 0x02, 0xEB, 0x53, 0x82, 0x40, 0x2F, 0x81, 0xA4, 0xBD,
+        0xD3, 0xB8, 0x51, 0x4F, 0x0E, 0x76, 0x38, 0x26, 0x0A, 0xB4, 0x18, 0xA4, 0x67, 0x3F, 0x7D,
+        0xF3, 0x59, 0x5B, 0x96, 0x17, 0x0B, 0x41, 0xBB, 0x87, 0x59, 0x5A, 0x41, 0xC5, 0xBE, 0xF5,
+        0x6C, 0xDD, 0xA8, 0x31, 0x78, 0x1E, 0x13, 0xCB, 0xEB, 0x3C, 0xAE, 0x75, 0x6C, 0x6C, 0xDC,
+        0x73, 0xBC, 0x2A, 0xF8, 0x2F, 0x7E, 0xA1, 0xD1, 0x82, 0x1B, 0x2F, 0x55, 0x97, 0xA7, 0x56,
+        0x13, 0x67, 0xE1, 0x4A, 0xA5, 0x13, 0xBA, 0x9F, 0xC7, 0x13, 0x82, 0x38, 0x10, 0xB8, 0xC7,
+        0xA5, 0xD7, 0xAA, 0xB8, 0x1E, 0x5F, 0xFF, 0x62, 0x49, 0xF8, 0x03, 0x42, 0xC7, 0xE5, 0x73,
+        0xA6, 0xC6, 0x0C, 0x8E, 0x82, 0xCF, 0x39, 0x94, 0x7B, 0xC8, 0x20, 0xF0, 0xC7, 0x71, 0x0C,
+        0xB3, 0x20, 0x1C, 0x17, 0x89, 0x1E, 0xEF, 0xCD, 0x89, 0xE3, 0xB1, 0xCC, 0x50, 0x65, 0x99,
+        0x3B, 0x4D, 0x48, 0xBE, 0xC3, 0xF2, 0x98, 0xC4, 0xF3, 0xEB, 0x23, 0x12, 0x5A, 0x4E, 0xEC,
+        0xF5, 0xD1, 0xA2, 0x9D, 0xCD, 0xFC, 0x60, 0xC9, 0x6B, 0x40, 0xF5, 0x84, 0x99, 0x6D, 0x0B,
+        0x91, 0x15, 0x4A, 0xFD, 0xDF, 0xBA, 0x2C, 0x19, 0xDA, 0x34, 0xD8, 0x23, 0x89, 0xF7, 0x36,
+        0x1F, 0xCB, 0xBA, 0xC6, 0x9F, 0x2A, 0x88, 0x33, 0x65, 0x39, 0xB0, 0x20, 0x40, 0x3F, 0x3A,
+        0x19, 0xB9, 0x2D, 0x5B, 0xB6, 0x56, 0x10, 0x11, 0x6C, 0xED, 0x92, 0x15, 0x1C, 0xE2, 0xD1,
+        0x76, 0xC2, 0x77, 0x78, 0xB0, 0x8A, 0x7D, 0x4A, 0xAF, 0x6D, 0xA4, 0x74, 0xD1, 0xB3, 0x3C,
+        0xA0, 0xC1, 0x39, 0x05, 0x48, 0x3B, 0x0A, 0xC8, 0x4C, 0x53, 0x51, 0xFC, 0x3B, 0x7A, 0xBE,
+        0xCD, 0x57, 0xDA, 0xDC, 0x12, 0x6A, 0x62, 0x10, 0xC4, 0x52, 0x88, 0xF5, 0x69, 0x57, 0x7A,
+        0x52, 0xF2, 0x99, 0x19, 0x2B, 0x1B, 0x6F, 0x41, 0x4B, 0x29, 0x23, 0x71, 0x9E, 0x78, 0x38,
+        0x53, 0x2E, 0x4D, 0xA6, 0xC3, 0x5B, 0xAF, 0xDE, 0x56, 0x20, 0xCF, 0xD2, 0xAB, 0x75, 0x1D,
+        0x46, 0x52, 0xDB, 0x3D, 0x06, 0x24, 0x77, 0xB5, 0xC9, 0x62, 0x63, 0x01, 0x23, 0xD6, 0x80,
+        0x16, 0xD8, 0xD7, 0xAB, 0xE3, 0x25, 0xEF, 0x4D, 0x20, 0x11, 0x8F, 0x21, 0x7C, 0x74, 0x66,
+        0x6C, 0xB1, 0x42, 0x1E, 0x74, 0x56, 0x95, 0x8F, 0x25, 0xD2, 0x34, 0x94, 0x29, 0xB1, 0x24,
+        0x32, 0x80, 0xCD, 0xB0, 0xD0, 0xF2, 0x2E, 0x8F, 0xDE, 0xC6, 0xE9, 0x1E, 0x51, 0x45, 0xD4,
+        0xA0, 0x08, 0x5C, 0x07, 0x7E, 0xAE, 0x0E, 0xB3, 0xB5, 0xCD, 0x73, 0xDA, 0xF3, 0xD1, 0xF0,
+        0x5C, 0x29, 0x70, 0xF3, 0xE9, 0x62, 0xB6, 0x65, 0xA6, 0x04, 0xDC, 0xB5, 0x88, 0x87, 0xA0,
+        0x83, 0xCC, 0x5A, 0x91, 0x1F, 0x40, 0xEF, 0x6B, 0x78, 0x00, 0x9B, 0x14, 0x31, 0xC1, 0x5C,
+        0xCE, 0x02, 0xB8, 0xC0, 0x84, 0x1D, 0xAF, 0x4D, 0x39, 0x75, 0x0F, 0xD7, 0x3E, 0xB8, 0xA5,
+        0x00, 0x42, 0xBC, 0xA6, 0x13, 0x9F, 0x76, 0x44, 0x81, 0xFC, 0x96, 0xCE, 0xB4, 0x68, 0xCD,
+        0xAC, 0x15, 0x76, 0xAE,
+    ];
+    let mut ss1 = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_dec(&mut ss1, &ct, &sk)?;
+
+    let ss_ref = [
+        0x49, 0xAC, 0x4D, 0x5D, 0x16, 0x34, 0xC6, 0xAF, 0xFA, 0x5A, 0x08, 0xC2, 0xB2, 0x28, 0xEC,
+        0x80, 0x6D, 0x78, 0x70, 0xB1, 0x51, 0x79, 0x90, 0x72, 0x86, 0x63, 0xD2, 0xD8, 0xBB, 0xC1,
+        0x84, 0xF2,
+    ];
+    assert_eq!(ss_ref, ss1);
+
+    Ok(())
+}
+
+#[test]
+fn test_poly_sq_tobytes() {
+    let mut a = Poly::new();
+    a.coeffs = [7; NTRU_N];
+    let mut r = [0u8; 1 + 11 * NTRU_PACK_DEG / 8];
+    poly_sq_tobytes(&mut r, &a);
+    let expected_r = [
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70,
+        0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0,
+        0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0,
+        0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80,
+        0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00,
+        0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01,
+        0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03,
+        0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07,
+        0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E,
+        0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C,
+        0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38,
+        0xC0, 0x01, 0x0E, 0x70, 0x80, 0x03, 0x1C, 0xE0, 0x00, 0x07, 0x38, 0xC0, 0x01, 0x0E, 0x00,
+    ];
+    assert_eq!(r, expected_r);
+}
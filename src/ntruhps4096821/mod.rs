@@ -0,0 +1,107 @@
+//! NTRU-HPS (Hoffstein-Pipher-Silverman) variant with n = 821 and q = 4096
+
+/// Constants distinguishing this variant from the other ones
+mod variant {
+    /// Name of the variant
+    pub const CRYPTO_ALGNAME: &str = "ntruhps4096821";
+    pub const NTRU_HPS: bool = true;
+    pub const NTRU_N: usize = 821;
+    pub const NTRU_LOGQ: usize = 12;
+
+    /// SHA3-256 digest of `pk` of the first NIST KAT (`count = 0`)
+    pub const KAT_PK_SHA3: [u8; 32] = [
+        0xA1, 0x6A, 0xB9, 0x26, 0x88, 0x92, 0xC6, 0x25, 0x61, 0xFF, 0x4C, 0xB3, 0x0A, 0xAD, 0x3D,
+        0xA2, 0xEB, 0x0A, 0xCC, 0x48, 0x43, 0x06, 0x93, 0x7A, 0xCA, 0xEE, 0xA0, 0xE2, 0xB8, 0x11,
+        0xA3, 0xA0,
+    ];
+    /// SHA3-256 digest of `sk` of the first NIST KAT
+    pub const KAT_SK_SHA3: [u8; 32] = [
+        0xF1, 0x41, 0x45, 0x78, 0xE1, 0x4D, 0x82, 0xF0, 0x8D, 0x4A, 0xD6, 0x47, 0x73, 0x8E, 0xF4,
+        0x14, 0x92, 0x3D, 0x85, 0x95, 0xAE, 0x18, 0xA6, 0xFE, 0x39, 0xAB, 0x01, 0xCE, 0x8C, 0x06,
+        0x48, 0x20,
+    ];
+    /// SHA3-256 digest of `ct` of the first NIST KAT
+    pub const KAT_CT_SHA3: [u8; 32] = [
+        0x0A, 0x33, 0xDC, 0x70, 0x94, 0xD3, 0xC4, 0xE3, 0x43, 0x5F, 0x5D, 0x4D, 0xBF, 0xCE, 0x49,
+        0xB7, 0x47, 0xBD, 0x6E, 0x1F, 0xB3, 0x42, 0x33, 0x4F, 0x9B, 0xDA, 0xE2, 0x22, 0x40, 0x3E,
+        0x21, 0x88,
+    ];
+    /// `ss` of the first NIST KAT
+    pub const KAT_SS: [u8; 32] = [
+        0x29, 0x39, 0x92, 0x00, 0x0D, 0xC2, 0x88, 0xE8, 0x15, 0x2F, 0x94, 0x51, 0xF0, 0x6D, 0xD8,
+        0x35, 0xC7, 0x5E, 0xA0, 0x08, 0x66, 0x2B, 0xAC, 0xE0, 0xFB, 0x97, 0xA9, 0x7B, 0x3A, 0xFB,
+        0x54, 0xE4,
+    ];
+
+    /// `CRYPTO_CIPHERTEXTBYTES` as type-level integer
+    #[cfg(feature = "kem")]
+    pub type CiphertextSize = kem::generic_array::typenum::Sum<
+        kem::generic_array::typenum::U1024,
+        kem::generic_array::typenum::U206,
+    >;
+}
+
+#[path = "../api.rs"]
+mod api;
+#[path = "../cmov.rs"]
+mod cmov;
+#[path = "../kat.rs"]
+mod kat;
+#[path = "../kem.rs"]
+mod kem;
+#[cfg(feature = "kem")]
+#[path = "../kem_traits.rs"]
+mod kem_traits;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+#[path = "../locked_sk.rs"]
+mod locked_sk;
+#[cfg(feature = "masked")]
+#[path = "../masked.rs"]
+mod masked;
+#[path = "../owcpa.rs"]
+mod owcpa;
+#[path = "../pack3.rs"]
+mod pack3;
+#[path = "../packq.rs"]
+mod packq;
+#[path = "../params.rs"]
+mod params;
+#[path = "../poly.rs"]
+mod poly;
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+#[path = "../poly_avx2.rs"]
+mod poly_avx2;
+#[path = "../poly_divstep.rs"]
+mod poly_divstep;
+#[path = "../poly_lift.rs"]
+mod poly_lift;
+#[path = "../poly_mod.rs"]
+mod poly_mod;
+#[path = "../poly_r2_inv.rs"]
+mod poly_r2_inv;
+#[path = "../poly_rq_mul.rs"]
+mod poly_rq_mul;
+#[path = "../poly_s3_bitsliced.rs"]
+mod poly_s3_bitsliced;
+#[path = "../poly_s3_inv.rs"]
+mod poly_s3_inv;
+#[path = "../poly_ternary.rs"]
+mod poly_ternary;
+#[path = "../sample.rs"]
+mod sample;
+#[path = "../sample_iid.rs"]
+mod sample_iid;
+#[path = "../types.rs"]
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::api::*;
+pub use self::kat::*;
+pub use self::kem::*;
+#[cfg(feature = "kem")]
+pub use self::kem_traits::*;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+pub use self::locked_sk::*;
+pub use self::types::*;
@@ -0,0 +1,648 @@
+use std::error;
+
+use super::packq::poly_sq_tobytes;
+use super::params::{NTRU_N, NTRU_PACK_DEG};
+use super::poly::Poly;
+use super::*;
+use crate::rng::AesState;
+
+type R = Result<(), Box<dyn error::Error>>;
+
+/// Run a known-answer test with every implementation of the ring multiplications
+fn each_backend(test: fn() -> R) -> R {
+    #[cfg(feature = "avx2")]
+    return crate::backend::for_each_backend(test);
+    #[cfg(not(feature = "avx2"))]
+    test()
+}
+
+#[test]
+fn test_api_sizes() {
+    assert_eq!(CRYPTO_PUBLICKEYBYTES, 1230);
+    assert_eq!(CRYPTO_SECRETKEYBYTES, 1590);
+    assert_eq!(CRYPTO_CIPHERTEXTBYTES, 1230);
+    assert_eq!(CRYPTO_BYTES, 32);
+}
+
+#[test]
+fn test_keypair_zeroed_rng() -> R {
+    each_backend(keypair_zeroed_rng)
+}
+
+fn keypair_zeroed_rng() -> R {
+    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+    let mut rng = AesState::new();
+
+    crypto_kem_keypair(&mut pk, &mut sk, &mut rng)?;
+
+    let pk_ref = [
+        0x41, 0xA1, 0xAC, 0xD5, 0x8C, 0xCA, 0x3D, 0xFF, 0x95, 0x03, 0x2A, 0xCD, 0x49, 0xE5, 0x04,
+        0x9A, 0x94, 0x0C, 0xE3, 0xA3, 0xE4, 0x25, 0x5F, 0x69, 0x1D, 0x5C, 0xC6, 0x66, 0xED, 0xE7,
+        0x4E, 0x14, 0xE3, 0xEA, 0x40, 0xF3, 0xBE, 0xBB, 0xDE, 0x2F, 0x7F, 0x4B, 0x9D, 0xD9, 0xBB,
+        0x44, 0x76, 0x22, 0x8E, 0x6D, 0x42, 0xF5, 0xFC, 0xA3, 0xB5, 0x94, 0x26, 0xAA, 0xE2, 0x19,
+        0x58, 0xF6, 0xDE, 0xD8, 0xC9, 0x64, 0xB5, 0xFD, 0x6C, 0xA6, 0xF9, 0xE3, 0xE7, 0x60, 0xB4,
+        0x3C, 0x5C, 0x2E, 0x5A, 0x9A, 0xCF, 0x95, 0xF0, 0x7B, 0xD7, 0xFF, 0x4D, 0x23, 0x50, 0xB6,
+        0x23, 0x16, 0x8F, 0x2D, 0x39, 0xCB, 0x48, 0xDF, 0xA2, 0x98, 0xA9, 0x52, 0x20, 0x5D, 0x3A,
+        0xB1, 0x32, 0x94, 0xA6, 0x57, 0x68, 0x1E, 0xB3, 0x9C, 0x11, 0x4A, 0x48, 0x5E, 0x42, 0x77,
+        0xC5, 0x4A, 0x83, 0x74, 0xB7, 0x7C, 0xF2, 0x4E, 0x8E, 0xF1, 0x4C, 0xAD, 0x16, 0x1B, 0x91,
+        0x3A, 0x89, 0xEA, 0x85, 0x97, 0xFD, 0xB5, 0xF1, 0x64, 0x5E, 0x95, 0x74, 0x70, 0x69, 0xE5,
+        0x24, 0x42, 0xD4, 0x00, 0xB7, 0xEC, 0x88, 0xEE, 0x68, 0x08, 0x9E, 0xD3, 0x18, 0x61, 0x7D,
+        0xE5, 0x07, 0x31, 0x20, 0x60, 0x01, 0xCE, 0x26, 0xF2, 0x33, 0x3A, 0xCB, 0xCA, 0x74, 0x70,
+        0x5C, 0x6D, 0xDC, 0x18, 0xA8, 0x1E, 0xD4, 0x6C, 0x51, 0xF2, 0xA9, 0x1C, 0xC7, 0x23, 0x9D,
+        0x28, 0x75, 0xF8, 0x58, 0xCE, 0x46, 0xCD, 0xEF, 0x8D, 0xDD, 0x96, 0x62, 0xEB, 0xE0, 0x36,
+        0x09, 0x6E, 0x98, 0x5C, 0x82, 0x70, 0xF0, 0x26, 0xE5, 0x74, 0x53, 0xCA, 0x08, 0x6A, 0x7E,
+        0xE8, 0x5D, 0x32, 0x24, 0x22, 0x61, 0xE1, 0xFB, 0x9D, 0x40, 0xD4, 0xE3, 0x46, 0x2E, 0x7D,
+        0x90, 0x9E, 0x1E, 0xEF, 0xE8, 0x76, 0x3D, 0xBE, 0x37, 0x2D, 0xC0, 0x79, 0x04, 0xD6, 0x40,
+        0xED, 0x20, 0x4F, 0xE0, 0x12, 0xCB, 0x29, 0xB5, 0xFD, 0x4F, 0x26, 0x1A, 0x30, 0x61, 0xEF,
+        0x45, 0xCE, 0x57, 0xC1, 0x78, 0x6A, 0xAC, 0xB2, 0x8C, 0xC6, 0x7C, 0x52, 0x04, 0xBC, 0x2D,
+        0xEC, 0x67, 0x04, 0xEC, 0xF2, 0x80, 0xDE, 0x29, 0xEC, 0xB1, 0x68, 0x9F, 0xD7, 0x3C, 0x0F,
+        0x98, 0x31, 0x75, 0x81, 0x23, 0x55, 0xBA, 0xD4, 0xCC, 0x3F, 0x85, 0x89, 0x0A, 0x67, 0x91,
+        0x48, 0x2A, 0x03, 0xF2, 0xB3, 0x1B, 0xF6, 0x27, 0xFC, 0xEA, 0x5B, 0x7E, 0x4B, 0xD4, 0x02,
+        0x49, 0x34, 0x05, 0x30, 0xD7, 0x38, 0x67, 0x39, 0xAA, 0xBD, 0x19, 0xEB, 0xD1, 0x5C, 0x21,
+        0x97, 0x7A, 0xEE, 0x86, 0x72, 0x22, 0xC4, 0xF2, 0xAE, 0x54, 0xB5, 0xA9, 0xC7, 0xFC, 0xDD,
+        0xF4, 0xB1, 0x15, 0x5A, 0xC0, 0x02, 0x40, 0x12, 0x06, 0x6B, 0xCB, 0xC7, 0xC5, 0x20, 0x46,
+        0x1E, 0x92, 0x1D, 0x05, 0x0B, 0x3F, 0x5D, 0x66, 0x1C, 0x2B, 0x4D, 0xEF, 0x82, 0xB4, 0x02,
+        0x18, 0x45, 0xA4, 0x67, 0x60, 0x7D, 0x98, 0xEB, 0x77, 0x6F, 0x20, 0xD6, 0x9E, 0xB7, 0x0C,
+        0xD2, 0x1D, 0x04, 0xFF, 0x4E, 0x8F, 0xE3, 0x9F, 0xB9, 0x1F, 0xD3, 0xA3, 0xBC, 0x2E, 0x1D,
+        0xB3, 0x47, 0xBA, 0x6D, 0xA3, 0xBD, 0xCD, 0xFD, 0x07, 0x14, 0xB0, 0x52, 0x2F, 0x05, 0x02,
+        0xE6, 0x66, 0x79, 0x44, 0x61, 0x4C, 0xA8, 0xEA, 0x86, 0x6E, 0xCC, 0x4F, 0xE8, 0x5F, 0x0A,
+        0xAD, 0x3E, 0xD3, 0x2C, 0x1D, 0xEB, 0x85, 0xB7, 0x64, 0x31, 0xD0, 0x55, 0xF7, 0x6C, 0x41,
+        0x86, 0x98, 0x44, 0x23, 0xFD, 0x49, 0x1E, 0x81, 0xA8, 0xE5, 0xD2, 0xA9, 0xB6, 0xEA, 0xEB,
+        0x3F, 0xF1, 0xF3, 0x74, 0x22, 0x02, 0x13, 0x1B, 0x84, 0xE2, 0xDD, 0x03, 0x8E, 0x01, 0x13,
+        0x1D, 0x95, 0x91, 0xF3, 0x5A, 0x8D, 0x94, 0xEC, 0x72, 0x78, 0xC2, 0xB5, 0x67, 0x4B, 0x23,
+        0x21, 0xA2, 0xD7, 0x50, 0x76, 0x0C, 0x5D, 0xCD, 0xEA, 0xE2, 0x91, 0x03, 0xD9, 0x89, 0xD1,
+        0x0F, 0xCF, 0x83, 0xC4, 0x0B, 0x8A, 0xA7, 0x3D, 0x1F, 0xF9, 0x31, 0xF5, 0x2F, 0xCA, 0xDF,
+        0xB1, 0xC4, 0x68, 0x24, 0x70, 0xC2, 0x38, 0x8B, 0x59, 0x54, 0x05, 0x74, 0xE5, 0xA0, 0x93,
+        0xB1, 0x47, 0x7C, 0x4E, 0x19, 0xD8, 0xD0, 0xF5, 0x86, 0xB4, 0x94, 0xEB, 0x2A, 0x77, 0x4E,
+        0x79, 0xFE, 0xA3, 0x0B, 0x1C, 0x96, 0x52, 0x95, 0x84, 0x12, 0x7A, 0x5D, 0x14, 0xC8, 0x10,
+        0x0D, 0x6A, 0xCF, 0xE0, 0xFE, 0x7B, 0xF0, 0xC2, 0xC7, 0xA5, 0xD3, 0x14, 0x96, 0xB7, 0x85,
+        0x73, 0x30, 0xD4, 0xD3, 0x79, 0xFE, 0x08, 0x4B, 0xBE, 0x4E, 0x8E, 0x27, 0x7C, 0x32, 0x15,
+        0x2A, 0xA3, 0x79, 0xF2, 0x08, 0x09, 0xED, 0x0A, 0xF1, 0xAE, 0x6A, 0x03, 0x2B, 0xF2, 0x44,
+        0xBD, 0xD3, 0x73, 0xEA, 0x99, 0xF3, 0xCF, 0xA8, 0x44, 0xC1, 0xFE, 0x7A, 0x2A, 0xF4, 0xA6,
+        0x6F, 0xFA, 0x49, 0xDB, 0x78, 0x3B, 0x83, 0xCA, 0x7D, 0x75, 0xD5, 0x01, 0xCF, 0xA8, 0xB4,
+        0xAC, 0xB0, 0xF9, 0x3F, 0xF9, 0x22, 0x20, 0xBF, 0xD6, 0xBF, 0xA9, 0xED, 0x4D, 0xFB, 0xCC,
+        0xE8, 0x59, 0x23, 0xD5, 0xAD, 0xD0, 0x8B, 0x4B, 0xA9, 0xC0, 0x6C, 0x00, 0xBA, 0x4C, 0xCC,
+        0x3C, 0xF9, 0xC6, 0xE9, 0x96, 0x90, 0x4B, 0x29, 0xDC, 0xEA, 0x36, 0xC0, 0xBF, 0x2F, 0x40,
+        0x9E, 0x8C, 0x1D, 0xD2, 0xF9, 0xD2, 0xFC, 0x31, 0x6C, 0x83, 0xA9, 0x7B, 0x56, 0x25, 0x20,
+        0xF6, 0xAB, 0x13, 0x99, 0x45, 0xB2, 0x33, 0x3D, 0x02, 0x56, 0x77, 0xDF, 0x5B, 0x20, 0x04,
+        0x5F, 0xFC, 0xD4, 0xB4, 0x1A, 0xA2, 0xAF, 0x9B, 0x06, 0x0F, 0x35, 0xEF, 0x25, 0x68, 0x8C,
+        0x13, 0xBC, 0x39, 0x80, 0xE3, 0xAC, 0xD9, 0xA0, 0xC4, 0x9A, 0x14, 0xA1, 0x4B, 0x8B, 0x29,
+        0xE6, 0x0B, 0x05, 0xC9, 0x51, 0x8F, 0xAE, 0xEB, 0x38, 0xBE, 0x95, 0x82, 0x40, 0x2E, 0x00,
+        0x02, 0xC7, 0x97, 0x93, 0x1A, 0xE4, 0xE2, 0xA3, 0xBF, 0xD6, 0x05, 0xA5, 0x40, 0x54, 0x1C,
+        0x95, 0x03, 0xB7, 0x62, 0x35, 0x33, 0x50, 0x25, 0x4B, 0x58, 0xA8, 0xBC, 0x85, 0xB4, 0x56,
+        0x74, 0x26, 0x50, 0x76, 0xC6, 0x85, 0x32, 0xDE, 0x73, 0x55, 0x7E, 0x80, 0xFD, 0x49, 0x88,
+        0x42, 0x56, 0x34, 0x1B, 0x84, 0x9A, 0xE4, 0x16, 0xC1, 0x81, 0x5E, 0x4A, 0xEF, 0xCA, 0xB2,
+        0x84, 0xB5, 0x56, 0xBA, 0x62, 0x57, 0x16, 0xA4, 0xCD, 0x95, 0x84, 0xC0, 0x29, 0x91, 0xA5,
+        0x05, 0xB6, 0xDA, 0xC8, 0x65, 0xF9, 0xD7, 0xE3, 0x1A, 0x19, 0x1D, 0x82, 0xB5, 0x2E, 0x8A,
+        0xE9, 0xD5, 0x2B, 0xB0, 0x1D, 0xAC, 0x48, 0xA9, 0x17, 0x02, 0x9D, 0xF7, 0x80, 0x1A, 0x2A,
+        0xAE, 0x45, 0x8F, 0x86, 0xF8, 0x95, 0xCB, 0x2C, 0xFB, 0x4A, 0xC1, 0xC4, 0xFC, 0x93, 0xF4,
+        0xE7, 0xDB, 0x60, 0x7B, 0x4D, 0x15, 0x79, 0x0E, 0xCE, 0x4F, 0x6A, 0x08, 0xCB, 0x34, 0x37,
+        0x5D, 0xF7, 0x03, 0x9E, 0xB4, 0x39, 0xDC, 0xF6, 0x53, 0x36, 0x9D, 0x9C, 0x0E, 0x0A, 0x15,
+        0x07, 0xAC, 0x48, 0x2C, 0x4E, 0x3C, 0x86, 0x75, 0x7D, 0x38, 0xD2, 0xC3, 0x9A, 0x74, 0x9A,
+        0x47, 0xE7, 0x49, 0xC1, 0x71, 0xA2, 0xA4, 0x6E, 0xC2, 0x82, 0x89, 0xC0, 0xFF, 0xA3, 0x31,
+        0x33, 0x7B, 0x65, 0x85, 0x2C, 0x3B, 0x86, 0xFA, 0x13, 0x82, 0x3E, 0x54, 0x4D, 0x12, 0x15,
+        0x68, 0x86, 0x45, 0x0E, 0xE9, 0xBD, 0x4D, 0xFC, 0xEF, 0x00, 0x4B, 0xCC, 0xB6, 0x9D, 0x8C,
+        0xF2, 0xB9, 0x1C, 0xA3, 0xA5, 0x61, 0x3E, 0x1F, 0x3D, 0x0A, 0x4A, 0xEC, 0x53, 0x01, 0xEA,
+        0x3B, 0xD6, 0x05, 0x3A, 0x7C, 0x61, 0x6B, 0xDC, 0xC1, 0x2E, 0x6D, 0xCD, 0xE8, 0x83, 0x70,
+        0x96, 0x13, 0x61, 0x80, 0x9E, 0x50, 0x6E, 0x5F, 0x76, 0x35, 0x2D, 0x34, 0xE9, 0x83, 0xC1,
+        0xBD, 0x23, 0x99, 0xBB, 0xA5, 0xED, 0xB1, 0x2D, 0x0C, 0x94, 0xC6, 0x36, 0x17, 0x75, 0x1C,
+        0x7A, 0x3B, 0x6C, 0x1F, 0x25, 0x5F, 0x85, 0xAC, 0xD1, 0x38, 0x34, 0x4B, 0xA2, 0x8B, 0x12,
+        0x3E, 0xDB, 0x24, 0x77, 0x43, 0x69, 0x4B, 0xF6, 0x6C, 0x60, 0xF5, 0xB4, 0xC5, 0xAD, 0x70,
+        0x40, 0xE0, 0x7C, 0x8D, 0x64, 0x79, 0x88, 0x3B, 0xF3, 0x9D, 0xCC, 0x1E, 0x6C, 0x0C, 0xF6,
+        0x7F, 0xE5, 0xFE, 0x6B, 0xC7, 0x64, 0x5C, 0xAF, 0xC3, 0xB8, 0xF1, 0xA4, 0x04, 0xB2, 0xD3,
+        0xB3, 0x6C, 0x1A, 0xBC, 0x1B, 0x31, 0xE4, 0x16, 0xB3, 0x65, 0x0D, 0x21, 0x2C, 0x1A, 0x3F,
+        0xAC, 0x81, 0x48, 0xD5, 0xAC, 0xD5, 0xF0, 0xCD, 0x8E, 0xCA, 0x7A, 0xB9, 0x92, 0x80, 0xDC,
+        0x94, 0x63, 0xE3, 0x94, 0x89, 0xB4, 0x79, 0xBF, 0x35, 0xF0, 0x10, 0x50, 0x88, 0x2D, 0xFB,
+        0x62, 0xCE, 0x09, 0x96, 0x61, 0x44, 0x87, 0x6F, 0xDC, 0x00, 0x5A, 0xFC, 0xC6, 0xE4, 0xCD,
+        0x94, 0x5E, 0x1A, 0xBA, 0xEA, 0xFC, 0x97, 0x6D, 0xB5, 0x12, 0xBB, 0x18, 0x29, 0x01, 0x17,
+        0xCD, 0xA5, 0xC9, 0x78, 0xA8, 0x47, 0x94, 0x7D, 0xBD, 0xA1, 0x19, 0x50, 0xE4, 0x39, 0x2C,
+        0xBD, 0x66, 0x01, 0x83, 0xB4, 0xE7, 0xF6, 0x1A, 0x99, 0xFF, 0xF9, 0x20, 0xDC, 0xC3, 0x39,
+        0x00, 0xE1, 0x79, 0xA2, 0x6E, 0xFB, 0xC7, 0x5A, 0xBB, 0x2C, 0x0B, 0xB3, 0x87, 0x22, 0x45,
+    ];
+    assert_eq!(pk_ref, pk);
+    let sk_ref = [
+        0x89, 0x2D, 0x93, 0x85, 0x9B, 0x0F, 0x01, 0xAF, 0x2F, 0xE7, 0x4B, 0x45, 0xBF, 0x59, 0xC7,
+        0x6E, 0x31, 0xAB, 0x85, 0xCD, 0xB1, 0x56, 0xC5, 0x46, 0x8F, 0x65, 0xC6, 0xA2, 0x57, 0x11,
+        0x5B, 0xC4, 0x59, 0x72, 0x3B, 0x1F, 0xBD, 0xEE, 0x8C, 0x61, 0xEE, 0x6D, 0x51, 0x7B, 0xB7,
+        0xA5, 0x26, 0x81, 0x5C, 0x8B, 0x74, 0xC8, 0x31, 0x35, 0x66, 0xCC, 0x51, 0xC2, 0x5D, 0xD5,
+        0x87, 0x69, 0x10, 0x62, 0x4F, 0xCF, 0xE0, 0x37, 0xDB, 0x7B, 0x10, 0xC9, 0x94, 0x98, 0x2B,
+        0x95, 0xD0, 0xAA, 0x20, 0x89, 0x3E, 0x4F, 0x67, 0x39, 0x74, 0xC5, 0x7E, 0xA9, 0x33, 0xD8,
+        0x8C, 0x8F, 0x3D, 0xBD, 0x9E, 0x98, 0x70, 0xD2, 0x09, 0x1B, 0x32, 0x3E, 0xD1, 0x10, 0x3E,
+        0xDA, 0x4E, 0x3F, 0xA1, 0x96, 0xEE, 0x29, 0x29, 0x06, 0xDC, 0xC3, 0x8D, 0x59, 0x42, 0x62,
+        0x1B, 0x74, 0xBF, 0xB3, 0x99, 0xB6, 0x7C, 0x74, 0x74, 0x60, 0x83, 0x3F, 0xEF, 0x0F, 0xE5,
+        0x23, 0x1B, 0xA1, 0x44, 0xC8, 0x1D, 0x66, 0x94, 0x07, 0xDA, 0xA4, 0xAA, 0x18, 0x8D, 0x55,
+        0xD9, 0x9A, 0xD2, 0x87, 0xC0, 0x89, 0xAC, 0xB0, 0x6E, 0x78, 0x0F, 0xD7, 0x1F, 0x8D, 0x9A,
+        0x27, 0xC0, 0x6D, 0xB8, 0xD1, 0x98, 0xC8, 0xD6, 0xA3, 0x53, 0x69, 0x01, 0xE9, 0x81, 0x79,
+        0x67, 0x86, 0x04, 0xE0, 0x82, 0xBC, 0xE5, 0x4E, 0x74, 0x18, 0xE2, 0x9F, 0xC0, 0x2B, 0x0D,
+        0xE6, 0xE1, 0x73, 0xA5, 0x4C, 0x4C, 0x26, 0x87, 0x45, 0xA1, 0x0C, 0xA1, 0xD9, 0x0F, 0xE0,
+        0x0C, 0x76, 0xD8, 0x20, 0x0D, 0x0B, 0xE5, 0xA7, 0x89, 0x80, 0xB5, 0xE4, 0x16, 0xF1, 0xC0,
+        0xDE, 0xB9, 0xD9, 0x69, 0x76, 0x7B, 0x43, 0x3A, 0x9F, 0xA7, 0x9F, 0x30, 0x8D, 0x88, 0x6C,
+        0xE2, 0x7A, 0x4A, 0x38, 0x36, 0x13, 0x01, 0x44, 0x02, 0xCA, 0xCA, 0xB5, 0x68, 0x2C, 0x6A,
+        0x37, 0x62, 0x0F, 0x66, 0x6B, 0x4D, 0x1C, 0x64, 0x3A, 0x82, 0xC2, 0x87, 0xC0, 0x40, 0x69,
+        0xE4, 0xC6, 0x7E, 0xD7, 0x5B, 0x11, 0xC7, 0x87, 0x7C, 0x97, 0x4D, 0xB7, 0xD9, 0x5F, 0x6E,
+        0xD3, 0xD2, 0xA9, 0xED, 0x1B, 0x4E, 0x12, 0x06, 0x2C, 0x35, 0xE9, 0x3F, 0xA7, 0x2C, 0x76,
+        0x8A, 0xC9, 0x89, 0x5D, 0x82, 0x3A, 0x38, 0x43, 0x9C, 0x87, 0xC5, 0xEC, 0x3E, 0x77, 0xD5,
+        0x82, 0x21, 0x52, 0x75, 0xB5, 0xE1, 0x15, 0xBF, 0xE5, 0x82, 0xD0, 0x17, 0x22, 0xA2, 0x97,
+        0x96, 0xBF, 0xB8, 0x7F, 0x0A, 0x95, 0x3F, 0x6D, 0x54, 0xCE, 0x7B, 0xB3, 0xB6, 0x4D, 0x67,
+        0x75, 0xA7, 0xA8, 0xE8, 0xEE, 0x46, 0x5B, 0x57, 0x45, 0x25, 0xC0, 0x54, 0x1D, 0xB9, 0x8C,
+        0xA2, 0xC1, 0x20, 0xE6, 0x0B, 0x7A, 0xDB, 0x8F, 0xFD, 0xFE, 0xA2, 0xA6, 0x66, 0x25, 0xD5,
+        0xF5, 0x0F, 0xDA, 0xE9, 0x03, 0xAE, 0x4A, 0x2D, 0xB5, 0xA8, 0x06, 0xB2, 0x1C, 0x20, 0x4D,
+        0x02, 0x25, 0xB6, 0xBC, 0x26, 0x2A, 0xD3, 0xF6, 0x3C, 0x8B, 0x42, 0x57, 0x9A, 0x27, 0x7D,
+        0xC7, 0x74, 0x89, 0x56, 0xAA, 0x24, 0xA6, 0xA2, 0x3C, 0x71, 0x46, 0xDC, 0x6C, 0xEC, 0xFC,
+        0x12, 0x85, 0x07, 0xCA, 0xC4, 0xF0, 0xCE, 0xB4, 0x7A, 0x85, 0x08, 0x4B, 0x8C, 0xDE, 0x8C,
+        0x1A, 0x02, 0xB7, 0x78, 0xA9, 0x6A, 0x2A, 0x52, 0x06, 0x59, 0xEA, 0x7E, 0x31, 0x85, 0xC0,
+        0xF9, 0xBC, 0x60, 0x6A, 0xE0, 0x1D, 0xE5, 0x56, 0x88, 0x02, 0xF3, 0x09, 0x1D, 0xAE, 0x0F,
+        0x90, 0xED, 0x65, 0x5F, 0x3D, 0x67, 0xDA, 0xAF, 0x54, 0x60, 0x55, 0xD2, 0x2D, 0x23, 0x35,
+        0xD9, 0x5D, 0x66, 0xCD, 0xA0, 0xF2, 0xAF, 0x72, 0xD6, 0x35, 0xF0, 0xB9, 0x64, 0xC8, 0x05,
+        0xC6, 0xC9, 0xDB, 0x29, 0xC9, 0x95, 0xAD, 0x60, 0xD9, 0x73, 0x59, 0x11, 0x55, 0xAA, 0x67,
+        0x07, 0x3E, 0x10, 0xC7, 0x00, 0x48, 0xDC, 0xE3, 0x0B, 0xA9, 0xAD, 0x21, 0x56, 0x25, 0x26,
+        0xF4, 0x93, 0x99, 0xBA, 0x2D, 0x28, 0x27, 0xFC, 0x92, 0x29, 0xDB, 0x2A, 0xA4, 0x89, 0xE8,
+        0xD9, 0x19, 0xC4, 0xF7, 0xFB, 0xD7, 0x01, 0x08, 0x8F, 0x5F, 0x3E, 0x0E, 0x5C, 0x6F, 0xF2,
+        0xD0, 0x2D, 0x9A, 0x31, 0x37, 0x10, 0x5B, 0x4D, 0x86, 0x0B, 0xCF, 0x41, 0x89, 0x0D, 0x18,
+        0x24, 0x4D, 0xD7, 0xD3, 0x7E, 0x58, 0x58, 0x37, 0x5D, 0x60, 0xD1, 0xD4, 0x47, 0x98, 0x8C,
+        0x18, 0x39, 0x79, 0x39, 0x82, 0x43, 0x7C, 0x8D, 0xE4, 0x3B, 0x31, 0x38, 0x1C, 0xAC, 0xD0,
+        0xA3, 0xE6, 0x94, 0xDB, 0x72, 0x57, 0xB9, 0x7A, 0xD6, 0x3E, 0x69, 0x5D, 0xDA, 0x74, 0x4F,
+        0xFB, 0xB0, 0x79, 0xF8, 0xD3, 0x01, 0x8C, 0x06, 0xA6, 0x91, 0xC0, 0x54, 0xA4, 0x1F, 0xC3,
+        0x3C, 0x74, 0x28, 0x9A, 0xBD, 0x33, 0xAD, 0x52, 0xBE, 0x38, 0xA4, 0xB5, 0x0F, 0x49, 0x35,
+        0x65, 0x85, 0x4C, 0xF6, 0x0B, 0x58, 0xD5, 0x34, 0xFC, 0x8E, 0x92, 0x5D, 0xBF, 0xCC, 0x00,
+        0x38, 0x84, 0x18, 0x48, 0xF4, 0xD6, 0x9F, 0x34, 0xD3, 0xB3, 0xBA, 0xC3, 0xC1, 0x82, 0x0D,
+        0xB6, 0xEB, 0xC1, 0xCE, 0x67, 0x1B, 0x9C, 0xB0, 0x3C, 0xB7, 0x8E, 0x61, 0x6C, 0x96, 0xB8,
+        0x23, 0x27, 0x57, 0xDB, 0x2B, 0x2C, 0xEC, 0xFC, 0xBF, 0x35, 0x5E, 0x97, 0x7F, 0xC9, 0x07,
+        0x8F, 0x67, 0x8C, 0x21, 0x2B, 0xA6, 0xD8, 0xB5, 0xA3, 0x69, 0x32, 0x63, 0x1D, 0x70, 0x14,
+        0x04, 0x79, 0x73, 0x3E, 0x5B, 0xE8, 0xC3, 0xEB, 0xD1, 0x01, 0x49, 0x00, 0x29, 0x6E, 0x7D,
+        0xB1, 0x87, 0xE9, 0xB0, 0x28, 0x15, 0x73, 0x35, 0xDE, 0xAE, 0x0E, 0x39, 0xFF, 0x30, 0xF4,
+        0x39, 0x3D, 0x0E, 0x1A, 0xF8, 0x35, 0x06, 0x1D, 0x3D, 0x95, 0xDA, 0xAA, 0x11, 0x64, 0x9B,
+        0xCC, 0x9B, 0x2A, 0xED, 0xC0, 0xE6, 0xE8, 0x7F, 0x7A, 0x55, 0x20, 0xDB, 0xCE, 0xC6, 0xE6,
+        0x28, 0x7E, 0xFD, 0x64, 0xC7, 0x6A, 0x16, 0xB5, 0x44, 0xAF, 0x77, 0x48, 0x51, 0x44, 0x69,
+        0x7B, 0xC0, 0xB4, 0x19, 0x05, 0x09, 0x83, 0x53, 0x0D, 0x50, 0xB8, 0x57, 0x81, 0xBE, 0x53,
+        0x46, 0xC4, 0x1A, 0x69, 0x81, 0x79, 0x17, 0xB0, 0xF5, 0x03, 0x4D, 0x6D, 0x12, 0xE4, 0xEB,
+        0xA4, 0x7C, 0x48, 0xDE, 0x06, 0x3B, 0x78, 0xB2, 0xD4, 0x4C, 0xE1, 0xE3, 0x7F, 0xE8, 0x7F,
+        0x38, 0x4F, 0xE1, 0x97, 0x25, 0x23, 0xE2, 0x4B, 0xF6, 0xD2, 0x5F, 0xAA, 0xAD, 0x2F, 0xF7,
+        0x78, 0x82, 0xB8, 0xE2, 0x21, 0x67, 0xC3, 0xF6, 0xC2, 0x95, 0xD3, 0xCD, 0x8D, 0x62, 0x07,
+        0x61, 0x71, 0xC6, 0x2F, 0xDF, 0x86, 0xD7, 0x78, 0x0B, 0x15, 0x64, 0x9B, 0xE3, 0x97, 0x51,
+        0x3C, 0x47, 0x89, 0x15, 0x53, 0xF6, 0xFF, 0xC9, 0x85, 0x7C, 0xFA, 0x53, 0xD1, 0xAD, 0x86,
+        0x3B, 0x8A, 0x36, 0xED, 0xA0, 0x49, 0x33, 0x1B, 0xEA, 0x05, 0x6D, 0xE1, 0xC3, 0x10, 0xA1,
+        0x75, 0xA8, 0x8E, 0xD0, 0x46, 0x39, 0xAC, 0x18, 0xD1, 0x70, 0x3E, 0xD5, 0x81, 0xD1, 0x38,
+        0x53, 0x8D, 0x63, 0x1C, 0xBB, 0x99, 0x5D, 0x3C, 0xDC, 0x88, 0x9E, 0xA6, 0xB3, 0xBE, 0xF3,
+        0x12, 0x7D, 0xD1, 0xC7, 0x28, 0xFA, 0x20, 0xF6, 0x59, 0xD3, 0xCD, 0xAF, 0x49, 0xF0, 0x2F,
+        0xB2, 0x16, 0xFC, 0xBC, 0xC5, 0x1B, 0x8D, 0xDA, 0xC5, 0x09, 0x5D, 0xD5, 0xBD, 0xF6, 0xD1,
+        0x34, 0xF3, 0x00, 0xBB, 0x5C, 0xE5, 0xD4, 0xEB, 0x1C, 0xA5, 0xBC, 0x79, 0x92, 0x6E, 0x51,
+        0xE1, 0x8B, 0xED, 0xC1, 0x5A, 0x07, 0x71, 0x17, 0x05, 0x6C, 0x6F, 0x51, 0xB3, 0x59, 0x68,
+        0x7B, 0x95, 0xEB, 0xA2, 0xD7, 0xAB, 0xF0, 0xE9, 0x7B, 0x3B, 0xAA, 0x7D, 0x74, 0x82, 0x45,
+        0xEA, 0x03, 0xF7, 0x7E, 0x40, 0x4A, 0xFE, 0xFB, 0x52, 0xA2, 0x91, 0xE4, 0xC1, 0x03, 0xAA,
+        0x51, 0x5B, 0x5D, 0xB3, 0xD7, 0x53, 0xC9, 0x30, 0xB0, 0x57, 0x74, 0xE6, 0x9F, 0x3F, 0x5B,
+        0x69, 0xC7, 0xF3, 0x0E, 0xA9, 0x44, 0xCB, 0xE7, 0x80, 0x6A, 0xCB, 0x4D, 0xF0, 0x69, 0x95,
+        0xB2, 0x3A, 0x14, 0x5B, 0x3F, 0x8D, 0x52, 0xD5, 0x77, 0xCC, 0xD9, 0x36, 0x06, 0x62, 0x5C,
+        0x07, 0x84, 0xD9, 0xD2, 0x7B, 0x91, 0x20, 0xA2, 0xC3, 0xE6, 0x30, 0x69, 0x30, 0x6A, 0x71,
+        0x5A, 0x44, 0xC2, 0xDF, 0xB7, 0x42, 0xD5, 0x7E, 0x7C, 0x9B, 0xA0, 0xFB, 0x82, 0xB3, 0xC6,
+        0x50, 0xD9, 0xFB, 0xA0, 0x9C, 0xED, 0xB1, 0x25, 0x50, 0xC2, 0x6C, 0x51, 0x71, 0xC7, 0xE2,
+        0x96, 0x0E, 0x27, 0xF1, 0x91, 0x0E, 0xD0, 0x21, 0x2B, 0xA4, 0x63, 0xAD, 0xF7, 0xFF, 0x66,
+        0x04, 0x44, 0x55, 0x8B, 0xBF, 0xCF, 0x7B, 0x72, 0xCB, 0x4D, 0x80, 0xE2, 0x50, 0x9E, 0x1D,
+        0x97, 0x8A, 0x60, 0x13, 0xF8, 0x56, 0xA8, 0x65, 0xF6, 0xDB, 0x7A, 0x89, 0x17, 0x74, 0xEB,
+        0x41, 0x07, 0x5A, 0xBD, 0xFB, 0x52, 0xF1, 0xB9, 0xF3, 0x73, 0xC1, 0x3B, 0x68, 0xAB, 0x11,
+        0x49, 0xFF, 0xC5, 0x19, 0x93, 0x52, 0xAC, 0xB0, 0x0E, 0x2B, 0xD6, 0xE9, 0x34, 0x9D, 0xAA,
+        0x3E, 0x04, 0x20, 0xF0, 0x36, 0xB3, 0x00, 0x41, 0x93, 0x7E, 0xA2, 0x97, 0x37, 0x70, 0xE0,
+        0x09, 0xE0, 0x49, 0x39, 0x76, 0x96, 0x44, 0x8C, 0x9F, 0x47, 0x24, 0x24, 0x37, 0x08, 0x10,
+        0xC0, 0x45, 0xDE, 0x3B, 0x28, 0xEE, 0x7A, 0x9D, 0x01, 0xC2, 0x6D, 0x51, 0x69, 0xE2, 0x0C,
+        0x03, 0xC2, 0x5F, 0x8E, 0xE4, 0xF0, 0xC0, 0x7F, 0x58, 0x51, 0xC3, 0x00, 0x4C, 0xE3, 0xE2,
+        0x89, 0xE4, 0xA7, 0xF7, 0xFC, 0xAB, 0xDF, 0x15, 0xCD, 0x68, 0x6D, 0xBA, 0x98, 0x8E, 0xF8,
+        0x21, 0x10, 0x43, 0x45, 0x9B, 0xD4, 0xF0, 0x4D, 0x60, 0xD7, 0x23, 0xD8, 0x38, 0xFB, 0x27,
+        0xB0, 0x1D, 0x7D, 0xE6, 0xC7, 0x75, 0xF0, 0x5B, 0xB0, 0x0D, 0xC0, 0x3B, 0xBC, 0x62, 0x04,
+        0x70, 0xDF, 0xBA, 0x59, 0xFC, 0x97, 0x3C, 0xB6, 0x4F, 0x79, 0xF0, 0x37, 0x2C, 0x35, 0x09,
+        0x8D, 0x32, 0x02, 0xAF, 0x65, 0x33, 0x01, 0x7A, 0xCB, 0xF7, 0x3F, 0x1E, 0x98, 0x3B, 0x6F,
+        0xEF, 0xC1, 0x85, 0x1F, 0x20, 0xDF, 0x25, 0x14, 0x49, 0x88, 0x5D, 0xEC, 0x97, 0x32, 0x87,
+        0xBA, 0xD2, 0xFD, 0x01, 0xC0, 0xFC, 0x04, 0x93, 0xE2, 0x90, 0xF3, 0x3F, 0x22, 0x51, 0x6D,
+        0xA8, 0xA9, 0xB7, 0xD9, 0xB0, 0x67, 0x6F, 0xD8, 0xB3, 0x83, 0x46, 0x0A, 0xDA, 0xD9, 0x68,
+        0x1D, 0x0C, 0xC2, 0x51, 0x8C, 0xDE, 0xDD, 0x6D, 0xF5, 0x7F, 0xD1, 0xF1, 0x0C, 0x67, 0xE3,
+        0xE2, 0xE8, 0x0A, 0xF2, 0x25, 0xF7, 0x09, 0xC5, 0xED, 0x91, 0x59, 0x85, 0xEA, 0x19, 0xE1,
+        0xF1, 0x58, 0x09, 0x93, 0x82, 0x6B, 0xDA, 0xB6, 0x7B, 0x1C, 0xDA, 0x89, 0x53, 0x44, 0x1A,
+        0x9E, 0xC8, 0x5F, 0xDE, 0x71, 0x18, 0x9F, 0x8E, 0x7B, 0x8E, 0x43, 0x8D, 0xA7, 0xA8, 0x04,
+        0xD0, 0x8C, 0xB2, 0xDC, 0xF7, 0xA0, 0xCC, 0x9C, 0x7B, 0xC3, 0x4A, 0x6C, 0xC5, 0xE6, 0x42,
+        0x64, 0xAA, 0x7E, 0xA0, 0x8B, 0x65, 0x3F, 0x80, 0xF1, 0x2F, 0x4B, 0x85, 0xC5, 0x5E, 0x50,
+        0xB4, 0x09, 0x6B, 0x0D, 0x0A, 0x7E, 0xFB, 0xFE, 0xA5, 0x81, 0x79, 0x28, 0x00, 0xEE, 0xA9,
+        0xD4, 0x5D, 0x97, 0xE1, 0xEC, 0x19, 0xC3, 0xCB, 0x9D, 0xBC, 0x56, 0xBF, 0xCE, 0x03, 0xFC,
+        0xC4, 0xA3, 0x59, 0xBB, 0x21, 0x7A, 0xE1, 0xBF, 0x79, 0x0F, 0xE0, 0x59, 0xD1, 0x35, 0xD7,
+        0x80, 0x54, 0xEB, 0x75, 0xCA, 0x41, 0x19, 0xD8, 0x09, 0xF6, 0xD8, 0x64, 0xCB, 0x08, 0x5D,
+        0x74, 0x0A, 0x45, 0x6B, 0x31, 0x06, 0x88, 0xBE, 0x8E, 0xE3, 0xE0, 0x3D, 0x2F, 0x61, 0x44,
+        0xDD, 0x68, 0x1C, 0x36, 0x7A, 0xDA, 0xE3, 0xFC, 0xC9, 0xF5, 0xA7, 0x97, 0x8E, 0x06, 0x7F,
+        0xF0, 0x5B, 0xF1, 0x71, 0x17, 0xF1, 0x52, 0x5F, 0x96, 0xFA, 0x0D, 0x80, 0x11, 0x8B, 0x8D,
+        0x57, 0x8A, 0x47, 0x78, 0x67, 0x58, 0x55, 0x6E, 0xD6, 0xA0, 0xD5, 0xE2, 0x81, 0x31, 0xC0,
+    ];
+    assert_eq!(sk_ref, sk);
+
+    Ok(())
+}
+
+#[test]
+fn test_enc_kat1() -> R {
+    each_backend(enc_kat1)
+}
+
+fn enc_kat1() -> R {
+    let pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut rng = AesState::new();
+    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+    let mut ss = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_enc(&mut ct, &mut ss, &pk, &mut rng)?;
+
+    let ct_ref = [
+        0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xF0, 0xFF, 0xFF, 0x1F, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0xF0, 0xFF, 0x00, 0xF0, 0xFF,
+        0x01, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00,
+        0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01, 0xF0, 0xFF,
+        0x00, 0x10, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0x10, 0x00,
+        0x00, 0x10, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x1F, 0x00, 0x01, 0xF0, 0xFF,
+        0x01, 0x10, 0x00, 0x01, 0xF0, 0xFF, 0x01, 0xF0, 0xFF, 0xFF, 0x0F, 0x00, 0x00, 0xF0, 0xFF,
+        0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0x0F, 0x00, 0xFF, 0x1F, 0x00, 0x01, 0x10, 0x00,
+        0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00,
+        0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0xF0, 0xFF,
+        0xFF, 0x1F, 0x00, 0x01, 0x10, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0x1F, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00,
+        0x00, 0x10, 0x00, 0x01, 0x00, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x01, 0xF0, 0xFF,
+        0xFF, 0x0F, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0x0F, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00,
+        0x01, 0xF0, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x00, 0xF0, 0xFF,
+        0x00, 0x10, 0x00, 0xFF, 0xFF, 0xFF, 0x01, 0xF0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00,
+        0x00, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x01, 0xF0, 0xFF,
+        0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x01, 0x10, 0x00, 0xFF, 0x0F, 0x00, 0xFF, 0x0F, 0x00,
+        0xFF, 0xFF, 0xFF, 0x01, 0xF0, 0xFF, 0x00, 0xF0, 0xFF, 0xFF, 0x0F, 0x00, 0x01, 0x10, 0x00,
+        0xFF, 0x0F, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00, 0xFF, 0x1F, 0x00,
+        0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF,
+        0x01, 0x00, 0x00, 0x01, 0x10, 0x00, 0x01, 0xF0, 0xFF, 0xFF, 0x1F, 0x00, 0xFF, 0x1F, 0x00,
+        0x00, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00, 0xFF, 0x1F, 0x00,
+        0x00, 0x10, 0x00, 0x00, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x1F, 0x00,
+        0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00, 0xFF, 0x0F, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0xFF, 0x0F, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x01, 0xF0, 0xFF, 0x01, 0xF0, 0xFF, 0x01, 0x10, 0x00,
+        0x00, 0x10, 0x00, 0x00, 0xF0, 0xFF, 0x01, 0xF0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0xF0, 0xFF,
+        0x00, 0xF0, 0xFF, 0xFF, 0x1F, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00,
+        0xFF, 0x0F, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF,
+        0xFF, 0x1F, 0x00, 0x00, 0xF0, 0xFF, 0x01, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x01, 0xF0, 0xFF,
+        0x00, 0xF0, 0xFF, 0x01, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00,
+        0xFF, 0x0F, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x0F, 0x00,
+        0xFF, 0x1F, 0x00, 0x00, 0x10, 0x00, 0xFF, 0x0F, 0x00, 0xFF, 0x0F, 0x00, 0x01, 0x10, 0x00,
+        0xFF, 0x0F, 0x00, 0xFF, 0x1F, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0x1F, 0x00,
+        0xFF, 0x0F, 0x00, 0x01, 0xF0, 0xFF, 0xFF, 0x0F, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x00,
+        0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0x0F, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0x0F, 0x00,
+        0xFF, 0xFF, 0xFF, 0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x01, 0x10, 0x00, 0xFF, 0xFF, 0xFF,
+        0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0xFF, 0xFF, 0xFF, 0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x10, 0x00, 0x00, 0x10, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x01, 0x10, 0x00, 0x01, 0x00, 0x00, 0x01, 0xF0, 0xFF, 0xFF, 0x0F, 0x00,
+        0x01, 0x10, 0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x1F, 0x00, 0xFF, 0x1F, 0x00, 0x01, 0x00, 0x00,
+        0xFF, 0x1F, 0x00, 0x01, 0x10, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x1F, 0x00,
+        0xFF, 0x0F, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x01, 0x10, 0x00,
+        0xFF, 0x1F, 0x00, 0x01, 0xF0, 0xFF, 0x01, 0x00, 0x00, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x01, 0xF0, 0xFF, 0x01, 0x00, 0x00,
+        0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00, 0xFF, 0x1F, 0x00,
+        0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x0F, 0x00,
+        0x00, 0xF0, 0xFF, 0x01, 0x10, 0x00, 0x01, 0xF0, 0xFF, 0x01, 0x10, 0x00, 0x01, 0x00, 0x00,
+        0x01, 0xF0, 0xFF, 0xFF, 0x0F, 0x00, 0xFF, 0x0F, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0xF0, 0xFF,
+        0x01, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x1F, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0xF0, 0xFF,
+        0x00, 0x00, 0x00, 0x01, 0x10, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01, 0xF0, 0xFF,
+        0xFF, 0x0F, 0x00, 0x00, 0x10, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF,
+        0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00,
+        0xFF, 0x0F, 0x00, 0xFF, 0x0F, 0x00, 0x01, 0x10, 0x00, 0xFF, 0x0F, 0x00, 0x01, 0x10, 0x00,
+        0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xF0, 0xFF,
+        0xFF, 0x0F, 0x00, 0x01, 0x10, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00,
+        0xFF, 0xFF, 0xFF, 0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x10, 0x00, 0x00, 0xF0, 0xFF,
+        0xFF, 0x1F, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0x00, 0x00,
+        0x00, 0xF0, 0xFF, 0xFF, 0x1F, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0xF0, 0xFF, 0xFF, 0x1F, 0x00, 0xFF, 0xFF, 0xFF,
+        0x01, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x01, 0x10, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0xF0, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0xFF, 0x0F, 0x00,
+        0x00, 0xF0, 0xFF, 0x00, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xF0, 0xFF,
+        0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0xFF, 0xFF, 0xFF,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0xFF, 0x1F, 0x00, 0x01, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x01, 0xF0, 0xFF, 0x00, 0xF0, 0xFF,
+        0xFF, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0xF0, 0xFF, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0xF0, 0xFF,
+        0x00, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00,
+        0xFF, 0xFF, 0xFF, 0x00, 0x10, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0xFF, 0xFF, 0xFF,
+        0x00, 0x10, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xF0, 0xFF, 0x00, 0xF0, 0xFF, 0xFF, 0x0F, 0x00, 0x00, 0x10, 0x00, 0xFF, 0xFF, 0xFF,
+    ];
+    assert_eq!(ct_ref, ct);
+    let ss_ref = [
+        0xC1, 0x26, 0x61, 0x87, 0xFE, 0x6A, 0x8C, 0x5F, 0xF4, 0x78, 0x60, 0x25, 0xCE, 0xD3, 0x16,
+        0xAE, 0xE7, 0x39, 0xFB, 0x3F, 0xEC, 0x5D, 0x59, 0x9F, 0xB3, 0xFD, 0x0E, 0xCD, 0xA3, 0xE9,
+        0xD9, 0x1B,
+    ];
+    assert_eq!(ss_ref, ss);
+
+    Ok(())
+}
+
+#[test]
+fn test_dec_kat1() -> R {
+    each_backend(dec_kat1)
+}
+
+fn dec_kat1() -> R {
+    let ct = [
+        0xC3, 0x1A, 0x29, 0x03, 0x4A, 0xEE, 0xF0, 0x46, 0x97, 0x18, 0x54, 0x9D, 0xFF, 0x4F, 0x83,
+        0x80, 0x23, 0xA1, 0x9A, 0xBA, 0x55, 0xBF, 0xB2, 0x49, 0xB4, 0x05, 0x9D, 0x37, 0xF8, 0x79,
+        0x21, 0x9B, 0x0A, 0x22, 0xF1, 0x7D, 0xAB, 0x3F, 0xF1, 0x6B, 0xA0, 0x5B, 0x3D, 0xFD, 0x44,
+        0xE9, 0xFD, 0x43, 0x11, 0x8A, 0x41, 0xD4, 0x31, 0x7E, 0xA3, 0x54, 0x07, 0xAA, 0xE2, 0x47,
+        0xE3, 0x1E, 0x90, 0xCD, 0x9E, 0x96, 0xBC, 0x11, 0x79, 0xC3, 0x37, 0xE4, 0x5C, 0x34, 0x3D,
+        0xC7, 0xC7, 0x83, 0xDF, 0x7C, 0x33, 0x0F, 0x83, 0x29, 0x88, 0xF0, 0xDF, 0xCA, 0x92, 0x33,
+        0xE2, 0x84, 0x2A, 0x45, 0xDB, 0xFB, 0xAD, 0xF2, 0xF6, 0xBE, 0x8B, 0x4C, 0xCF, 0xC2, 0xE5,
+        0xAF, 0xB1, 0xBA, 0xBC, 0xC2, 0x2A, 0x78, 0x08, 0xE5, 0xD6, 0x61, 0x67, 0xE6, 0x34, 0x10,
+        0xC3, 0x12, 0x7C, 0x08, 0x69, 0xA9, 0x96, 0x9E, 0x5C, 0x10, 0x94, 0xAC, 0xCB, 0xFD, 0x2F,
+        0x6F, 0xFF, 0xED, 0xE2, 0x51, 0x71, 0x87, 0xF7, 0x3A, 0x6A, 0x63, 0xB3, 0x9A, 0x9E, 0x09,
+        0xA1, 0x77, 0x8E, 0x2C, 0xF1, 0x62, 0x92, 0x46, 0x5D, 0xA1, 0xFF, 0xDE, 0x8E, 0xEA, 0x45,
+        0xBF, 0x99, 0x61, 0xC4, 0x8C, 0xEB, 0x3E, 0xF3, 0x70, 0x3C, 0x54, 0x69, 0x60, 0x54, 0x9F,
+        0xF1, 0x97, 0xA0, 0x4B, 0x59, 0xC8, 0xA2, 0x52, 0x1C, 0x22, 0xA1, 0x71, 0xE8, 0xB2, 0x14,
+        0xC6, 0x97, 0x48, 0x43, 0x83, 0x93, 0x9A, 0x3E, 0x4D, 0xCF, 0x54, 0x52, 0xE7, 0xE4, 0x17,
+        0x90, 0x22, 0xDB, 0x52, 0x46, 0x34, 0x4C, 0x19, 0xFF, 0xDD, 0x25, 0x23, 0xF8, 0xB3, 0xAA,
+        0xCD, 0xB3, 0x41, 0xA3, 0xD0, 0x8A, 0xEC, 0xB3, 0xAA, 0x42, 0xD4, 0x46, 0x55, 0xB4, 0xF8,
+        0xB0, 0xDE, 0xB8, 0x88, 0x39, 0xEE, 0xE3, 0x86, 0xB5, 0x2D, 0xB3, 0xA6, 0x35, 0x19, 0x14,
+        0x55, 0xF9, 0x28, 0xEA, 0xE7, 0x53, 0x5B, 0x45, 0xB5, 0xD1, 0x35, 0x39, 0xB5, 0xFF, 0x03,
+        0x31, 0xD0, 0x9F, 0xE0, 0xD5, 0xFD, 0x0C, 0x45, 0x53, 0x69, 0xB3, 0x86, 0x92, 0x12, 0xD5,
+        0xD5, 0x9E, 0x17, 0x94, 0xF6, 0x80, 0xDE, 0x7D, 0xC8, 0x04, 0x26, 0xB3, 0x96, 0x57, 0x9E,
+        0x70, 0x6E, 0x35, 0x50, 0x12, 0x22, 0xAE, 0x1F, 0x14, 0x1D, 0xD8, 0xEF, 0xE7, 0xC9, 0xF4,
+        0x79, 0x12, 0x25, 0xA4, 0x38, 0x62, 0xAF, 0x0C, 0x42, 0x06, 0x7F, 0x69, 0xC5, 0xB6, 0x68,
+        0xA6, 0x9C, 0xFC, 0xC2, 0x7B, 0xA3, 0x6A, 0xC2, 0x27, 0xD5, 0xC4, 0xA5, 0xFC, 0x7C, 0x87,
+        0x3F, 0xBA, 0xBB, 0x60, 0x4B, 0x27, 0x14, 0x5A, 0x56, 0xD0, 0xFD, 0x07, 0x41, 0xB1, 0x9E,
+        0x93, 0x04, 0x16, 0xB2, 0xAB, 0x1F, 0x21, 0x3D, 0x7B, 0x09, 0x44, 0xCA, 0x8B, 0xEA, 0xA8,
+        0x61, 0x44, 0x5A, 0xBC, 0xF5, 0x9B, 0x21, 0x7F, 0x30, 0x9F, 0x6D, 0xA8, 0xA2, 0xE3, 0x9C,
+        0x58, 0x3F, 0x0E, 0xE2, 0xAE, 0x01, 0x8C, 0x81, 0xB9, 0x37, 0x9E, 0xED, 0x51, 0xAC, 0x0B,
+        0x9A, 0xD6, 0x27, 0xB3, 0x87, 0x0E, 0x5F, 0x89, 0x81, 0xA2, 0xA0, 0xF0, 0x6D, 0x15, 0x3D,
+        0x23, 0xA0, 0x32, 0x57, 0xF6, 0x9A, 0x42, 0xC3, 0x65, 0x0F, 0x8C, 0xCB, 0xC0, 0xA1, 0x08,
+        0x3D, 0x37, 0xA0, 0x24, 0x13, 0xFD, 0xF3, 0x64, 0x35, 0xA1, 0xFA, 0xE0, 0xE4, 0x8B, 0x6E,
+        0xD0, 0x03, 0x30, 0xD0, 0x21, 0x06, 0x11, 0x9A, 0x83, 0x0A, 0x9C, 0x0E, 0x0D, 0xF7, 0x6B,
+        0x0B, 0x96, 0xC1, 0x87, 0xB1, 0x72, 0x43, 0xBE, 0x11, 0xE3, 0x5A, 0x2C, 0x84, 0x7F, 0x6F,
+        0xF3, 0xF6, 0xD8, 0x53, 0x7D, 0x42, 0xCB, 0x63, 0xE6, 0xE6, 0xF8, 0x9B, 0x26, 0x2B, 0x7E,
+        0x7B, 0x92, 0x46, 0xA4, 0x9A, 0x33, 0x46, 0x22, 0xE6, 0x5E, 0xC8, 0xB7, 0x78, 0x70, 0xAA,
+        0x34, 0x40, 0xEA, 0xC7, 0x70, 0x58, 0xBD, 0x13, 0x73, 0xD1, 0x2A, 0xEC, 0x34, 0x34, 0x8A,
+        0x12, 0xE7, 0x4E, 0x65, 0x7F, 0xEB, 0x59, 0x21, 0xF1, 0x96, 0x7E, 0xB1, 0x55, 0x48, 0xFF,
+        0x3C, 0xB9, 0x7D, 0xF8, 0xDD, 0xC7, 0x35, 0xBD, 0x20, 0xD9, 0x9F, 0x93, 0x0D, 0xE9, 0x62,
+        0x17, 0x3B, 0x4E, 0xD0, 0x5C, 0xF0, 0x3F, 0xDF, 0x53, 0xBD, 0x3C, 0xE7, 0x2E, 0x7A, 0x1B,
+        0x2A, 0x0C, 0xE2, 0xF7, 0x1C, 0x64, 0x50, 0x2C, 0xD5, 0x30, 0xC5, 0x09, 0xCE, 0x16, 0x42,
+        0x04, 0x4E, 0x1B, 0x4F, 0x3D, 0xC4, 0xBC, 0x79, 0xE9, 0xCB, 0x81, 0x8C, 0x93, 0xE6, 0x80,
+        0x35, 0x87, 0x2E, 0x7C, 0x2A, 0x95, 0xA5, 0xC5, 0x0C, 0xDB, 0x84, 0x39, 0x75, 0x40, 0xCE,
+        0xB7, 0x4E, 0xF4, 0x17, 0x1A, 0x1F, 0xB9, 0xC7, 0x75, 0x11, 0x3B, 0x68, 0xF8, 0xE4, 0x1A,
+        0xCC, 0x11, 0xB6, 0x3F, 0xCF, 0x9D, 0x5A, 0x95, 0xCF, 0x84, 0x0F, 0xF5, 0x3F, 0x34, 0x40,
+        0xAA, 0x4E, 0x7F, 0x06, 0x45, 0x44, 0xDE, 0x45, 0x28, 0xF0, 0x34, 0xA8, 0x28, 0x87, 0x7B,
+        0x2A, 0x11, 0xCC, 0x5B, 0xB3, 0xDA, 0x6E, 0xCD, 0x10, 0x0E, 0xAD, 0x0D, 0x83, 0x9C, 0xF8,
+        0xB3, 0x79, 0x04, 0xEE, 0xBD, 0x7D, 0xB2, 0xF9, 0xBD, 0xB9, 0x13, 0xBF, 0x3A, 0x35, 0x1C,
+        0xAE, 0xA6, 0x01, 0x33, 0x0D, 0xDF, 0x97, 0xF9, 0xED, 0xE4, 0x98, 0xFA, 0x5C, 0xC2, 0xB1,
+        0x09, 0x69, 0x9E, 0x43, 0xA9, 0xC9, 0x86, 0x99, 0xA1, 0x87, 0xDB, 0x24, 0xA5, 0x95, 0x53,
+        0x7E, 0xB2, 0x2B, 0xA8, 0x9B, 0x58, 0xE0, 0x86, 0x24, 0xD7, 0x41, 0x9A, 0x06, 0xF2, 0x3C,
+        0xA5, 0xAF, 0x59, 0x97, 0xEE, 0xE4, 0x95, 0xC2, 0xBF, 0x3D, 0xEA, 0xDA, 0x61, 0x3F, 0x60,
+        0xFC, 0x3B, 0x9C, 0x34, 0x22, 0x50, 0x54, 0x1B, 0xB0, 0x0B, 0x19, 0xB9, 0x6A, 0x6A, 0x65,
+        0xEC, 0x1A, 0x0D, 0xD4, 0xDD, 0xCE, 0xD7, 0x34, 0xAE, 0x0E, 0x7F, 0xD1, 0x24, 0x4E, 0xA1,
+        0x4E, 0xFD, 0xC1, 0x50, 0xBF, 0x3B, 0x55, 0xC0, 0xD5, 0xF2, 0x59, 0x64, 0x69, 0xC5, 0x75,
+        0xCC, 0xE3, 0x5B, 0xF9, 0x87, 0x2B, 0x0D, 0x14, 0xDD, 0x22, 0x96, 0xBA, 0x76, 0x91, 0x5F,
+        0x6D, 0xA5, 0xD3, 0x41, 0xD8, 0xA3, 0xB8, 0x08, 0xD3, 0x46, 0xA0, 0x7D, 0x6F, 0xBE, 0xA5,
+        0xDB, 0x0C, 0x0C, 0xB0, 0x33, 0x2D, 0xD5, 0x24, 0x0D, 0xC0, 0x10, 0x56, 0x80, 0xEC, 0x3C,
+        0x80, 0xA1, 0xE8, 0xE7, 0x27, 0x53, 0x37, 0xBD, 0xFA, 0x32, 0x03, 0xCC, 0xC3, 0x86, 0xC9,
+        0x3D, 0x9C, 0x51, 0x27, 0x13, 0x29, 0x33, 0x99, 0x3F, 0xCB, 0x2D, 0x50, 0xF1, 0x6D, 0xC0,
+        0x30, 0x2B, 0xC5, 0x5E, 0x8B, 0x72, 0xE0, 0x6C, 0x91, 0x42, 0x64, 0x29, 0x90, 0x20, 0xC2,
+        0x53, 0x51, 0xF5, 0xE0, 0x7C, 0xE4, 0xE7, 0x26, 0xE9, 0x4D, 0x96, 0x34, 0x7A, 0xF8, 0xF0,
+        0x4A, 0x5E, 0x59, 0xE2, 0x66, 0x24, 0x6E, 0x8D, 0x9C, 0xB2, 0x2D, 0x7C, 0x6B, 0x8C, 0x42,
+        0x25, 0x9D, 0xAC, 0xEC, 0xF1, 0x0C, 0xF7, 0x58, 0x51, 0xFB, 0x59, 0x09, 0xB7, 0xBF, 0xB4,
+        0x44, 0xC2, 0x6A, 0xC8, 0x01, 0xE9, 0xC1, 0x74, 0x3D, 0x51, 0x4A, 0xBB, 0xE7, 0x3B, 0x69,
+        0x29, 0x8D, 0x8F, 0x4D, 0xED, 0x58, 0x76, 0x27, 0xA4, 0x44, 0x71, 0x27, 0x0A, 0xFC, 0x43,
+        0xE5, 0x79, 0x1F, 0x71, 0xD6, 0x99, 0xAE, 0x36, 0xC8, 0x98, 0xFE, 0xF4, 0x68, 0x6F, 0x94,
+        0x0C, 0x0C, 0x63, 0x36, 0x4A, 0x04, 0x08, 0x40, 0xD8, 0x02, 0x2D, 0xC0, 0xA5, 0x3E, 0xA4,
+        0xF2, 0x46, 0x02, 0xCC, 0x36, 0x0E, 0x72, 0x4A, 0x9F, 0x08, 0xF7, 0xC7, 0x61, 0x4D, 0x1B,
+        0xD9, 0x12, 0x08, 0xC7, 0x89, 0xE1, 0x99, 0xB3, 0xEE, 0xFA, 0xBD, 0x1A, 0xC4, 0x07, 0xF2,
+        0x47, 0x88, 0x92, 0xF1, 0x20, 0x28, 0x8A, 0xA0, 0x0C, 0x98, 0x25, 0xB1, 0x06, 0xAA, 0x49,
+        0x40, 0xC4, 0x46, 0x37, 0x45, 0xFB, 0x0F, 0x73, 0x99, 0xB5, 0x70, 0xDB, 0xFD, 0x75, 0xAA,
+        0xFD, 0xA2, 0x21, 0x7C, 0x9B, 0x52, 0x9F, 0x32, 0x7D, 0xBB, 0xC4, 0xF7, 0x92, 0x3B, 0x17,
+        0xAC, 0xEE, 0x60, 0xDF, 0xB8, 0x10, 0x5C, 0x46, 0xC9, 0x30, 0x9E, 0xA1, 0x8C, 0x84, 0x7F,
+        0x25, 0xD3, 0x25, 0x67, 0x26, 0xC4, 0x11, 0xA0, 0xE1, 0x73, 0xE7, 0x0F, 0x79, 0xC0, 0x4C,
+        0x6F, 0xAD, 0x60, 0xF1, 0xE3, 0xC1, 0x52, 0xCE, 0x7D, 0xA9, 0x00, 0xD9, 0x04, 0x4B, 0xC4,
+        0xE9, 0x7F, 0xD2, 0xFC, 0x83, 0xEE, 0x2E, 0xB0, 0x59, 0x41, 0x6F, 0x5B, 0xBD, 0x6B, 0x24,
+        0x2A, 0xE8, 0x9A, 0xFF, 0x3A, 0x48, 0xD4, 0x9A, 0xCC, 0x39, 0x7A, 0xD3, 0x08, 0x3C, 0x75,
+        0x8E, 0x1E, 0xDE, 0xD7, 0x9F, 0xE8, 0xEF, 0x4F, 0xDE, 0x6F, 0x20, 0x8B, 0x1C, 0x6E, 0x96,
+        0x14, 0xBD, 0xA6, 0x80, 0x63, 0xD8, 0xC7, 0xC9, 0x58, 0x42, 0xD8, 0xA1, 0x0E, 0x45, 0x38,
+        0x87, 0x6E, 0x20, 0x53, 0x03, 0x71, 0x06, 0xAF, 0x38, 0xFF, 0xB1, 0x2F, 0x0A, 0xC9, 0x66,
+        0x64, 0x30, 0x63, 0xA0, 0xF1, 0x67, 0x4C, 0x57, 0x20, 0x99, 0x33, 0xFD, 0x61, 0xFD, 0xD2,
+        0x5F, 0xA2, 0xCC, 0x5E, 0x9F, 0x7D, 0x66, 0x24, 0x12, 0xD9, 0x98, 0xE0, 0x78, 0x8C, 0xDF,
+        0x87, 0xED, 0x76, 0xF1, 0x15, 0xD8, 0xF5, 0x45, 0x24, 0x7D, 0xC8, 0x27, 0x41, 0xEE, 0xB6,
+    ];
+    let sk = [
+        0xD0, 0x67, 0xD9, 0x8F, 0x00, 0x55, 0xE2, 0xC3, 0xDE, 0xEF, 0x10, 0x76, 0xBB, 0xB7, 0x55,
+        0xAF, 0xD0, 0x65, 0x11, 0x2C, 0x85, 0xC4, 0x6E, 0x6C, 0x35, 0x06, 0x55, 0xD9, 0x62, 0x50,
+        0x73, 0xE9, 0x4E, 0x45, 0x28, 0xC0, 0x53, 0xE7, 0x20, 0x20, 0x6C, 0xDB, 0x78, 0x0A, 0x61,
+        0xAD, 0x51, 0x20, 0xC4, 0x98, 0xA4, 0xCD, 0x3E, 0x60, 0xC3, 0x34, 0xD8, 0x70, 0x2F, 0x0F,
+        0x79, 0xD8, 0x14, 0x18, 0xAF, 0x95, 0x9C, 0xAB, 0x77, 0x22, 0xEB, 0xDD, 0x30, 0xDF, 0x0A,
+        0x47, 0x9E, 0x14, 0x03, 0xD3, 0x37, 0xAD, 0x92, 0x3E, 0x5D, 0xBC, 0xED, 0x4E, 0xE0, 0x48,
+        0x39, 0x02, 0x0F, 0x77, 0x51, 0xC0, 0x75, 0xA4, 0x89, 0x2A, 0xAB, 0xBB, 0x0E, 0x00, 0x1A,
+        0xBA, 0x95, 0x51, 0x5E, 0x1B, 0x65, 0xC6, 0x3C, 0x50, 0x3D, 0x97, 0xE5, 0x1E, 0xAF, 0xB9,
+        0xAC, 0x93, 0x0F, 0x5A, 0x1D, 0xBC, 0xB7, 0xE9, 0x38, 0x8B, 0x5E, 0xF1, 0xE7, 0x5E, 0x89,
+        0x3E, 0x1B, 0x5E, 0x49, 0x95, 0x5E, 0x7E, 0x3E, 0x4B, 0x38, 0x19, 0xDB, 0xA9, 0xEA, 0x9D,
+        0x23, 0xB0, 0x98, 0x6F, 0x0D, 0xA2, 0xAD, 0xDF, 0xF0, 0xA3, 0x10, 0x59, 0x52, 0x67, 0x56,
+        0x08, 0x29, 0xBC, 0xAC, 0x32, 0x5D, 0x3E, 0x5C, 0xD1, 0x75, 0x5D, 0x2B, 0xAF, 0xBA, 0x02,
+        0xD3, 0x5C, 0x4E, 0x42, 0x6A, 0x93, 0xBD, 0xAF, 0x18, 0x4C, 0xB6, 0x6F, 0x78, 0xE2, 0x8F,
+        0x5C, 0x7C, 0x8C, 0xA6, 0x6B, 0x87, 0x7B, 0x5D, 0x06, 0xDB, 0xAF, 0xA4, 0x85, 0x59, 0x73,
+        0x66, 0xB2, 0x14, 0xEB, 0xD0, 0x85, 0x81, 0xA8, 0x21, 0x9F, 0xE9, 0x3D, 0xB6, 0xA3, 0x67,
+        0x95, 0x98, 0xB5, 0x61, 0x00, 0x7E, 0x58, 0xB0, 0x7F, 0x13, 0xB5, 0x3A, 0xCB, 0xAC, 0x3F,
+        0x1A, 0x42, 0xAA, 0xD8, 0x82, 0x87, 0x66, 0xB8, 0xB9, 0xB4, 0x3A, 0xA8, 0x4E, 0xC6, 0xAD,
+        0x1D, 0x66, 0x29, 0x3F, 0x88, 0x22, 0x76, 0x7B, 0x2A, 0xC7, 0xD9, 0xA1, 0xD8, 0xB4, 0x71,
+        0x59, 0x0B, 0x5B, 0x25, 0x30, 0x45, 0xBB, 0x5C, 0x62, 0x21, 0x12, 0xA0, 0xF2, 0xC0, 0xC5,
+        0xDB, 0x11, 0xBF, 0x74, 0x9C, 0x82, 0xBC, 0x86, 0xB9, 0x3F, 0x92, 0xDF, 0x31, 0x67, 0x06,
+        0x6C, 0x9D, 0x7B, 0x0F, 0xCC, 0xB9, 0xCD, 0xD4, 0x53, 0x36, 0xDD, 0x74, 0x66, 0x9A, 0x65,
+        0x51, 0xD2, 0x05, 0x92, 0x28, 0xE2, 0xB0, 0xDB, 0x81, 0x74, 0x75, 0x60, 0x99, 0xB2, 0x5F,
+        0xD4, 0x1D, 0x5B, 0xEB, 0x34, 0x2E, 0x1D, 0x3A, 0x2E, 0x55, 0x66, 0xAC, 0xED, 0xAF, 0x9F,
+        0x6C, 0xF9, 0x82, 0x77, 0x89, 0x43, 0x8F, 0xC8, 0xC2, 0xB9, 0x8C, 0x6C, 0x06, 0xEF, 0x88,
+        0x8D, 0xBF, 0x01, 0x67, 0x73, 0xBD, 0x34, 0x0B, 0xB6, 0xCF, 0x89, 0x67, 0x3F, 0x07, 0xF9,
+        0x3F, 0x89, 0xDF, 0xC8, 0x53, 0xC2, 0x40, 0x27, 0x39, 0xB7, 0x29, 0x1E, 0x8C, 0xD7, 0x65,
+        0xD7, 0x63, 0x24, 0xDD, 0x1A, 0x1B, 0xE1, 0x76, 0x15, 0x61, 0xB2, 0x9E, 0x91, 0xED, 0xA3,
+        0x9C, 0x49, 0xE1, 0x8B, 0x38, 0x2C, 0x3A, 0xC0, 0x83, 0x06, 0x18, 0xF5, 0x55, 0x0B, 0x6E,
+        0xC7, 0x19, 0xF7, 0x0D, 0x51, 0x0F, 0x87, 0x49, 0xD2, 0x51, 0x8C, 0x27, 0x11, 0xD4, 0x49,
+        0x25, 0xAA, 0x5C, 0xEE, 0x7D, 0xAF, 0x64, 0xA3, 0x41, 0x1C, 0x00, 0x48, 0x9E, 0xD5, 0xA5,
+        0xDD, 0xC1, 0xE3, 0xF4, 0x69, 0x64, 0xB5, 0xE2, 0x57, 0x41, 0x7F, 0xEA, 0x5C, 0x68, 0xF1,
+        0x56, 0x51, 0xD6, 0x66, 0x04, 0xC4, 0xA3, 0xC3, 0x76, 0xB7, 0xD7, 0x3D, 0xC7, 0xAC, 0x10,
+        0x10, 0x4C, 0xCA, 0xA6, 0x94, 0x9A, 0xC3, 0x54, 0x6C, 0x14, 0x0F, 0xCD, 0x39, 0x80, 0x66,
+        0xC2, 0xEB, 0xDE, 0x9B, 0x46, 0x75, 0x57, 0xCA, 0x00, 0xC1, 0x25, 0xD3, 0x0F, 0xFC, 0xF2,
+        0xB3, 0x78, 0x87, 0x2B, 0x0B, 0x8C, 0x06, 0x4B, 0x19, 0x17, 0xE1, 0x79, 0xDC, 0x13, 0xE0,
+        0x00, 0x71, 0x4B, 0x3E, 0x80, 0x58, 0xF5, 0xF3, 0xA2, 0xF5, 0x82, 0xC5, 0x0A, 0x78, 0x3D,
+        0x3C, 0xF3, 0xF3, 0xA3, 0xF8, 0x56, 0x4A, 0x19, 0xE7, 0x5C, 0xDA, 0x2A, 0x1F, 0x62, 0xC4,
+        0xAC, 0xFD, 0xF3, 0x4A, 0xFE, 0x02, 0x1E, 0xA2, 0xD4, 0x24, 0x3A, 0x07, 0x37, 0xB7, 0x12,
+        0xA8, 0xC1, 0xED, 0x04, 0x55, 0x8F, 0xB4, 0x00, 0xA6, 0xBA, 0x1D, 0x02, 0x06, 0xBC, 0x72,
+        0xEA, 0xD9, 0x52, 0x59, 0xC6, 0x6C, 0xD2, 0xDC, 0xE5, 0x68, 0x3B, 0xC7, 0xCD, 0xBC, 0xF9,
+        0x25, 0x88, 0xBE, 0xF6, 0x44, 0x99, 0xA8, 0xED, 0xE1, 0xE6, 0xF6, 0xC8, 0x6C, 0x97, 0x38,
+        0x8A, 0x20, 0xAD, 0x2F, 0xF7, 0x13, 0x6A, 0x13, 0x5A, 0x89, 0x5A, 0xF1, 0x8E, 0x27, 0xD2,
+        0xDE, 0x58, 0x23, 0xD3, 0xC5, 0xA1, 0x74, 0x15, 0x28, 0x2C, 0x6B, 0x1B, 0xDD, 0x59, 0xC4,
+        0xC4, 0xD9, 0x4D, 0x94, 0xD5, 0x21, 0xAF, 0x15, 0x1A, 0xC7, 0x0C, 0x08, 0x54, 0xFA, 0x3C,
+        0x18, 0x07, 0x9F, 0x30, 0xF9, 0x25, 0x96, 0x7E, 0xE0, 0x03, 0x25, 0xFC, 0xD6, 0x62, 0x9A,
+        0xD3, 0x5F, 0x0F, 0xC5, 0xE1, 0x5A, 0xE7, 0xB1, 0xE4, 0x3C, 0x38, 0x07, 0x09, 0x87, 0xEA,
+        0xD6, 0x28, 0x79, 0x21, 0x99, 0x2E, 0x70, 0x65, 0xA1, 0x70, 0xB3, 0x0E, 0x1A, 0xD7, 0x79,
+        0x4E, 0x7E, 0x86, 0x60, 0xE2, 0xF9, 0xC6, 0x10, 0x8A, 0x53, 0xE1, 0x77, 0x43, 0xEB, 0x27,
+        0x88, 0x76, 0xC9, 0x20, 0xF2, 0x3D, 0x9B, 0xE6, 0xFE, 0xA0, 0xCD, 0xA7, 0xDA, 0x05, 0x88,
+        0x15, 0x65, 0x05, 0x98, 0x29, 0xA8, 0x4F, 0xC0, 0xF2, 0x33, 0x55, 0x48, 0x61, 0x13, 0x08,
+        0x88, 0x59, 0x5C, 0xC0, 0x50, 0xFB, 0xEE, 0xB7, 0xE7, 0x18, 0x9F, 0x02, 0x38, 0x5A, 0x81,
+        0x45, 0x4F, 0x9D, 0xEF, 0xF0, 0xA8, 0x9B, 0x13, 0xCC, 0xAB, 0xF2, 0x97, 0x0C, 0xD3, 0x95,
+        0xDD, 0x0D, 0xED, 0xC8, 0xC3, 0x33, 0x2A, 0xD1, 0x7B, 0x6C, 0xFF, 0x2C, 0x1E, 0x2B, 0xA8,
+        0x67, 0xE4, 0x74, 0x39, 0x47, 0xAE, 0x90, 0xEB, 0x89, 0xB7, 0x2D, 0x9A, 0x3F, 0x5E, 0x1A,
+        0xB1, 0x2A, 0x97, 0x55, 0xCD, 0x3B, 0x57, 0x5C, 0x7E, 0x5A, 0x15, 0x24, 0x60, 0x87, 0x62,
+        0xA7, 0x88, 0xD9, 0x23, 0xDE, 0x23, 0x3E, 0x81, 0x10, 0xE5, 0x84, 0xC2, 0xD4, 0x50, 0x30,
+        0x8A, 0xDA, 0x06, 0xCC, 0xA1, 0xB6, 0xD5, 0x6A, 0x22, 0x2A, 0xE9, 0x3E, 0xD6, 0x01, 0xDA,
+        0xA4, 0x29, 0xD1, 0x2C, 0x11, 0xEA, 0xE0, 0xDA, 0xAE, 0xEE, 0x36, 0xB8, 0xC7, 0xD9, 0x72,
+        0xC2, 0x29, 0xBD, 0x59, 0x98, 0x87, 0x26, 0xCA, 0x90, 0xBF, 0xF0, 0x9D, 0x99, 0x5B, 0x42,
+        0xF0, 0x1A, 0x69, 0xFC, 0xD4, 0xBA, 0x10, 0xFB, 0xEF, 0x43, 0xC1, 0xC8, 0x74, 0xB4, 0x93,
+        0xE8, 0x3B, 0x90, 0x4E, 0xCC, 0x50, 0x65, 0x7F, 0x6B, 0xE4, 0xB5, 0x54, 0xA5, 0x33, 0x1C,
+        0xEE, 0x26, 0xEA, 0x9F, 0x33, 0xD2, 0xA3, 0x7A, 0x83, 0x12, 0xD2, 0x29, 0xEA, 0x5C, 0xA5,
+        0x5C, 0xAF, 0xCD, 0x1B, 0xC6, 0xF4, 0x9E, 0x15, 0xC1, 0xC0, 0x55, 0x91, 0xAE, 0x74, 0x6B,
+        0x22, 0xF0, 0x7C, 0x9D, 0x33, 0xA8, 0xB7, 0x9A, 0x9D, 0x7E, 0x0B, 0x32, 0xBE, 0x80, 0x0B,
+        0x02, 0xCC, 0xCB, 0x2B, 0xD8, 0x06, 0x86, 0x56, 0x0A, 0xA3, 0x96, 0xF8, 0x79, 0xB1, 0x50,
+        0xA5, 0x78, 0x4F, 0x89, 0x20, 0x52, 0x86, 0x4B, 0xA9, 0xCF, 0xF1, 0x41, 0x4A, 0x03, 0xA0,
+        0xBE, 0xA2, 0xDC, 0xF1, 0x12, 0x5A, 0x34, 0x41, 0xF0, 0xA6, 0x25, 0x99, 0x91, 0x10, 0x64,
+        0xFC, 0x31, 0xEC, 0x64, 0x49, 0xB1, 0xF1, 0x73, 0x0B, 0xF3, 0x37, 0x80, 0x49, 0xB9, 0xA7,
+        0x0E, 0xFF, 0xE4, 0x9D, 0x95, 0x73, 0xB8, 0x9D, 0x71, 0x44, 0xAD, 0xB6, 0xFD, 0x65, 0x86,
+        0x31, 0x80, 0x81, 0xBB, 0x6E, 0xAD, 0x9F, 0x20, 0xD6, 0x21, 0x00, 0x26, 0xAE, 0x5F, 0x8C,
+        0x1E, 0x4E, 0x6D, 0xFB, 0xF3, 0x34, 0x65, 0x09, 0x64, 0xCD, 0x62, 0xB3, 0xDC, 0x73, 0xA1,
+        0x79, 0xD9, 0xFE, 0xEB, 0x39, 0x76, 0x73, 0x77, 0xD7, 0x66, 0x4C, 0x76, 0xB5, 0x68, 0xC7,
+        0xF8, 0x5A, 0x0B, 0xBE, 0x65, 0xAA, 0xB6, 0x1F, 0x2C, 0x20, 0x72, 0x6D, 0x43, 0x32, 0x96,
+        0x04, 0x8E, 0xBE, 0x64, 0xAB, 0x04, 0x96, 0xCA, 0xDF, 0x38, 0x47, 0xE1, 0x0B, 0x91, 0x32,
+        0x95, 0x34, 0x23, 0x4D, 0x8E, 0x7B, 0x8F, 0x28, 0x68, 0x64, 0x73, 0x69, 0xCD, 0xF0, 0xFF,
+        0x57, 0x07, 0xBE, 0xD8, 0xED, 0xB5, 0x8F, 0x25, 0xDD, 0x68, 0x15, 0x9F, 0xC8, 0xA0, 0x16,
+        0x2A, 0x85, 0xBB, 0xEA, 0x35, 0x31, 0x23, 0x51, 0xE5, 0x08, 0x63, 0x1F, 0xBD, 0x70, 0xF1,
+        0x58, 0xAC, 0xE9, 0xDE, 0xC7, 0x3A, 0x5A, 0xBE, 0x2A, 0x04, 0x72, 0xC8, 0x19, 0x15, 0x2C,
+        0xF7, 0xCF, 0xA1, 0x6D, 0x7B, 0x7A, 0x43, 0x48, 0x9A, 0x5E, 0x48, 0xA4, 0xC2, 0xA1, 0x79,
+        0x3B, 0x61, 0xFA, 0x5C, 0x3C, 0x9F, 0x02, 0xD0, 0x72, 0xDE, 0x4E, 0xED, 0x0C, 0xB3, 0xFE,
+        0x78, 0x9B, 0xAE, 0xFA, 0x40, 0x10, 0x04, 0x64, 0x6B, 0x1B, 0x9C, 0x9D, 0x5C, 0x63, 0xCF,
+        0x2C, 0xE7, 0xAB, 0x91, 0xC3, 0xC6, 0x9C, 0x1D, 0x97, 0xE8, 0x1E, 0x6B, 0x2B, 0x14, 0x12,
+        0x03, 0x35, 0xDB, 0x22, 0x04, 0x56, 0x97, 0xD7, 0xBB, 0x7F, 0x1E, 0x67, 0x65, 0xAF, 0x8F,
+        0xD7, 0xE5, 0xD8, 0xAB, 0xCB, 0xD6, 0x59, 0x8E, 0x7B, 0x1B, 0x3F, 0xEC, 0x79, 0x70, 0xE2,
+        0x00, 0xF6, 0x22, 0x67, 0xA1, 0x62, 0xE9, 0xDA, 0x90, 0x9C, 0xAE, 0x1A, 0x93, 0xE8, 0x8E,
+        0xBB, 0x82, 0xB7, 0x37, 0x8C, 0x33, 0x06, 0xA9, 0xDD, 0xEF, 0x07, 0xFF, 0x13, 0x7D, 0xCA,
+        0xCF, 0x4B, 0x6B, 0x09, 0x12, 0xB6, 0x5B, 0x43, 0x71, 0x1F, 0x27, 0xEE, 0xBF, 0xE2, 0xC5,
+        0x60, 0x06, 0x9B, 0xA0, 0x5A, 0x0C, 0x48, 0xD0, 0x6E, 0xFD, 0xAA, 0xEE, 0xAB, 0x06, 0x30,
+        0x9C, 0x12, 0x5A, 0x05, 0x1B, 0xD7, 0x96, 0xAA, 0xA0, 0xEC, 0x75, 0x6C, 0xEC, 0x8A, 0x2E,
+        0x41, 0xAA, 0x5A, 0x4E, 0xE3, 0x68, 0x56, 0xEE, 0xB4, 0x83, 0xC1, 0xBD, 0x91, 0xCA, 0x53,
+        0x04, 0xC6, 0xA1, 0x62, 0x6B, 0x54, 0xC4, 0xAD, 0x49, 0x42, 0x26, 0x9D, 0xBB, 0xC1, 0xE8,
+        0x2B, 0xD3, 0xC1, 0x68, 0x31, 0xB8, 0xFB, 0xB4, 0xE8, 0x44, 0x92, 0x45, 0xC0, 0x76, 0x08,
+        0x29, 0x04, 0xD7, 0x56, 0xBF, 0x19, 0x5A, 0xCB, 0xD7, 0xC2, 0x30, 0x97, 0x96, 0xD9, 0x45,
+        0x86, 0x93, 0xAB, 0x54, 0x1E, 0x20, 0x45, 0x95, 0xC4, 0xCB, 0x0B, 0x13, 0x92, 0xA8, 0x51,
+        0xCD, 0x71, 0x83, 0xDF, 0x5C, 0x02, 0x56, 0xC0, 0x59, 0x9C, 0x18, 0x91, 0x45, 0x3E, 0x3A,
+        0x95, 0xE7, 0x7B, 0x8D, 0x9A, 0x5B, 0x2D, 0xFC, 0x0B, 0x3F, 0xF4, 0x38, 0xBA, 0x0C, 0xEF,
+        0x61, 0xCF, 0x97, 0xC2, 0x9B, 0x99, 0xE1, 0x6D, 0x6B, 0xCD, 0xDA, 0xE8, 0x1F, 0x4E, 0x4C,
+        0x05, 0x55, 0xF8, 0x10, 0x12, 0xE1, 0x82, 0x3F, 0x36, 0xA0, 0xD8, 0x5C, 0x0B, 0x7D, 0x80,
+        0x11, 0x2E, 0x94, 0x99, 0x56, 0x16, 0x2F, 0x97, 0x55, 0xEC, 0x80, 0x90, 0x26, 0xCF, 0x9B,
+        0xDC, 0x1A, 0xA3, 0x91, 0x10, 0x8D, 0x05, 0xC0, 0x43, 0x17, 0x4B, 0x7A, 0x10, 0x50, 0xE0,
+        0x8D, 0x41, 0xBE, 0x4D, 0xD8, 0x6C, 0x22, 0xED, 0x4A, 0x8E, 0x5A, 0xF7, 0x8D, 0x2C, 0x8B,
+        0x5F, 0x0C, 0x2A, 0x35, 0xFF, 0x44, 0x2B, 0xF2, 0x2A, 0x91, 0x95, 0x63, 0x1B, 0x71, 0x3D,
+        0x31, 0x35, 0x80, 0x0A, 0xF2, 0x3A, 0x4E, 0x29, 0x8F, 0x95, 0x5D, 0x2D, 0x0A, 0x62, 0x9A,
+        0x75, 0x80, 0x3A, 0x21, 0xEF, 0xAE, 0x69, 0x9D, 0x29, 0x19, 0x75, 0x3F, 0x18, 0x98, 0x32,
+        0xA4, 0x5D, 0x71, 0x66, 0x5F, 0x71, 0xBA, 0xC9, 0x6F, 0x48, 0x52, 0x8D, 0x97, 0xE7, 0x0E,
+        0xE5, 0x79, 0x44, 0x93, 0xF0, 0x10, 0xD3, 0x50, 0x4F, 0x5B, 0xD3, 0x29, 0xDB, 0x16, 0x5A,
+    ];
+    let mut ss1 = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_dec(&mut ss1, &ct, &sk)?;
+
+    let ss_ref = [
+        0x29, 0x39, 0x92, 0x00, 0x0D, 0xC2, 0x88, 0xE8, 0x15, 0x2F, 0x94, 0x51, 0xF0, 0x6D, 0xD8,
+        0x35, 0xC7, 0x5E, 0xA0, 0x08, 0x66, 0x2B, 0xAC, 0xE0, 0xFB, 0x97, 0xA9, 0x7B, 0x3A, 0xFB,
+        0x54, 0xE4,
+    ];
+    assert_eq!(ss_ref, ss1);
+
+    Ok(())
+}
+
+#[test]
+fn test_poly_sq_tobytes() {
+    let mut a = Poly::new();
+    a.coeffs = [7; NTRU_N];
+    let mut r = [0u8; 3 * NTRU_PACK_DEG / 2];
+    poly_sq_tobytes(&mut r, &a);
+    let expected_r = [
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+        0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00, 0x07, 0x70, 0x00,
+    ];
+    assert_eq!(r, expected_r);
+}
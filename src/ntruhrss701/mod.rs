@@ -0,0 +1,107 @@
+//! NTRU-HRSS (Hülsing-Rijneveld-Schanck-Schwabe) variant with n = 701 and q = 8192
+
+/// Constants distinguishing this variant from the other ones
+mod variant {
+    /// Name of the variant
+    pub const CRYPTO_ALGNAME: &str = "ntruhrss701";
+    pub const NTRU_HPS: bool = false;
+    pub const NTRU_N: usize = 701;
+    pub const NTRU_LOGQ: usize = 13;
+
+    /// SHA3-256 digest of `pk` of the first NIST KAT (`count = 0`)
+    pub const KAT_PK_SHA3: [u8; 32] = [
+        0xAC, 0x1B, 0xCE, 0xC8, 0x4D, 0xD6, 0x50, 0x9F, 0x69, 0xA1, 0x7C, 0x25, 0xD8, 0xE7, 0xCA,
+        0xC0, 0xE2, 0x55, 0x84, 0x8B, 0x72, 0x9A, 0x24, 0x37, 0x49, 0x1E, 0xE9, 0xA3, 0x68, 0x43,
+        0xE9, 0x7C,
+    ];
+    /// SHA3-256 digest of `sk` of the first NIST KAT
+    pub const KAT_SK_SHA3: [u8; 32] = [
+        0x51, 0x40, 0x9F, 0x98, 0x8A, 0x7E, 0x55, 0x9D, 0xCA, 0xCF, 0xD1, 0x31, 0x93, 0x9F, 0xB3,
+        0x17, 0xE5, 0x38, 0xFF, 0xBC, 0xF7, 0xE6, 0x18, 0x8B, 0x2F, 0xF1, 0x16, 0xFA, 0xE5, 0x28,
+        0x49, 0x8D,
+    ];
+    /// SHA3-256 digest of `ct` of the first NIST KAT
+    pub const KAT_CT_SHA3: [u8; 32] = [
+        0xD7, 0xE3, 0x84, 0x10, 0x36, 0x7D, 0xD5, 0x4F, 0x07, 0x66, 0xA6, 0x59, 0x26, 0x79, 0xDC,
+        0x48, 0xA3, 0xBB, 0x55, 0xF1, 0xD2, 0x95, 0x29, 0x13, 0x3B, 0x8B, 0x4C, 0x15, 0x48, 0xA3,
+        0xF1, 0x5C,
+    ];
+    /// `ss` of the first NIST KAT
+    pub const KAT_SS: [u8; 32] = [
+        0x10, 0xAF, 0x7B, 0xA1, 0xD6, 0x25, 0xB1, 0x61, 0x72, 0xC5, 0xB8, 0x0E, 0x2E, 0xE5, 0x3A,
+        0xE9, 0xB7, 0xF3, 0xED, 0xBE, 0x2E, 0x22, 0x6F, 0x11, 0x3E, 0xDE, 0x5A, 0x0E, 0xA8, 0xD1,
+        0xA9, 0x78,
+    ];
+
+    /// `CRYPTO_CIPHERTEXTBYTES` as type-level integer
+    #[cfg(feature = "kem")]
+    pub type CiphertextSize = kem::generic_array::typenum::Sum<
+        kem::generic_array::typenum::U1024,
+        kem::generic_array::typenum::U114,
+    >;
+}
+
+#[path = "../api.rs"]
+mod api;
+#[path = "../cmov.rs"]
+mod cmov;
+#[path = "../kat.rs"]
+mod kat;
+#[path = "../kem.rs"]
+mod kem;
+#[cfg(feature = "kem")]
+#[path = "../kem_traits.rs"]
+mod kem_traits;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+#[path = "../locked_sk.rs"]
+mod locked_sk;
+#[cfg(feature = "masked")]
+#[path = "../masked.rs"]
+mod masked;
+#[path = "../owcpa.rs"]
+mod owcpa;
+#[path = "../pack3.rs"]
+mod pack3;
+#[path = "../packq.rs"]
+mod packq;
+#[path = "../params.rs"]
+mod params;
+#[path = "../poly.rs"]
+mod poly;
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+#[path = "../poly_avx2.rs"]
+mod poly_avx2;
+#[path = "../poly_divstep.rs"]
+mod poly_divstep;
+#[path = "../poly_lift.rs"]
+mod poly_lift;
+#[path = "../poly_mod.rs"]
+mod poly_mod;
+#[path = "../poly_r2_inv.rs"]
+mod poly_r2_inv;
+#[path = "../poly_rq_mul.rs"]
+mod poly_rq_mul;
+#[path = "../poly_s3_bitsliced.rs"]
+mod poly_s3_bitsliced;
+#[path = "../poly_s3_inv.rs"]
+mod poly_s3_inv;
+#[path = "../poly_ternary.rs"]
+mod poly_ternary;
+#[path = "../sample.rs"]
+mod sample;
+#[path = "../sample_iid.rs"]
+mod sample_iid;
+#[path = "../types.rs"]
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::api::*;
+pub use self::kat::*;
+pub use self::kem::*;
+#[cfg(feature = "kem")]
+pub use self::kem_traits::*;
+#[cfg(all(feature = "locked-memory", target_os = "linux"))]
+pub use self::locked_sk::*;
+pub use self::types::*;
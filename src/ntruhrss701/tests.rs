@@ -0,0 +1,671 @@
+use std::error;
+
+use super::packq::poly_sq_tobytes;
+use super::poly::Poly;
+use super::*;
+use crate::rng::AesState;
+
+type R = Result<(), Box<dyn error::Error>>;
+
+/// Run a known-answer test with every implementation of the ring multiplications
+fn each_backend(test: fn() -> R) -> R {
+    #[cfg(feature = "avx2")]
+    return crate::backend::for_each_backend(test);
+    #[cfg(not(feature = "avx2"))]
+    test()
+}
+
+#[test]
+fn test_api_sizes() {
+    assert_eq!(CRYPTO_PUBLICKEYBYTES, 1138);
+    assert_eq!(CRYPTO_SECRETKEYBYTES, 1450);
+    assert_eq!(CRYPTO_CIPHERTEXTBYTES, 1138);
+    assert_eq!(CRYPTO_BYTES, 32);
+}
+
+#[test]
+fn test_keypair_zeroed_rng() -> R {
+    each_backend(keypair_zeroed_rng)
+}
+
+fn keypair_zeroed_rng() -> R {
+    let mut pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut sk = [0u8; CRYPTO_SECRETKEYBYTES];
+    let mut rng = AesState::new();
+
+    crypto_kem_keypair(&mut pk, &mut sk, &mut rng)?;
+
+    let pk_ref = [
+        0x85, 0xE3, 0xD2, 0x88, 0x23, 0xE9, 0xE1, 0xA9, 0x31, 0xB6, 0xE5, 0x83, 0xAD, 0xF1, 0xB0,
+        0x16, 0x1E, 0xD1, 0x75, 0x78, 0x32, 0x4E, 0xD2, 0x89, 0x19, 0x62, 0x48, 0x34, 0x31, 0x41,
+        0x04, 0xDC, 0x82, 0x88, 0x6E, 0x81, 0x1A, 0x6D, 0xF7, 0x67, 0x99, 0xC5, 0x03, 0x8F, 0x04,
+        0xF4, 0xB2, 0x30, 0x91, 0x5F, 0xC8, 0x71, 0xE5, 0x95, 0xBF, 0x60, 0x46, 0x91, 0x20, 0x8C,
+        0xE6, 0x00, 0x9B, 0xEE, 0x21, 0xB3, 0x94, 0x69, 0xCC, 0x74, 0xBC, 0xAE, 0xF7, 0x8D, 0xEC,
+        0x29, 0xC7, 0x21, 0x02, 0xB8, 0x8D, 0xDA, 0xAB, 0xF9, 0xA6, 0x97, 0x4D, 0x57, 0xC2, 0x93,
+        0x98, 0xCD, 0xE6, 0x84, 0xF5, 0x94, 0x58, 0xBD, 0x38, 0x5A, 0xA0, 0x72, 0xBE, 0x21, 0x50,
+        0xDC, 0x66, 0x04, 0xF1, 0x0A, 0x6A, 0x73, 0x0F, 0xFB, 0x97, 0x81, 0xD0, 0xB2, 0xDB, 0x87,
+        0x31, 0xB7, 0xEB, 0xDC, 0x87, 0x6E, 0x99, 0x1E, 0xAC, 0x59, 0x0B, 0x64, 0x08, 0xD5, 0x35,
+        0x03, 0x82, 0x46, 0x0F, 0xA6, 0x92, 0xBD, 0xA8, 0x94, 0x56, 0x2D, 0xEF, 0x48, 0x05, 0x4D,
+        0x7A, 0x68, 0x2C, 0xBE, 0x9D, 0x54, 0x10, 0x02, 0x71, 0x83, 0xDB, 0x62, 0x07, 0xB6, 0x37,
+        0x96, 0xCD, 0x3B, 0xFC, 0x2A, 0x42, 0x66, 0x6D, 0x11, 0x30, 0x69, 0x28, 0x46, 0xAA, 0x34,
+        0xAA, 0x00, 0xAE, 0x9A, 0x72, 0xDF, 0x10, 0x84, 0x3D, 0x98, 0x56, 0x14, 0x41, 0x1A, 0xB4,
+        0x77, 0x6A, 0x80, 0x28, 0x66, 0xE2, 0x41, 0xDB, 0xDC, 0x00, 0x78, 0xE6, 0xE0, 0x6A, 0x5C,
+        0x8A, 0xA5, 0xF1, 0x0E, 0x6E, 0x9D, 0x4F, 0x55, 0x70, 0x82, 0xF7, 0x9F, 0x0E, 0x89, 0x86,
+        0xC7, 0xE1, 0xC9, 0xA7, 0x75, 0x44, 0x5E, 0xB4, 0x10, 0x28, 0xB0, 0x26, 0x53, 0x0E, 0x64,
+        0x18, 0x49, 0x06, 0xB0, 0x5C, 0x27, 0xC7, 0x09, 0x07, 0x9E, 0x43, 0x0F, 0xDD, 0x8E, 0x8E,
+        0xC4, 0x83, 0x1F, 0x86, 0xD7, 0x83, 0xCD, 0xFD, 0x6C, 0x17, 0xD7, 0xDC, 0x36, 0x28, 0xDB,
+        0xCF, 0xD9, 0x75, 0x65, 0xC5, 0x7E, 0x43, 0x00, 0xD0, 0x22, 0xD8, 0x0A, 0x5D, 0x8F, 0x09,
+        0x29, 0x9C, 0xE2, 0x7C, 0xB7, 0x07, 0x9E, 0xAA, 0xF3, 0x6B, 0x9C, 0xF6, 0x4D, 0x90, 0x8A,
+        0x65, 0x08, 0x1C, 0x48, 0x01, 0x64, 0x80, 0x56, 0x81, 0xE9, 0xAA, 0x54, 0xD9, 0x60, 0x34,
+        0x63, 0xC8, 0x37, 0xE3, 0x46, 0xAB, 0x76, 0xDC, 0xA9, 0x80, 0x42, 0x7E, 0x78, 0xCF, 0xB4,
+        0x49, 0xA3, 0x1E, 0xE8, 0x65, 0x32, 0x90, 0xF8, 0xE3, 0xEB, 0xF2, 0x2E, 0xC5, 0x8E, 0x53,
+        0x15, 0xD5, 0xC6, 0xFF, 0x6C, 0xA6, 0xFC, 0xE2, 0x19, 0x1B, 0xAC, 0x9F, 0xCF, 0xD0, 0xE2,
+        0x76, 0x05, 0xFA, 0x42, 0x19, 0x61, 0x62, 0x72, 0x12, 0xB4, 0x6F, 0x2F, 0xD5, 0xDD, 0xE5,
+        0x2F, 0x26, 0x08, 0xB9, 0xBD, 0x0C, 0x09, 0x2C, 0x77, 0x6D, 0xAF, 0x4F, 0x38, 0x0F, 0x4D,
+        0x29, 0xC6, 0xD3, 0xE2, 0x1A, 0xD8, 0x93, 0x36, 0xE1, 0x17, 0xB4, 0x3B, 0xAF, 0xA5, 0x79,
+        0x84, 0x92, 0xA0, 0xA2, 0x24, 0x0C, 0x71, 0x27, 0x62, 0x57, 0x91, 0xDE, 0x51, 0xA8, 0xD0,
+        0xC1, 0xBA, 0x60, 0x9B, 0xEA, 0x57, 0xBB, 0x1E, 0x6A, 0xB0, 0x45, 0x5A, 0x56, 0xD5, 0x77,
+        0xC1, 0x2F, 0xF7, 0x25, 0x6C, 0x1B, 0xCB, 0xD8, 0xF5, 0x2E, 0x5B, 0x04, 0x83, 0x56, 0x56,
+        0xAD, 0xC1, 0xFC, 0x49, 0xE6, 0x43, 0x61, 0xF5, 0x3C, 0xDC, 0x30, 0x15, 0xAA, 0x25, 0x31,
+        0x3B, 0x09, 0x20, 0x13, 0x3E, 0xF9, 0xD8, 0xCB, 0xD2, 0x82, 0xC7, 0x1B, 0xEF, 0xD1, 0x14,
+        0xDA, 0x89, 0xF0, 0x89, 0x91, 0x3C, 0x3C, 0x6D, 0xEB, 0xBE, 0xBD, 0xE8, 0x33, 0x91, 0xC1,
+        0xD4, 0x20, 0x64, 0x8C, 0x29, 0xC1, 0xCC, 0xCD, 0x31, 0x7F, 0x57, 0x52, 0xC4, 0x55, 0xA3,
+        0xA2, 0x67, 0x1C, 0x9C, 0x45, 0x0F, 0x5B, 0x0B, 0x41, 0x39, 0xCF, 0xD6, 0x9B, 0x1B, 0xBF,
+        0x75, 0x4E, 0xC1, 0x65, 0xD9, 0x15, 0xA1, 0x10, 0x6A, 0x9A, 0x1B, 0x6F, 0x92, 0x52, 0x24,
+        0xE5, 0x38, 0xD3, 0x43, 0x9B, 0xDC, 0x93, 0x8B, 0x2D, 0x16, 0xCF, 0x64, 0xF4, 0x06, 0xD1,
+        0x74, 0xCB, 0xF3, 0x0D, 0x41, 0x77, 0x01, 0xF5, 0xB6, 0xF6, 0x68, 0xB2, 0xCC, 0x51, 0x99,
+        0x40, 0xED, 0xAB, 0x76, 0x34, 0xD0, 0xF4, 0x71, 0x70, 0x28, 0x9B, 0x45, 0x3F, 0xC1, 0xA9,
+        0x58, 0xB7, 0x44, 0x06, 0xC7, 0x27, 0x17, 0x9E, 0x1B, 0x8D, 0xD6, 0x47, 0x44, 0x9B, 0xC7,
+        0x02, 0xC0, 0x8A, 0xA2, 0xA7, 0x32, 0x96, 0x68, 0xB8, 0x07, 0x76, 0xB6, 0xD0, 0xDA, 0x2A,
+        0x8F, 0xC8, 0x97, 0xC7, 0xE0, 0x4A, 0x8E, 0xBE, 0xB1, 0x7C, 0xE8, 0xF3, 0x38, 0x46, 0x46,
+        0xE1, 0xF2, 0x75, 0xAC, 0x8C, 0x07, 0x7D, 0xC3, 0x53, 0x14, 0x2D, 0x96, 0xA8, 0xC3, 0x9C,
+        0x45, 0xD2, 0xBD, 0x35, 0x35, 0x30, 0x11, 0x52, 0x4C, 0x7A, 0xFF, 0xE3, 0xAB, 0x60, 0x51,
+        0x80, 0x93, 0xB7, 0xC7, 0x64, 0x7B, 0x1F, 0x5A, 0x71, 0xBF, 0x0A, 0x46, 0x9B, 0xC9, 0x31,
+        0x0E, 0x49, 0xEB, 0xB3, 0x01, 0x65, 0xB5, 0x3A, 0x99, 0x20, 0x6A, 0x74, 0x1B, 0xED, 0x0F,
+        0xC7, 0xC3, 0xBC, 0x38, 0xC8, 0xF8, 0x3D, 0x90, 0x72, 0x81, 0xED, 0x52, 0x6C, 0xA0, 0x9C,
+        0x8D, 0x7A, 0x17, 0x33, 0xD0, 0x60, 0x74, 0x20, 0xD7, 0x34, 0xBD, 0x5F, 0x30, 0x4F, 0x51,
+        0xFA, 0xDC, 0xC0, 0xC1, 0xAF, 0x58, 0x0D, 0x01, 0x2C, 0xDD, 0xB6, 0x01, 0x29, 0x6B, 0x11,
+        0x01, 0x69, 0x9E, 0xB5, 0x35, 0xAC, 0xA5, 0x5B, 0x51, 0x83, 0x38, 0xA1, 0x20, 0x49, 0xBD,
+        0xF9, 0x14, 0xF6, 0x31, 0xEA, 0x52, 0x9F, 0x62, 0x6D, 0xFF, 0x58, 0x21, 0x5D, 0x40, 0xC4,
+        0xCF, 0x39, 0xF0, 0xE4, 0xCB, 0x99, 0x46, 0x34, 0xFD, 0x20, 0x13, 0xD8, 0xD7, 0x7E, 0x72,
+        0x41, 0x5A, 0x03, 0x5E, 0x67, 0x2E, 0xD3, 0x86, 0x93, 0x87, 0x80, 0x1E, 0x11, 0xB9, 0x89,
+        0xF9, 0x41, 0x6E, 0xAB, 0x31, 0x56, 0x5C, 0x25, 0x94, 0xF2, 0xD8, 0x27, 0x21, 0xE9, 0xEB,
+        0xCB, 0x1B, 0xBE, 0xCC, 0xDE, 0x41, 0xBE, 0x59, 0x06, 0x31, 0x07, 0x32, 0x43, 0x1A, 0xD2,
+        0xF4, 0x35, 0x1F, 0xE2, 0x3D, 0x46, 0x8C, 0x8B, 0x46, 0xE9, 0x02, 0x78, 0xAC, 0x70, 0x90,
+        0x6C, 0xD4, 0x89, 0xB7, 0x7C, 0x18, 0x62, 0xA9, 0x0A, 0x87, 0xC5, 0x47, 0x32, 0xBB, 0xAE,
+        0x7E, 0x01, 0x19, 0x7E, 0x76, 0x6B, 0xC6, 0x4D, 0xC2, 0x3D, 0xED, 0x62, 0x3B, 0x3B, 0x67,
+        0x0C, 0x3F, 0xC1, 0x94, 0x3A, 0xA7, 0x23, 0x35, 0xFB, 0x8A, 0x07, 0x79, 0xEC, 0x33, 0x85,
+        0x26, 0xB1, 0xAD, 0xE9, 0xC0, 0x84, 0x63, 0xFE, 0xE7, 0x34, 0x2A, 0x50, 0x8D, 0xC0, 0x43,
+        0x8F, 0xEE, 0x27, 0x3B, 0xF5, 0x84, 0x8D, 0x11, 0xB5, 0x0D, 0x87, 0xF2, 0x56, 0x0C, 0x79,
+        0xA3, 0x79, 0x48, 0xA9, 0xBF, 0x0D, 0x1C, 0xCA, 0xD2, 0x54, 0x43, 0x7A, 0x63, 0x35, 0xFD,
+        0x56, 0xF1, 0x5C, 0x80, 0xAE, 0x78, 0x53, 0xB6, 0x00, 0x8D, 0xBA, 0x48, 0xF5, 0xB9, 0xDC,
+        0x38, 0xAC, 0xC5, 0x2C, 0x1D, 0xD2, 0xE0, 0x3E, 0x33, 0x59, 0xFE, 0x77, 0x4A, 0x6E, 0x03,
+        0xC5, 0x3B, 0x85, 0xC2, 0x70, 0x79, 0x65, 0xBF, 0xCB, 0x50, 0x6E, 0xD5, 0xE5, 0xEE, 0xDD,
+        0xCB, 0x34, 0xD7, 0x0F, 0x7F, 0xF2, 0xA5, 0x4F, 0x9B, 0x8F, 0xCB, 0x21, 0xF6, 0xAB, 0x0F,
+        0xEA, 0x1A, 0x3A, 0xE4, 0xDD, 0x8D, 0xA4, 0x53, 0xC7, 0x99, 0x04, 0x79, 0x22, 0x4B, 0x68,
+        0x1F, 0x5B, 0x3C, 0x06, 0x80, 0x64, 0x57, 0x34, 0xE8, 0x2E, 0x90, 0x25, 0x3F, 0x36, 0x53,
+        0x7C, 0xA5, 0x8A, 0xB2, 0x78, 0x15, 0x8E, 0x0D, 0xA0, 0x33, 0x9F, 0xFD, 0xF1, 0xD7, 0xDF,
+        0x9C, 0x23, 0x22, 0x5E, 0x76, 0x31, 0xD6, 0xD4, 0x66, 0xEE, 0xB0, 0x68, 0x26, 0x48, 0x73,
+        0x45, 0x79, 0x1B, 0xBD, 0xAF, 0xAB, 0x9E, 0x99, 0xEF, 0x69, 0x0E, 0xB1, 0x31, 0xCC, 0xCD,
+        0xE2, 0x5B, 0x23, 0x56, 0xBA, 0x61, 0x88, 0xE6, 0x68, 0x5E, 0x59, 0x8D, 0x03, 0x89, 0xE8,
+        0xE4, 0x43, 0x73, 0xFE, 0x7E, 0x79, 0x89, 0x66, 0xA4, 0x3C, 0xBC, 0xE9, 0xAF, 0x1C, 0x4D,
+        0x34, 0xDF, 0x2F, 0x7C, 0x89, 0xEC, 0x90, 0x73, 0xD1, 0xBC, 0x2E, 0xD9, 0x4A, 0xF9, 0xA5,
+        0x9A, 0x81, 0x5C, 0x6B, 0xA8, 0x35, 0x7B, 0xA2, 0x30, 0x32, 0x5D, 0x36, 0xE7, 0x8D, 0x9B,
+        0x60, 0x3D, 0x8B, 0xD9, 0x0F, 0xBF, 0x0F, 0x77, 0x4B, 0x0B, 0x27, 0x11, 0x0F,
+    ];
+    assert_eq!(pk_ref, pk);
+    let sk_ref = [
+        0xD9, 0x48, 0xED, 0x9D, 0xE2, 0x0C, 0x02, 0xB2, 0x25, 0xC9, 0x42, 0x27, 0x6D, 0x56, 0x80,
+        0x89, 0x29, 0xAB, 0xCE, 0xE5, 0x69, 0x59, 0x73, 0x28, 0xDF, 0x65, 0x7E, 0xA2, 0xA8, 0x0E,
+        0xB6, 0xDC, 0xA9, 0x8A, 0x3A, 0x3D, 0x6C, 0xD6, 0xDC, 0x5E, 0x95, 0x88, 0xA2, 0x93, 0x5D,
+        0xA8, 0x2E, 0x9F, 0xB5, 0x73, 0xC4, 0xE3, 0x29, 0x4D, 0xAE, 0xE4, 0xA2, 0xE0, 0xB7, 0xED,
+        0xD8, 0x66, 0x1A, 0x5F, 0x47, 0xEA, 0x8E, 0x1C, 0x8A, 0x93, 0x1A, 0xE7, 0xEF, 0x7A, 0x35,
+        0x7D, 0x77, 0xA7, 0x1F, 0x6E, 0x3D, 0x31, 0xB0, 0x21, 0xC4, 0xDD, 0xC6, 0xA6, 0x2A, 0xBD,
+        0xDC, 0x71, 0x3E, 0xD8, 0xE5, 0x7A, 0xC2, 0xF0, 0x12, 0x36, 0x28, 0x20, 0x76, 0x0D, 0x3D,
+        0xBF, 0x45, 0x24, 0xE8, 0x78, 0x95, 0x47, 0x31, 0x03, 0x8C, 0xDB, 0xDE, 0x56, 0x4B, 0x5F,
+        0x1B, 0x8C, 0x6D, 0xB0, 0xE1, 0xB6, 0xD7, 0x8C, 0xC4, 0x5D, 0xCA, 0x24, 0x94, 0x0C, 0x9E,
+        0x3B, 0x1B, 0x83, 0x4C, 0xE3, 0x96, 0xD3, 0x2C, 0x7F, 0x35, 0x81, 0x48, 0xD6, 0xDA, 0x35,
+        0x69, 0xAC, 0x4C, 0x00, 0x9E, 0x11, 0x78, 0xD3, 0x56, 0x76, 0x46, 0x5E, 0xC0, 0xD8, 0xE0,
+        0x75, 0xDB, 0x9B, 0x71, 0xB6, 0x6E, 0x72, 0xA0, 0x0F, 0x28, 0x5F, 0xD3, 0x76, 0xBD, 0xD6,
+        0x95, 0x43, 0x1C, 0x6D, 0x09, 0x00, 0x26, 0x8F, 0xDE, 0x9D, 0x97, 0xEF, 0x24, 0x0E, 0xCD,
+        0x95, 0x3E, 0xD3, 0x50, 0xD6, 0xAC, 0x8F, 0x2C, 0x99, 0x6D, 0x81, 0xCE, 0x45, 0x42, 0x39,
+        0xC4, 0xC0, 0xE3, 0x0A, 0x7D, 0x17, 0x62, 0xB2, 0x87, 0xA0, 0x68, 0x1C, 0x01, 0x5C, 0x07,
+        0xB4, 0x1D, 0x96, 0x23, 0x1E, 0x2E, 0x05, 0xEA, 0xAA, 0x15, 0x9E, 0x3A, 0xBF, 0xD2, 0xF2,
+        0x51, 0x15, 0xDE, 0x7D, 0x97, 0x61, 0x2C, 0xD6, 0xDE, 0xD0, 0x4A, 0x92, 0x9F, 0x15, 0x7E,
+        0x88, 0xAB, 0xBF, 0xBE, 0xC7, 0x76, 0x22, 0xD9, 0x9B, 0xA9, 0x01, 0x14, 0xB4, 0x9B, 0xEC,
+        0x9E, 0x1A, 0x39, 0x0E, 0x78, 0xF0, 0x04, 0xD4, 0x5F, 0x75, 0xCF, 0xE0, 0x40, 0xE8, 0x53,
+        0x97, 0x75, 0xEF, 0x93, 0xA1, 0x6D, 0x90, 0x5A, 0x28, 0x44, 0x03, 0xF7, 0x2D, 0x01, 0xFD,
+        0xFA, 0x19, 0xB6, 0x5C, 0x2C, 0x72, 0x6D, 0xD0, 0x79, 0x0E, 0xBE, 0x7F, 0xF7, 0x7A, 0xD8,
+        0x0A, 0x43, 0x1D, 0x2D, 0x5D, 0x2F, 0xDC, 0xF2, 0xC1, 0x07, 0x7F, 0x77, 0x1D, 0x09, 0x8F,
+        0x8D, 0xC0, 0xB2, 0x54, 0x91, 0xCA, 0xF0, 0x9B, 0x3E, 0x60, 0x6B, 0x1E, 0x50, 0xB6, 0x60,
+        0xFE, 0x83, 0x93, 0xC1, 0xD1, 0x1B, 0x90, 0x6D, 0xE0, 0x45, 0xC1, 0xE9, 0x0B, 0xCB, 0x2B,
+        0xA1, 0xF3, 0x9E, 0x84, 0x9F, 0x0B, 0x6F, 0xFE, 0xF0, 0xF2, 0xEF, 0x1F, 0xA2, 0xD1, 0x6E,
+        0xA8, 0xA4, 0x54, 0xB7, 0xC2, 0x6B, 0x36, 0x76, 0xD7, 0x06, 0xA9, 0xEE, 0xC1, 0x36, 0x68,
+        0xDA, 0x3A, 0xE3, 0xE7, 0x20, 0x78, 0xCA, 0x4B, 0x05, 0x49, 0x9B, 0x01, 0xAA, 0x6E, 0xE6,
+        0x88, 0x34, 0xD9, 0xE1, 0x1E, 0x26, 0x02, 0xCE, 0x7C, 0xD3, 0xEE, 0xE9, 0xC5, 0x63, 0x35,
+        0x6F, 0xD9, 0x6E, 0x9E, 0x93, 0xC8, 0x5E, 0x2C, 0x65, 0xB5, 0x95, 0xFB, 0x4F, 0xFB, 0xE0,
+        0x12, 0xB2, 0x80, 0x8A, 0xB9, 0xC1, 0x87, 0x5E, 0xE6, 0x5B, 0x33, 0x23, 0x51, 0x62, 0xF2,
+        0x57, 0x25, 0x8F, 0x44, 0xAC, 0x68, 0x1F, 0x1E, 0x0F, 0xF8, 0x3F, 0xB6, 0xBA, 0xBF, 0xE4,
+        0xE0, 0xDC, 0x1A, 0xDF, 0xD5, 0xE7, 0x6B, 0x0C, 0x30, 0x80, 0x4C, 0xD5, 0xC7, 0x6F, 0x68,
+        0x13, 0xD1, 0xB3, 0xF2, 0xCE, 0xAA, 0x5A, 0xE0, 0x87, 0x10, 0x46, 0x65, 0x71, 0x1A, 0x81,
+        0x6B, 0x0D, 0xAA, 0x70, 0x7D, 0x18, 0x86, 0x8A, 0x5D, 0x6B, 0x6A, 0xD7, 0x84, 0x18, 0x26,
+        0xC9, 0xCC, 0x39, 0x90, 0xD7, 0xFF, 0x96, 0x5F, 0xF2, 0x2D, 0x0F, 0x9C, 0x18, 0xCC, 0xF8,
+        0xE2, 0xC1, 0xB9, 0x83, 0x40, 0x78, 0xDD, 0x6F, 0x32, 0xAE, 0xC0, 0xE6, 0xDD, 0x8E, 0x1B,
+        0x21, 0xED, 0x4E, 0x5D, 0xCA, 0x31, 0x0F, 0xE3, 0x12, 0x57, 0xBB, 0x9B, 0xB7, 0xD4, 0xA4,
+        0xBC, 0xF2, 0x2A, 0x95, 0xEB, 0x7F, 0x46, 0x8A, 0xFF, 0xA4, 0xD3, 0xCF, 0x0F, 0x09, 0xA3,
+        0x6E, 0x8B, 0x47, 0x66, 0xD8, 0x52, 0xFC, 0x23, 0x39, 0x88, 0xB6, 0x2F, 0x4A, 0xE3, 0xBD,
+        0x9E, 0x16, 0xBC, 0x8E, 0xBD, 0x54, 0x33, 0xB6, 0x7B, 0xEC, 0x60, 0x88, 0xA3, 0xBE, 0x04,
+        0xD8, 0xA4, 0xF3, 0x91, 0x88, 0xF6, 0x9B, 0xF8, 0x2A, 0xDA, 0xFF, 0xC5, 0x75, 0xF4, 0xA7,
+        0x91, 0xAD, 0xB7, 0xB5, 0xD8, 0xE2, 0x49, 0x1E, 0xB7, 0xE6, 0x9F, 0x50, 0xCD, 0x3F, 0xA6,
+        0x14, 0x69, 0xB1, 0x38, 0xC9, 0xF1, 0x64, 0x7D, 0x24, 0x6D, 0x48, 0xC0, 0x2C, 0x4A, 0x97,
+        0x27, 0xF4, 0x49, 0x1E, 0x51, 0xED, 0x98, 0x69, 0x41, 0x10, 0xB0, 0xB9, 0x15, 0xCF, 0x08,
+        0xE8, 0xD2, 0x15, 0x30, 0xC5, 0x82, 0x24, 0x4D, 0x0F, 0x6B, 0x67, 0xB1, 0x72, 0xFF, 0x76,
+        0x27, 0x39, 0xE9, 0xCB, 0x2B, 0x62, 0x11, 0x4A, 0x57, 0x51, 0x89, 0xDB, 0x36, 0x50, 0x7A,
+        0x42, 0x03, 0xEE, 0x03, 0x00, 0x8C, 0x25, 0x6A, 0x99, 0x7D, 0xDF, 0xB7, 0xE4, 0x13, 0xF9,
+        0x42, 0x6A, 0x4D, 0x1C, 0x90, 0x94, 0x26, 0x19, 0x0F, 0xD5, 0xE9, 0x76, 0x5E, 0xC1, 0xC8,
+        0xBB, 0x91, 0xF2, 0xC7, 0xE8, 0x78, 0xD3, 0xD6, 0x6E, 0x4F, 0xF9, 0x16, 0xAA, 0xEC, 0x05,
+        0xD8, 0xFB, 0xB5, 0xAB, 0x1F, 0x20, 0x77, 0x54, 0x48, 0x5E, 0x67, 0xE1, 0xFC, 0xB7, 0x0E,
+        0x5E, 0x45, 0xAD, 0x5A, 0x28, 0xA3, 0x48, 0xEF, 0xD2, 0x8F, 0x9E, 0xBF, 0xFE, 0x47, 0x45,
+        0xEE, 0xA9, 0xD6, 0x66, 0xA5, 0xBE, 0x53, 0x25, 0x5F, 0x9A, 0x97, 0xF4, 0xEC, 0x6C, 0xEB,
+        0xD1, 0xC8, 0x4F, 0xA3, 0xD5, 0xD7, 0xFC, 0x08, 0xE5, 0x92, 0xAD, 0x57, 0xC1, 0x14, 0xB8,
+        0xF0, 0xA6, 0x7D, 0x4A, 0x6B, 0x6C, 0xF5, 0x64, 0x12, 0x47, 0xF8, 0x69, 0x16, 0xE6, 0x64,
+        0x9F, 0x01, 0xF1, 0x33, 0xC7, 0x51, 0xF0, 0x75, 0x7B, 0x4D, 0x37, 0xB9, 0x57, 0xA3, 0x17,
+        0xBA, 0xBE, 0xEA, 0x4D, 0xC1, 0x5A, 0x74, 0x72, 0xEF, 0xEA, 0x98, 0xAC, 0xF7, 0xE7, 0x9A,
+        0xDE, 0xE0, 0x2F, 0x99, 0x2D, 0x1E, 0x3D, 0x84, 0xFE, 0xC3, 0x2D, 0xE0, 0x57, 0x35, 0x82,
+        0xE5, 0x2E, 0x76, 0x44, 0x1D, 0xE3, 0xC6, 0x99, 0x40, 0x76, 0xA9, 0xE0, 0x9B, 0x0B, 0x73,
+        0x17, 0x12, 0xFA, 0x04, 0xC4, 0x4F, 0xC2, 0xDC, 0xB3, 0xE3, 0x88, 0x7C, 0x1B, 0xF9, 0xCA,
+        0xD4, 0x9F, 0xD8, 0x3C, 0xB4, 0xE4, 0x4E, 0xEE, 0x46, 0x16, 0x66, 0xA9, 0xE6, 0x09, 0x2F,
+        0x3B, 0x20, 0xBF, 0xF0, 0x0B, 0x7F, 0x09, 0x90, 0xF2, 0x9E, 0x21, 0x81, 0x01, 0x26, 0xBF,
+        0xB5, 0x94, 0x97, 0xF6, 0xA1, 0x19, 0x69, 0x50, 0x77, 0x08, 0x8D, 0x28, 0xDD, 0xE1, 0x08,
+        0xCB, 0xC0, 0x31, 0x26, 0x14, 0xF9, 0x9A, 0x00, 0x3E, 0x3B, 0x6C, 0x24, 0x44, 0x55, 0xBF,
+        0x53, 0xCD, 0x54, 0xBF, 0xE8, 0x61, 0xC6, 0x72, 0xF0, 0x67, 0xA7, 0xB6, 0x5B, 0x35, 0xC0,
+        0x03, 0x93, 0x8B, 0x15, 0xC2, 0x6C, 0x3F, 0x5C, 0x7A, 0x93, 0xB5, 0x96, 0x77, 0xF4, 0x84,
+        0x7A, 0xCC, 0x82, 0x3D, 0xA5, 0x36, 0xA0, 0x1B, 0xB3, 0x1A, 0x65, 0xBD, 0xAC, 0xD6, 0x25,
+        0x2D, 0xFD, 0xC5, 0x7C, 0x33, 0xC0, 0xB0, 0xE7, 0x75, 0xF9, 0x58, 0xE1, 0x76, 0x6E, 0xFB,
+        0x06, 0x6E, 0xD3, 0x98, 0x8C, 0x76, 0xD7, 0x3A, 0xFE, 0x55, 0xFE, 0x9F, 0x62, 0xE7, 0xE9,
+        0x83, 0x28, 0x4F, 0x9B, 0xDE, 0x4F, 0xA9, 0x8D, 0x9B, 0xAC, 0x8C, 0x1B, 0xCD, 0xF5, 0xE6,
+        0xF7, 0x18, 0xC6, 0xAA, 0xBB, 0xDB, 0x4D, 0xEE, 0xB1, 0x23, 0x4F, 0x18, 0xCA, 0x4E, 0x2B,
+        0xC9, 0x57, 0xB7, 0xC5, 0x46, 0x9E, 0xF9, 0x13, 0x71, 0x45, 0x42, 0x96, 0xE8, 0x77, 0x19,
+        0x8A, 0x7B, 0x7F, 0x90, 0x11, 0x53, 0x43, 0x55, 0x97, 0x89, 0xCD, 0xF0, 0xE0, 0x40, 0x08,
+        0xB7, 0xD6, 0xF2, 0x28, 0xD2, 0xD3, 0x19, 0x19, 0xB8, 0x79, 0x22, 0x67, 0x52, 0xE6, 0xF2,
+        0x4F, 0xCB, 0xDE, 0x31, 0xD4, 0x00, 0xFC, 0x3E, 0xDE, 0x55, 0x34, 0xFB, 0x62, 0x67, 0x47,
+        0x44, 0x75, 0xF1, 0x7E, 0xDC, 0xB4, 0xF4, 0x8A, 0xB5, 0x33, 0x1A, 0x5B, 0x95, 0xAC, 0x66,
+        0xFC, 0x56, 0xAE, 0xEE, 0x90, 0x03, 0xC2, 0x4C, 0x30, 0xF4, 0x3B, 0x8D, 0x6D, 0xBF, 0xC7,
+        0xF1, 0xB6, 0x41, 0x37, 0xEA, 0x6F, 0xB7, 0xD9, 0xF3, 0xA5, 0x0D, 0x43, 0x48, 0x63, 0x79,
+        0x2E, 0xEE, 0xBB, 0xAE, 0xE2, 0xE7, 0x29, 0x0E, 0xFA, 0xA7, 0x79, 0xC9, 0x3C, 0x86, 0xBD,
+        0xC2, 0x3C, 0x57, 0x69, 0x89, 0x87, 0x6D, 0x8D, 0xE2, 0xA5, 0xBD, 0x0A, 0x7D, 0x79, 0x69,
+        0x4F, 0x0E, 0x88, 0xEA, 0x5B, 0xF7, 0xA5, 0xE9, 0x1D, 0x52, 0x87, 0x72, 0x37, 0xAA, 0x04,
+        0xF0, 0x13, 0x67, 0x88, 0x2C, 0x3E, 0x2F, 0x37, 0x53, 0x72, 0xE1, 0x2C, 0x60, 0x09, 0x7E,
+        0xBE, 0x4C, 0xA6, 0x76, 0x06, 0x3C, 0x72, 0x51, 0x58, 0xEC, 0x85, 0x41, 0x5B, 0x0F, 0x52,
+        0xBC, 0x5C, 0x2E, 0xB7, 0x1D, 0xA8, 0xE9, 0x31, 0xD6, 0x44, 0xBE, 0x92, 0xE4, 0xF2, 0xE8,
+        0x39, 0x22, 0x74, 0xD1, 0xAE, 0x52, 0x9C, 0xE7, 0x0E, 0xF1, 0x01, 0x71, 0xB2, 0xAC, 0x82,
+        0x3A, 0x45, 0xB2, 0x2B, 0xD9, 0xAA, 0x4D, 0xAA, 0xD4, 0xB5, 0x06, 0xEF, 0x0F, 0xD7, 0x0E,
+        0x3B, 0xD9, 0x20, 0xAE, 0xF1, 0x83, 0x68, 0x82, 0x64, 0x03, 0x23, 0x1E, 0xF2, 0xC2, 0xAC,
+        0xF3, 0xE7, 0x93, 0x8A, 0xB2, 0xCA, 0xA7, 0xE0, 0x2A, 0xFC, 0x42, 0x67, 0x5F, 0x6C, 0xA4,
+        0xC7, 0xBF, 0x3A, 0x7B, 0x6B, 0x14, 0xA7, 0x66, 0x0E, 0xA6, 0xBF, 0x27, 0x69, 0xF4, 0xA5,
+        0xB0, 0x3F, 0xCF, 0x6A, 0x9B, 0x12, 0xBC, 0xE8, 0x0F, 0xC6, 0x2E, 0x05, 0x55, 0xDC, 0xBE,
+        0x87, 0x3C, 0x06, 0x05, 0xEB, 0x4F, 0x15, 0x4B, 0x98, 0x01, 0xB5, 0x61, 0x86, 0x00, 0x89,
+        0xDC, 0x2D, 0x96, 0xF5, 0xAD, 0x31, 0x39, 0xDB, 0x2C, 0xCB, 0xC2, 0xF6, 0xF0, 0xDC, 0x10,
+        0x98, 0x1B, 0x41, 0xF2, 0xCB, 0x2F, 0x15, 0xBA, 0x6C, 0x44, 0x36, 0x23, 0x81, 0x7C, 0x8C,
+        0x57, 0xB7, 0xF4, 0x39, 0xF5, 0x4C, 0x9C, 0x90, 0x80, 0x07, 0xA1, 0xEE, 0xBD, 0xE7, 0xE1,
+        0xDF, 0x82, 0x13, 0x42, 0xB0, 0xF6, 0x29, 0x2D, 0xA0, 0x87, 0xB6, 0xE8, 0x44, 0xBF, 0xD5,
+        0xB9, 0x76, 0xE4, 0x92, 0x81, 0xDE, 0x8E, 0x0D, 0x8A, 0xD5, 0x15, 0x47, 0x40, 0x46, 0x01,
+        0x53, 0x54, 0x2B, 0xE5, 0x0A, 0x3F, 0x70, 0x83, 0x48, 0x06, 0x71, 0x96, 0xF5, 0x71, 0x17,
+        0x4D, 0xA6, 0xC3, 0xC0, 0x59, 0x5E, 0xF6, 0x07, 0x10, 0x36,
+    ];
+    assert_eq!(sk_ref, sk);
+
+    Ok(())
+}
+
+#[test]
+fn test_enc_kat1() -> R {
+    each_backend(enc_kat1)
+}
+
+fn enc_kat1() -> R {
+    let pk = [0u8; CRYPTO_PUBLICKEYBYTES];
+    let mut rng = AesState::new();
+    let mut ct = [0u8; CRYPTO_CIPHERTEXTBYTES];
+    let mut ss = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_enc(&mut ct, &mut ss, &pk, &mut rng)?;
+
+    let ct_ref = [
+        0x01, 0xE0, 0xFF, 0xFF, 0x7F, 0x00, 0x20, 0x00, 0xFE, 0x3F, 0x00, 0x08, 0x00, 0xFF, 0x1F,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x5F, 0x00, 0xFC,
+        0xFF, 0xFF, 0x1F, 0x00, 0xFE, 0xBF, 0x00, 0xF0, 0xFF, 0x01, 0x20, 0x00, 0xFC, 0xFF, 0xFF,
+        0x2F, 0x00, 0xFC, 0x7F, 0x00, 0x08, 0x00, 0xFF, 0xFF, 0xFF, 0x07, 0x80, 0xFF, 0x1F, 0x00,
+        0x02, 0xC0, 0xFF, 0xFF, 0xFF, 0x02, 0xC0, 0xFF, 0x0B, 0x00, 0xFF, 0x0F, 0x00, 0x00, 0x00,
+        0x00, 0x10, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0x80, 0xFF, 0x2F, 0x00, 0xFC, 0x7F, 0x00, 0x00,
+        0x00, 0xFF, 0x3F, 0x00, 0x04, 0x00, 0x00, 0xF0, 0xFF, 0x01, 0x40, 0x00, 0x00, 0x00, 0xFE,
+        0x5F, 0x00, 0xFC, 0xFF, 0xFF, 0x1F, 0x00, 0x00, 0xC0, 0xFF, 0x07, 0x00, 0x02, 0x00, 0x00,
+        0xF8, 0xFF, 0x00, 0xF0, 0xFF, 0x05, 0x80, 0xFF, 0x07, 0x00, 0x00, 0x40, 0x00, 0x00, 0x80,
+        0xFF, 0x1F, 0x00, 0xFC, 0x3F, 0x00, 0x00, 0x00, 0x02, 0xC0, 0xFF, 0x07, 0x80, 0xFF, 0x2F,
+        0x00, 0x00, 0x00, 0x00, 0xF8, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x03,
+        0x00, 0x00, 0xF8, 0xFF, 0x02, 0xC0, 0xFF, 0x07, 0x80, 0x00, 0x00, 0x00, 0xFC, 0x3F, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x1F, 0x00, 0xFE, 0x7F, 0x00, 0x00, 0x00,
+        0x00, 0xE0, 0xFF, 0x07, 0x80, 0xFF, 0x2F, 0x00, 0x00, 0xC0, 0xFF, 0x07, 0x00, 0x01, 0xE0,
+        0xFF, 0x07, 0x00, 0x00, 0x00, 0x00, 0xFC, 0xBF, 0x00, 0x00, 0x00, 0x00, 0xC0, 0xFF, 0x07,
+        0x80, 0xFF, 0x2F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00, 0x80, 0x00,
+        0x10, 0x00, 0xFE, 0xFF, 0xFF, 0x17, 0x00, 0xFE, 0x1F, 0x00, 0x04, 0x80, 0xFF, 0x0F, 0x00,
+        0x00, 0x80, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0xFE, 0xFF,
+        0xFF, 0x0F, 0x00, 0x01, 0xC0, 0xFF, 0x0B, 0x80, 0xFF, 0x0F, 0x00, 0x00, 0xC0, 0xFF, 0x0F,
+        0x00, 0xFF, 0x3F, 0x00, 0xFC, 0x7F, 0x01, 0xE0, 0xFF, 0x03, 0xC0, 0xFF, 0x17, 0x00, 0xFE,
+        0x3F, 0x00, 0xFC, 0x7F, 0x01, 0xF0, 0xFF, 0x01, 0x40, 0x00, 0xF0, 0xFF, 0x01, 0xE0, 0xFF,
+        0x0B, 0x00, 0xFF, 0x1F, 0x00, 0x02, 0x80, 0xFF, 0x17, 0x00, 0xFF, 0xFF, 0xFF, 0x07, 0x80,
+        0xFF, 0x1F, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x3F, 0x00, 0xFC, 0xFF, 0x00, 0x10,
+        0x00, 0xFE, 0xFF, 0xFF, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00,
+        0xC0, 0xFF, 0x07, 0x00, 0xFF, 0x1F, 0x00, 0x08, 0x00, 0xFF, 0x0F, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x80, 0xFF, 0x1F, 0x00, 0xFC, 0x7F, 0x00, 0x08, 0x00,
+        0xFE, 0x3F, 0x00, 0x00, 0x80, 0xFF, 0x0F, 0x00, 0x04, 0xC0, 0xFF, 0x07, 0x00, 0x01, 0xE0,
+        0xFF, 0xFF, 0x7F, 0x01, 0xE0, 0xFF, 0x01, 0x80, 0x00, 0xF0, 0xFF, 0x01, 0x00, 0x00, 0xFC,
+        0x7F, 0x01, 0xF0, 0xFF, 0x01, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0xFC, 0xFF, 0xFF,
+        0x0F, 0x00, 0x02, 0x40, 0x00, 0xF0, 0xFF, 0x00, 0x40, 0x00, 0xF8, 0x7F, 0x01, 0x00, 0x00,
+        0x00, 0x80, 0xFF, 0x07, 0x00, 0x02, 0x00, 0x00, 0xF8, 0x7F, 0x01, 0xF0, 0xFF, 0x01, 0x00,
+        0x00, 0x08, 0x00, 0xFF, 0x3F, 0x00, 0xFC, 0x7F, 0x00, 0x10, 0x00, 0x00, 0x80, 0xFF, 0x07,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xE0, 0xFF, 0x03, 0x00, 0x00, 0xF8, 0xFF, 0x01,
+        0x00, 0x00, 0xFC, 0x7F, 0x01, 0xF0, 0xFF, 0xFF, 0x3F, 0x00, 0x10, 0x00, 0xFF, 0xFF, 0xFF,
+        0x0B, 0x80, 0xFF, 0x1F, 0x00, 0xFE, 0xFF, 0xFF, 0x07, 0x00, 0x01, 0x00, 0x00, 0x00, 0x80,
+        0xFF, 0x2F, 0x00, 0x00, 0xC0, 0xFF, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00,
+        0x00, 0xFC, 0x3F, 0x00, 0x00, 0x00, 0x02, 0xC0, 0xFF, 0x03, 0x00, 0x01, 0xF0, 0xFF, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0xC0, 0xFF, 0x07, 0x80, 0x00, 0xE0, 0xFF, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0xFF, 0x1F, 0x00, 0x04, 0x00, 0x00, 0x10, 0x00, 0xFE, 0xFF, 0xFF, 0x07, 0x00,
+        0x00, 0x40, 0x00, 0x00, 0x80, 0xFF, 0x1F, 0x00, 0x00, 0xC0, 0xFF, 0xFF, 0xFF, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0xC0, 0xFF, 0x0F, 0x00, 0xFF, 0xFF, 0xFF, 0x0B,
+        0x00, 0x00, 0x00, 0x00, 0xFC, 0xBF, 0x00, 0xF0, 0xFF, 0x01, 0xE0, 0xFF, 0x0B, 0x80, 0xFF,
+        0x0F, 0x00, 0x02, 0x00, 0x00, 0xF0, 0xFF, 0x02, 0xE0, 0xFF, 0xFF, 0x7F, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x00, 0xF0, 0xFF, 0x00, 0x40, 0x00, 0x00, 0x00, 0xFF, 0x1F, 0x00, 0x02, 0x80,
+        0xFF, 0x07, 0x00, 0x01, 0x20, 0x00, 0xF8, 0x7F, 0x01, 0xE0, 0xFF, 0x05, 0x00, 0x00, 0xF8,
+        0xFF, 0xFF, 0x5F, 0x00, 0xFC, 0xFF, 0x00, 0x00, 0x00, 0xFE, 0x3F, 0x00, 0x00, 0x00, 0x01,
+        0xC0, 0xFF, 0x03, 0x00, 0x01, 0x00, 0x00, 0xFE, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0xFF, 0x2F, 0x00, 0x00, 0x80, 0xFF, 0x07, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x80, 0xFF, 0x17, 0x00, 0x00, 0xC0, 0xFF, 0x07, 0x80, 0xFF, 0x1F,
+        0x00, 0x00, 0xC0, 0xFF, 0x17, 0x00, 0xFF, 0xFF, 0xFF, 0x0B, 0x80, 0xFF, 0xFF, 0xFF, 0x01,
+        0x40, 0x00, 0xF8, 0xFF, 0x01, 0x20, 0x00, 0xF8, 0x7F, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0xF8, 0xFF, 0x01, 0xE0, 0xFF, 0x0B, 0x80, 0xFF, 0x1F, 0x00, 0xFC, 0x3F, 0x00, 0x08, 0x00,
+        0xFF, 0x1F, 0x00, 0x04, 0x80, 0x00, 0xF0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0xFE, 0x1F,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0xFE, 0x1F, 0x00, 0x04,
+        0x00, 0x00, 0x10, 0x00, 0xFE, 0x3F, 0x00, 0xF8, 0xFF, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xE0, 0xFF, 0x05, 0xC0, 0xFF, 0xFF, 0xFF, 0x02, 0xC0, 0xFF, 0x07, 0x80, 0xFF, 0x2F, 0x00,
+        0xFC, 0x7F, 0x00, 0x00, 0x00, 0xFF, 0x3F, 0x00, 0x00, 0x80, 0xFF, 0x2F, 0x00, 0xFE, 0xFF,
+        0xFF, 0x07, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xFF, 0x0F, 0x00, 0x04, 0x00, 0x00, 0xF0,
+        0xFF, 0x00, 0x00, 0x00, 0x08, 0x80, 0xFF, 0xFF, 0xFF, 0x01, 0x40, 0x00, 0xF8, 0xFF, 0x00,
+        0x20, 0x00, 0x04, 0x00, 0xFF, 0x0F, 0x00, 0x04, 0x80, 0xFF, 0x0F, 0x00, 0x00, 0x20, 0x00,
+        0xFC, 0xFF, 0xFF, 0x1F, 0x00, 0x00, 0x40, 0x00, 0xF0, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0xE0, 0xFF, 0x01, 0x80, 0x00, 0x00, 0x00, 0xFE, 0x1F, 0x00, 0x04, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x80, 0xFF, 0x0F, 0x00, 0xFF, 0x3F, 0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x05,
+        0xC0, 0xFF, 0xFF, 0xFF, 0x01, 0xE0, 0xFF, 0x03, 0x00, 0x00, 0x00, 0x00, 0x04, 0x80, 0xFF,
+        0x17, 0x00, 0xFF, 0x3F, 0x00, 0x00, 0x80, 0xFF, 0x1F, 0x00, 0xFC, 0xBF, 0x00, 0xF8, 0xFF,
+        0xFF, 0x1F, 0x00, 0x04, 0x80, 0xFF, 0x2F, 0x00, 0xFC, 0x7F, 0x00, 0xF8, 0xFF, 0x02, 0xC0,
+        0xFF, 0x0B, 0x00, 0xFF, 0x1F, 0x00, 0x00, 0xC0, 0xFF, 0x17, 0x00, 0x00, 0x00, 0x00, 0xF8,
+        0x7F, 0x00, 0x10, 0x00, 0x02, 0x80, 0xFF, 0x0F, 0x00, 0x00, 0xE0, 0xFF, 0x07, 0x00, 0x00,
+        0xF0, 0xFF, 0x05, 0x00, 0x00, 0xF8, 0xFF, 0x00, 0x00, 0x00, 0xFC, 0x7F, 0x01, 0xF0, 0xFF,
+        0xFF, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0xFC, 0xFF, 0xFF, 0x2F, 0x00, 0xFE, 0x3F,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0xFC, 0xFF, 0xFF, 0x1F, 0x00, 0x00, 0xC0, 0xFF, 0x0F,
+        0x00, 0x00, 0x20, 0x00, 0xFC, 0xFF, 0xFF, 0x0F, 0x00, 0x04, 0x00, 0x00, 0xF0, 0xFF, 0x02,
+        0xE0, 0xFF, 0xFF, 0x7F, 0x01, 0x00, 0x00, 0xFC, 0x7F, 0x00, 0x00, 0x00, 0x01, 0xE0, 0xFF,
+        0x07, 0x80, 0xFF, 0x0F, 0x00, 0x00, 0x40, 0x00, 0xF8, 0xFF, 0x00, 0xE0, 0xFF, 0x07, 0x80,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF8, 0xFF, 0xFF, 0x1F, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xF0, 0xFF, 0x02, 0xE0, 0xFF, 0xFF, 0x7F, 0x01, 0x00,
+    ];
+    assert_eq!(ct_ref, ct);
+    let ss_ref = [
+        0x56, 0x59, 0x33, 0x56, 0x9C, 0x40, 0xC0, 0xA5, 0x30, 0x15, 0x84, 0x1D, 0x5A, 0x9B, 0x9F,
+        0x35, 0x7A, 0xC9, 0xE0, 0xD2, 0xBC, 0x81, 0xC4, 0x7B, 0x59, 0x97, 0xE1, 0x97, 0xFD, 0x40,
+        0x99, 0x8A,
+    ];
+    assert_eq!(ss_ref, ss);
+
+    Ok(())
+}
+
+#[test]
+fn test_dec_kat1() -> R {
+    each_backend(dec_kat1)
+}
+
+fn dec_kat1() -> R {
+    let ct = [
+        0x4F, 0xB2, 0x9C, 0xE5, 0xCE, 0x75, 0x34, 0x44, 0xA0, 0xB9, 0x41, 0xD8, 0x77, 0x30, 0x5F,
+        0x93, 0x38, 0xED, 0x3F, 0x4F, 0x30, 0xF5, 0xE8, 0xA0, 0xBC, 0x1F, 0x41, 0xAD, 0x98, 0x17,
+        0xB1, 0x52, 0x8C, 0x9E, 0x86, 0xCC, 0xFF, 0x4E, 0x6F, 0x6B, 0xD3, 0x64, 0xC1, 0xEB, 0xA2,
+        0xAF, 0xF7, 0xD4, 0x83, 0x35, 0xE7, 0x1F, 0xAC, 0x9B, 0xFE, 0x5B, 0x85, 0xE9, 0xFF, 0x19,
+        0xB3, 0xE6, 0x0E, 0xAA, 0x35, 0x17, 0xE8, 0x89, 0x13, 0xF4, 0x1C, 0xD0, 0x10, 0x49, 0x2B,
+        0x9C, 0x06, 0x0D, 0x89, 0xBA, 0xDF, 0xA5, 0x25, 0x7B, 0x1E, 0xA7, 0x7F, 0x1E, 0x32, 0xAE,
+        0x2C, 0x2F, 0xAE, 0xAA, 0xB5, 0x87, 0xAD, 0x30, 0x5F, 0xBB, 0x99, 0x96, 0x1C, 0xE8, 0x8D,
+        0x39, 0xDB, 0xA4, 0x8D, 0x8B, 0xC5, 0x98, 0xEF, 0x3D, 0xC8, 0xBF, 0xEC, 0xE9, 0x7E, 0x67,
+        0x30, 0x1B, 0xCE, 0x08, 0x1E, 0xF8, 0xEE, 0x52, 0x1E, 0xF2, 0x3F, 0xE7, 0x0A, 0x98, 0x83,
+        0x4B, 0x63, 0xE0, 0x21, 0xB4, 0x16, 0x1D, 0x1A, 0x9C, 0xD0, 0x03, 0xC3, 0x00, 0xF2, 0xE9,
+        0xBE, 0xF0, 0xFD, 0xD8, 0x41, 0x91, 0x53, 0x19, 0x43, 0x63, 0x13, 0x7F, 0x28, 0x81, 0x04,
+        0x62, 0xD5, 0x12, 0x16, 0x1C, 0x82, 0xFE, 0xF9, 0xD5, 0xC1, 0x1E, 0x88, 0x25, 0x0B, 0x00,
+        0xBF, 0x51, 0x20, 0x2E, 0x02, 0x34, 0x10, 0x78, 0xB4, 0x0F, 0x89, 0xBB, 0xE5, 0xEA, 0x8B,
+        0xF0, 0x13, 0x2C, 0x6E, 0xFB, 0xD7, 0x5A, 0x2C, 0x27, 0x28, 0x6D, 0xF1, 0x46, 0x34, 0x42,
+        0x55, 0x20, 0xC7, 0xBF, 0x66, 0x03, 0xA6, 0xB8, 0x05, 0xD1, 0xB3, 0x74, 0x90, 0xE2, 0xF1,
+        0x51, 0x64, 0xB4, 0xD2, 0x76, 0xC1, 0xB4, 0xDE, 0x29, 0x7F, 0x39, 0x7B, 0x48, 0x02, 0x75,
+        0xB8, 0xE3, 0x99, 0x08, 0xA7, 0x85, 0xE5, 0xB4, 0x9B, 0x29, 0x64, 0x73, 0xDA, 0x62, 0x27,
+        0x0C, 0x2D, 0x0A, 0x39, 0x6D, 0xB1, 0x89, 0x8D, 0x19, 0x0A, 0x04, 0x3D, 0x04, 0x2D, 0x4F,
+        0xD7, 0xFF, 0x4E, 0xDB, 0x90, 0x46, 0x68, 0x61, 0xB2, 0xC5, 0x90, 0x09, 0x14, 0x59, 0xD0,
+        0x22, 0xF9, 0x82, 0x63, 0x16, 0xBC, 0xE8, 0xED, 0x5A, 0x57, 0x76, 0x9D, 0xB3, 0xEF, 0xB5,
+        0x1B, 0xB2, 0xFD, 0xDC, 0x3C, 0x78, 0x82, 0xD3, 0xFC, 0x38, 0x60, 0xC3, 0x38, 0xE1, 0x66,
+        0x4B, 0x7C, 0xCD, 0x93, 0xAC, 0xCA, 0x5E, 0xB9, 0x34, 0x2D, 0xBD, 0xF1, 0x0B, 0x1D, 0x9B,
+        0x53, 0xD3, 0x9E, 0xF8, 0x91, 0x7D, 0x9C, 0xDB, 0x0F, 0x22, 0x2F, 0x6B, 0xB2, 0xBC, 0xB7,
+        0xED, 0x50, 0x98, 0x28, 0x46, 0xF3, 0x9F, 0xA4, 0x86, 0x23, 0x9E, 0x73, 0x8E, 0x48, 0xE5,
+        0x90, 0x81, 0xBD, 0xC4, 0x64, 0x85, 0xCF, 0x64, 0xB8, 0xD3, 0xA0, 0xCD, 0x1D, 0x43, 0x0D,
+        0x26, 0x6B, 0x1E, 0x89, 0xDA, 0xC0, 0xC0, 0x0B, 0x8D, 0xE1, 0x9D, 0x8A, 0x89, 0x01, 0xBC,
+        0x65, 0x05, 0xFC, 0xD7, 0xA3, 0x84, 0xA8, 0xC0, 0x44, 0x64, 0x29, 0x8D, 0x9E, 0x27, 0x42,
+        0x0C, 0x6D, 0x97, 0x31, 0x77, 0xB6, 0xC0, 0x0B, 0x8F, 0x4E, 0x28, 0xDF, 0x7B, 0x67, 0x50,
+        0xAF, 0x0B, 0x7F, 0x66, 0x97, 0x27, 0x08, 0x06, 0xDA, 0x80, 0x66, 0x47, 0x91, 0x82, 0xDD,
+        0xBC, 0x86, 0x65, 0x17, 0xAA, 0x49, 0x45, 0x89, 0xA3, 0x85, 0x7B, 0x63, 0xA4, 0x00, 0x46,
+        0x72, 0xBE, 0x2D, 0x52, 0x10, 0x52, 0x82, 0xC7, 0x52, 0xE0, 0xD5, 0x5F, 0x1C, 0x5A, 0x29,
+        0xA2, 0x34, 0x5B, 0xE5, 0x1B, 0x39, 0x1A, 0x6D, 0xD9, 0x6F, 0x4B, 0xFB, 0x34, 0xEF, 0x0A,
+        0x6E, 0x43, 0x01, 0x4C, 0x6F, 0x71, 0x2E, 0x3B, 0x53, 0xF5, 0xF6, 0x0A, 0x50, 0x25, 0x02,
+        0x48, 0x10, 0xCB, 0xDE, 0x01, 0x2C, 0x2F, 0xFB, 0x9B, 0xF4, 0x86, 0x4B, 0xEC, 0x3A, 0x6B,
+        0x1E, 0x08, 0xD6, 0xDA, 0xCF, 0xBD, 0xFE, 0x54, 0xC9, 0xD8, 0x75, 0xCE, 0x88, 0xF3, 0x48,
+        0x8F, 0x2C, 0xFB, 0xBA, 0xFA, 0x38, 0x33, 0x40, 0x62, 0x15, 0x16, 0x7A, 0x86, 0xDD, 0x4A,
+        0x6B, 0xC0, 0xEF, 0xC2, 0xE4, 0xCB, 0x6F, 0x28, 0x6F, 0xF4, 0x67, 0x0F, 0x23, 0xCA, 0x8D,
+        0x8A, 0xB7, 0x8F, 0x55, 0x00, 0x0F, 0x14, 0x38, 0xAA, 0x2C, 0xA1, 0x19, 0x27, 0xB9, 0x7F,
+        0xD5, 0x9D, 0x77, 0xC4, 0x4B, 0xBD, 0xA6, 0xB7, 0xAE, 0xD9, 0x6F, 0xB8, 0xF6, 0x6A, 0x25,
+        0x7A, 0x3D, 0x10, 0x1F, 0x4C, 0xE0, 0x24, 0xD9, 0x8E, 0x9D, 0xF1, 0x57, 0xC8, 0x97, 0x86,
+        0x24, 0xC8, 0x38, 0x76, 0x58, 0x54, 0x3D, 0x9D, 0x96, 0x45, 0xB5, 0x7B, 0x92, 0x3D, 0xE2,
+        0x70, 0x2D, 0x4F, 0xB1, 0x00, 0x74, 0x0A, 0x82, 0xBB, 0x21, 0xBD, 0x8B, 0x79, 0xF1, 0x4F,
+        0x79, 0x55, 0x68, 0x4E, 0x49, 0xC4, 0xEC, 0x7E, 0x6B, 0x72, 0xA2, 0xF1, 0x73, 0x6F, 0xAA,
+        0x1F, 0xEC, 0x3B, 0xB3, 0xB3, 0x1D, 0xDA, 0xFE, 0xD9, 0x5B, 0x1E, 0x50, 0xA7, 0x13, 0xEC,
+        0x00, 0x50, 0xD5, 0xA9, 0x5B, 0x94, 0x89, 0xAA, 0xCF, 0x77, 0x84, 0x7B, 0x87, 0xD1, 0xCB,
+        0xFF, 0xAE, 0xA9, 0x0F, 0x1A, 0xF0, 0xEE, 0x5E, 0x2A, 0xAC, 0xB5, 0xD8, 0x0E, 0x9B, 0xF1,
+        0x42, 0xCA, 0xB9, 0x27, 0x22, 0xB0, 0xED, 0x72, 0xBE, 0x31, 0x83, 0x5C, 0x9F, 0xD0, 0xCF,
+        0x16, 0x0C, 0x14, 0x00, 0x51, 0x6B, 0xDC, 0x81, 0xAF, 0x7C, 0xC7, 0x10, 0x14, 0x68, 0x40,
+        0xFA, 0xE0, 0x56, 0xA7, 0x89, 0x8F, 0xFE, 0xFF, 0xD8, 0x19, 0xB6, 0x4C, 0x3E, 0x2E, 0x94,
+        0x6D, 0x2A, 0xB4, 0x38, 0x06, 0x31, 0xD9, 0x97, 0x8E, 0xDB, 0x61, 0x0B, 0xE0, 0x91, 0x8E,
+        0xDB, 0x7C, 0x31, 0x4A, 0x6F, 0x97, 0x14, 0xE3, 0x94, 0xDF, 0xDD, 0x2D, 0xE7, 0xC7, 0x88,
+        0xB0, 0xE6, 0xB0, 0x1A, 0x57, 0xFB, 0x46, 0xD0, 0xCF, 0x27, 0xFB, 0xF6, 0xFD, 0x45, 0x9F,
+        0xBF, 0xC7, 0xFE, 0xF8, 0x40, 0xD0, 0xB8, 0x63, 0xED, 0x6E, 0xF1, 0xE0, 0xA1, 0xA9, 0x3E,
+        0xD0, 0xCD, 0x24, 0x32, 0xC5, 0x5E, 0x8C, 0xDA, 0xAD, 0xF6, 0xCB, 0x31, 0xB1, 0xF1, 0x87,
+        0x2B, 0xDE, 0x39, 0x03, 0x7F, 0x5D, 0xBC, 0x94, 0xCC, 0xDE, 0x7A, 0x43, 0xAB, 0xBF, 0x89,
+        0x02, 0x29, 0x1F, 0xCA, 0xF0, 0x63, 0xD6, 0xC4, 0xC1, 0x70, 0x52, 0xF6, 0x3A, 0x06, 0xAD,
+        0x88, 0xAE, 0xDA, 0x5B, 0xBE, 0xCE, 0xFB, 0x91, 0x4D, 0xA4, 0xCA, 0xB6, 0x0F, 0x33, 0xE9,
+        0xA7, 0xAC, 0x89, 0xEA, 0xD9, 0xA0, 0xDF, 0xB0, 0x3A, 0xFE, 0xEA, 0x45, 0x6A, 0xF7, 0x55,
+        0x86, 0x49, 0x13, 0x3E, 0x77, 0x86, 0x2F, 0x28, 0x76, 0xA3, 0xE2, 0x1F, 0xB5, 0x7A, 0xB2,
+        0x29, 0x3F, 0xCA, 0xFE, 0xF6, 0xBF, 0x71, 0xE5, 0x53, 0x08, 0xFA, 0xED, 0x72, 0x02, 0x16,
+        0xBB, 0x66, 0xD5, 0xA8, 0x91, 0x72, 0xE3, 0x85, 0x87, 0xB1, 0xB3, 0x5D, 0x07, 0xF8, 0xDB,
+        0xAA, 0x54, 0x70, 0xF7, 0x26, 0xC3, 0x61, 0xC3, 0x00, 0xC7, 0xB9, 0x67, 0x2A, 0xE9, 0xB9,
+        0xF2, 0xDD, 0x8E, 0x7A, 0x17, 0x1E, 0x38, 0x07, 0xDB, 0x74, 0x5D, 0xDE, 0x43, 0x4A, 0xC3,
+        0x20, 0x60, 0xCD, 0x27, 0xF4, 0x46, 0xF8, 0xAA, 0xDB, 0xB2, 0xC2, 0x34, 0x97, 0x5D, 0x42,
+        0x30, 0x8C, 0xE3, 0x7C, 0x63, 0x72, 0x27, 0xFC, 0xF7, 0x97, 0x7B, 0x0C, 0x01, 0x42, 0x77,
+        0x2A, 0xAF, 0xC0, 0x47, 0x67, 0x85, 0x08, 0x08, 0x6B, 0x5F, 0xEA, 0x05, 0x95, 0x06, 0xDD,
+        0x66, 0x01, 0xE1, 0x52, 0xDA, 0xCD, 0x2C, 0xF2, 0x35, 0xB7, 0x2F, 0xFF, 0x07, 0xB5, 0x08,
+        0x74, 0xF2, 0x53, 0x12, 0x55, 0x1B, 0x28, 0xF6, 0xDB, 0x1E, 0x92, 0x50, 0x1E, 0x1E, 0x04,
+        0x59, 0xA8, 0x2A, 0xD5, 0x4D, 0xF1, 0xCC, 0x90, 0x67, 0xFF, 0xA3, 0x16, 0xD3, 0x1C, 0xCE,
+        0x67, 0x47, 0x64, 0x3E, 0xF5, 0x14, 0xAB, 0x6C, 0x04, 0x61, 0x89, 0xD8, 0x1E, 0x04, 0x2E,
+        0x7E, 0x8F, 0xC9, 0xF2, 0xD1, 0x90, 0x09, 0x0B, 0x1F, 0x44, 0x7C, 0x8F, 0x86, 0x72, 0x36,
+        0x5A, 0x0F, 0x52, 0x83, 0x8A, 0x17, 0x85, 0x37, 0xB0, 0x91, 0x8B, 0x2A, 0x28, 0xCE, 0x1F,
+        0x3A, 0x20, 0x71, 0x15, 0x63, 0x1F, 0x5A, 0x9C, 0x53, 0xCE, 0xEC, 0x45, 0x33, 0x06, 0xDC,
+        0x03, 0xAB, 0x07, 0x8F, 0xB6, 0x72, 0xBF, 0x9A, 0x87, 0x65, 0x36, 0x4E, 0x70, 0x90, 0x4F,
+        0x1E, 0xD2, 0xE5, 0x43, 0x2F, 0x5E, 0x9B, 0x83, 0xC5, 0xC7, 0xB5, 0xA7, 0xDF, 0x47, 0xD3,
+        0xB5, 0xE2, 0xB1, 0xC0, 0x15, 0xA4, 0xE6, 0x72, 0x2E, 0xCF, 0x9C, 0x8A, 0x06,
+    ];
+    let sk = [
+        0xD0, 0x67, 0xD9, 0x8F, 0x00, 0x55, 0xE2, 0xC3, 0xDE, 0xEF, 0x10, 0x76, 0xBB, 0xB7, 0x55,
+        0xAF, 0xD0, 0x65, 0x11, 0x2C, 0x85, 0xC4, 0x6E, 0x6C, 0x35, 0x06, 0x55, 0xD9, 0x62, 0x50,
+        0x73, 0xE9, 0x4E, 0x45, 0x28, 0xC0, 0x53, 0xE7, 0x20, 0x20, 0x6C, 0xDB, 0x78, 0x0A, 0x61,
+        0xAD, 0x51, 0x20, 0xC4, 0x98, 0xA4, 0xCD, 0x3E, 0x60, 0xC3, 0x34, 0xD8, 0x70, 0x2F, 0x0F,
+        0x79, 0xD8, 0x14, 0x18, 0xAF, 0x95, 0x9C, 0xAB, 0x77, 0x22, 0xEB, 0xDD, 0x30, 0xDF, 0x0A,
+        0x47, 0x9E, 0x14, 0x03, 0xD3, 0x37, 0xAD, 0x92, 0x3E, 0x5D, 0xBC, 0xED, 0x4E, 0xE0, 0x48,
+        0x39, 0x02, 0x0F, 0x77, 0x51, 0xC0, 0x75, 0xA4, 0x89, 0x2A, 0xAB, 0xBB, 0x0E, 0x00, 0x1A,
+        0xBA, 0x95, 0x51, 0x5E, 0x1B, 0x65, 0xC6, 0x3C, 0x50, 0x3D, 0x97, 0xE5, 0x1E, 0xAF, 0xB9,
+        0xAC, 0x93, 0x0F, 0x5A, 0x1D, 0xBC, 0xB7, 0xE9, 0x38, 0x8B, 0x5E, 0xF1, 0xE7, 0x5E, 0x89,
+        0x3E, 0x1B, 0x5E, 0x49, 0x95, 0xE9, 0x0F, 0x71, 0x69, 0x1C, 0x40, 0xB0, 0x03, 0x52, 0xA9,
+        0x6C, 0xB9, 0xBE, 0x2E, 0x1D, 0x94, 0x42, 0x3F, 0x89, 0x7D, 0xED, 0x5F, 0xD4, 0x66, 0xE8,
+        0x4C, 0xB7, 0x22, 0x90, 0x01, 0x49, 0x81, 0xDE, 0x96, 0x2E, 0xBF, 0x41, 0xB4, 0x2B, 0xE1,
+        0xE8, 0xB8, 0xB7, 0xBE, 0x2E, 0xDF, 0xDB, 0x49, 0xA5, 0x58, 0xA6, 0x81, 0x45, 0x81, 0x96,
+        0xD3, 0xB3, 0x95, 0x0A, 0xA7, 0x3F, 0x4D, 0xE4, 0xE8, 0x08, 0x9B, 0xD4, 0xE1, 0x61, 0x14,
+        0x6F, 0xD1, 0x16, 0x51, 0xC9, 0x55, 0xDB, 0x46, 0x61, 0xDF, 0xE0, 0xC1, 0x81, 0x85, 0xEE,
+        0x2D, 0x7A, 0x25, 0xE2, 0x4D, 0xBA, 0xCA, 0x2E, 0xAD, 0x84, 0x54, 0x1D, 0xAA, 0xAC, 0x37,
+        0x95, 0xBB, 0x1F, 0xD2, 0x53, 0x6C, 0xCE, 0xBC, 0x57, 0x2E, 0xA4, 0x71, 0x84, 0x5B, 0x66,
+        0x9C, 0x33, 0x6F, 0x0F, 0xE7, 0x05, 0x5D, 0xBF, 0x16, 0x19, 0x7F, 0x51, 0x95, 0x36, 0x94,
+        0x46, 0x78, 0x9F, 0x0C, 0xAF, 0x8A, 0x0E, 0x38, 0x8F, 0xE6, 0x84, 0x6E, 0x5F, 0x35, 0xB0,
+        0xE3, 0x09, 0xF9, 0x6A, 0x93, 0x2F, 0x05, 0x50, 0x2F, 0xDF, 0xFE, 0xCB, 0x30, 0x71, 0xCF,
+        0x7F, 0xAD, 0x85, 0x3A, 0x05, 0xB2, 0x4A, 0x71, 0x5C, 0xEF, 0xCA, 0xD7, 0x2E, 0x4C, 0x56,
+        0xEA, 0x03, 0xED, 0x49, 0x8C, 0x90, 0x87, 0x3D, 0x99, 0xC3, 0xA8, 0x0D, 0xA4, 0x8D, 0xA2,
+        0xAA, 0x70, 0x24, 0xF7, 0xE4, 0xAF, 0x7D, 0xC9, 0xA6, 0x54, 0x6D, 0xCD, 0x59, 0x6D, 0xBC,
+        0x8F, 0x5A, 0x65, 0xD5, 0x64, 0xE0, 0x8A, 0x11, 0x97, 0x43, 0x76, 0x8E, 0xF8, 0x4A, 0x79,
+        0x9D, 0xCE, 0xB4, 0x9D, 0xF2, 0x43, 0x21, 0x2B, 0x82, 0x3E, 0x5F, 0x0C, 0x85, 0x39, 0xCA,
+        0x9F, 0x60, 0x04, 0xF0, 0x56, 0x12, 0x33, 0x30, 0x45, 0x0E, 0x25, 0xD0, 0xA1, 0xC3, 0xCD,
+        0x3C, 0x8A, 0x4B, 0xF3, 0x31, 0xF4, 0xE5, 0xAF, 0xA7, 0x70, 0xB2, 0xF7, 0x95, 0x41, 0x2B,
+        0x47, 0x2D, 0x04, 0xFF, 0xAC, 0x26, 0xF1, 0xE8, 0x26, 0xE7, 0x2C, 0xE3, 0x36, 0xA2, 0x95,
+        0x85, 0x7A, 0x7E, 0x65, 0xEB, 0xB1, 0x07, 0x7C, 0x74, 0xF3, 0x61, 0x02, 0xFC, 0xEE, 0xF3,
+        0x45, 0x52, 0x95, 0x4A, 0xA8, 0x31, 0xA3, 0xCA, 0x6E, 0xA4, 0xED, 0xEE, 0x22, 0x64, 0xDB,
+        0x71, 0x04, 0x7F, 0xBC, 0xD4, 0x22, 0x00, 0xD2, 0xE9, 0x73, 0xE0, 0x1A, 0x92, 0xCD, 0xD7,
+        0x1D, 0xFC, 0xD2, 0x90, 0xD4, 0xE5, 0x50, 0x79, 0xA9, 0x52, 0x05, 0x1D, 0x6E, 0x79, 0x0A,
+        0xA0, 0xA8, 0x56, 0x61, 0xD5, 0x04, 0x11, 0x3F, 0xD2, 0x94, 0xC9, 0x67, 0xB8, 0x48, 0xD7,
+        0x2F, 0x58, 0xDB, 0xBE, 0xE6, 0x7C, 0x27, 0x93, 0x47, 0xBF, 0x03, 0xDB, 0x5D, 0xEC, 0x7B,
+        0x0C, 0xD1, 0xD4, 0x44, 0xA8, 0x52, 0xEA, 0x83, 0x1B, 0x18, 0x55, 0xE9, 0x45, 0x85, 0x57,
+        0xE6, 0x25, 0x0A, 0x9B, 0xD4, 0xCE, 0x5F, 0x29, 0x7E, 0xA4, 0x69, 0x73, 0x2A, 0x0C, 0xDC,
+        0x01, 0x84, 0x2A, 0x38, 0xA8, 0xF8, 0x9C, 0xBB, 0x9B, 0xAD, 0x23, 0x19, 0xA6, 0xEF, 0xA9,
+        0x5F, 0xE4, 0x72, 0xA7, 0xD8, 0x7F, 0xAD, 0xBD, 0x95, 0x38, 0x83, 0x7B, 0x6D, 0x00, 0xCF,
+        0x12, 0x3A, 0xAB, 0x07, 0x58, 0x5E, 0x2A, 0x0A, 0x8E, 0xEC, 0x91, 0xAC, 0x7A, 0xE0, 0x87,
+        0x59, 0x2A, 0xC7, 0x37, 0x69, 0x5A, 0x7C, 0xC6, 0x6A, 0x1C, 0xA1, 0x99, 0x62, 0x2D, 0x2C,
+        0xCF, 0x3C, 0x46, 0xD3, 0xB5, 0x3D, 0x1B, 0x4D, 0x84, 0x0A, 0x5B, 0x22, 0x31, 0xB4, 0xE7,
+        0x68, 0x0F, 0xAE, 0xF7, 0xDB, 0x87, 0x76, 0xE0, 0x2E, 0x5B, 0x49, 0x6C, 0x22, 0x09, 0x1D,
+        0x03, 0xEC, 0x07, 0xA4, 0x4D, 0x9E, 0x91, 0x0F, 0x59, 0x1C, 0xA4, 0x98, 0xBF, 0xCF, 0x02,
+        0xF7, 0x92, 0xD0, 0x3E, 0xEB, 0x55, 0x64, 0x3A, 0x91, 0x76, 0x89, 0x10, 0x11, 0x61, 0x94,
+        0x0D, 0x6B, 0x59, 0xF9, 0x94, 0x3B, 0x72, 0x03, 0x66, 0xE3, 0xB0, 0x55, 0x49, 0xCE, 0x7D,
+        0x89, 0x72, 0xDF, 0x1A, 0x4F, 0x3C, 0x63, 0x25, 0x1D, 0xB6, 0x10, 0x84, 0xFE, 0x93, 0x65,
+        0xFF, 0xED, 0xCE, 0xB7, 0xCE, 0xDE, 0x9E, 0x27, 0x7E, 0xBA, 0xA4, 0x3A, 0xBE, 0x56, 0x7F,
+        0x92, 0xC9, 0x7A, 0x53, 0x45, 0x25, 0x62, 0xF0, 0x5F, 0x32, 0xA7, 0xEE, 0x34, 0x7E, 0xA1,
+        0x60, 0x0E, 0x0A, 0xB9, 0x7E, 0x68, 0x6D, 0x0E, 0xBA, 0xF0, 0xC2, 0x58, 0xEF, 0x8C, 0x76,
+        0x32, 0xD6, 0xCC, 0xAA, 0xA5, 0x32, 0x3D, 0xAC, 0x2E, 0xD8, 0xFD, 0xD5, 0x45, 0x78, 0x90,
+        0x49, 0x31, 0xA6, 0x14, 0xCD, 0x4E, 0x8D, 0x33, 0x86, 0xC9, 0x3A, 0x41, 0x60, 0xC5, 0x7C,
+        0x2F, 0x72, 0xB4, 0x2E, 0xB2, 0xD3, 0x5A, 0xA2, 0x87, 0xF1, 0x82, 0x4D, 0xA8, 0x21, 0xF9,
+        0x0C, 0x20, 0x42, 0x25, 0x5F, 0xFF, 0xD3, 0x8D, 0xB3, 0x99, 0x83, 0x55, 0x79, 0x96, 0xF8,
+        0x67, 0x73, 0x96, 0x13, 0x79, 0x63, 0xFD, 0x03, 0x06, 0xB5, 0xA8, 0x70, 0x27, 0x14, 0x47,
+        0x7B, 0xB3, 0x50, 0x1F, 0x75, 0x58, 0xE6, 0x6D, 0xF1, 0x09, 0x07, 0xCA, 0x94, 0x10, 0xC5,
+        0x37, 0x0C, 0x2A, 0x99, 0x07, 0x5A, 0x7C, 0xB0, 0x66, 0xD1, 0xAF, 0x78, 0xEE, 0x91, 0xD9,
+        0x1D, 0xDA, 0x37, 0x7B, 0x36, 0xAE, 0x2D, 0xFA, 0x26, 0x5A, 0xF6, 0x20, 0x43, 0x6F, 0xA1,
+        0x13, 0x32, 0xBB, 0xD6, 0x1D, 0xAC, 0x03, 0x24, 0xFB, 0x90, 0xBA, 0x58, 0xD7, 0x4C, 0xE6,
+        0x29, 0x9E, 0x29, 0xC0, 0x4E, 0xBF, 0x2F, 0xE4, 0x44, 0x15, 0x54, 0x53, 0x70, 0x47, 0xE3,
+        0x5B, 0x3C, 0xA4, 0x36, 0x11, 0x8E, 0xF2, 0x86, 0x38, 0xA3, 0x2A, 0x30, 0xEC, 0x1A, 0xCE,
+        0xB2, 0x56, 0xFC, 0x1B, 0xB8, 0x4C, 0xEE, 0x81, 0x27, 0xC8, 0x2A, 0x72, 0x5D, 0x1E, 0x80,
+        0x3B, 0x61, 0x95, 0x6E, 0x50, 0xC4, 0xD6, 0xAE, 0x0D, 0xC5, 0x3F, 0xCB, 0x5C, 0xD0, 0x68,
+        0x5D, 0x66, 0xE1, 0x50, 0x7B, 0xFF, 0x52, 0xFF, 0xDC, 0x8E, 0xE6, 0xD0, 0x1B, 0xD2, 0x49,
+        0x2C, 0xB4, 0x42, 0x00, 0x19, 0x5B, 0x26, 0x94, 0x86, 0x08, 0xF1, 0xEE, 0xA7, 0x27, 0x1C,
+        0xAF, 0x89, 0x70, 0xD4, 0x97, 0x44, 0x4E, 0xA5, 0x46, 0x37, 0x72, 0x70, 0x17, 0x1A, 0x53,
+        0x4A, 0xFE, 0xE0, 0x5D, 0x01, 0xE0, 0x5D, 0xA2, 0xDE, 0xE2, 0x50, 0x49, 0xC1, 0x44, 0x92,
+        0xB9, 0x2E, 0x72, 0xB8, 0xF8, 0x9E, 0xBB, 0x1E, 0x43, 0x56, 0x30, 0xB7, 0x44, 0x02, 0x26,
+        0xEB, 0x3E, 0x85, 0x66, 0x28, 0x40, 0x65, 0x12, 0x3D, 0xC0, 0x25, 0xA5, 0x3B, 0x8E, 0x98,
+        0x99, 0xE4, 0x1B, 0x63, 0x65, 0x54, 0x82, 0x8F, 0xF8, 0xA7, 0x9B, 0x41, 0x04, 0x33, 0x14,
+        0xC5, 0x45, 0x79, 0x81, 0xA8, 0x88, 0x6C, 0xE2, 0xF5, 0xB5, 0x5D, 0xE2, 0x05, 0x82, 0xF3,
+        0x6C, 0xCF, 0xD5, 0xA8, 0x5E, 0x43, 0x92, 0x28, 0xD6, 0x9B, 0x1A, 0x92, 0x75, 0x16, 0xBB,
+        0x05, 0x4F, 0x37, 0x95, 0xBA, 0x25, 0x30, 0x94, 0x1E, 0x49, 0x73, 0xF2, 0xD0, 0x9D, 0xD3,
+        0xC7, 0x32, 0x74, 0xF3, 0xAD, 0x04, 0x6A, 0x1D, 0xCE, 0x4A, 0xF7, 0x8E, 0xA5, 0xAA, 0x18,
+        0xB7, 0x68, 0x36, 0x8A, 0xA0, 0x97, 0xE7, 0xD6, 0xDA, 0x65, 0x47, 0x57, 0x54, 0xF9, 0x75,
+        0x03, 0x88, 0x03, 0xF9, 0x83, 0x2E, 0x5A, 0x9A, 0x0F, 0xC7, 0xA7, 0xF7, 0x4F, 0xA3, 0x0E,
+        0x41, 0x0A, 0x89, 0x93, 0x3B, 0xC1, 0x24, 0xC9, 0x5B, 0x31, 0x65, 0x73, 0x71, 0xE3, 0xBD,
+        0x6E, 0x53, 0x78, 0xB6, 0xE8, 0x86, 0x6A, 0x47, 0xD3, 0xF1, 0x41, 0xED, 0x57, 0xEE, 0xF1,
+        0xFF, 0x5A, 0xAA, 0x64, 0xDD, 0xB7, 0xD9, 0x9A, 0xE3, 0x66, 0x3A, 0x54, 0x48, 0xE9, 0xA5,
+        0xE3, 0xF1, 0xEC, 0x06, 0xBC, 0xD3, 0x24, 0x58, 0x92, 0xD6, 0xC5, 0x6C, 0xC0, 0xE7, 0x6B,
+        0x0E, 0x7B, 0x36, 0x03, 0x06, 0xEE, 0x9C, 0x12, 0x7A, 0xAB, 0xD1, 0x43, 0xAD, 0xAA, 0x38,
+        0x60, 0xC4, 0x2E, 0xF1, 0xAF, 0x59, 0x2C, 0x96, 0x86, 0x42, 0xC3, 0x9C, 0x1D, 0xB8, 0xD3,
+        0xD0, 0xA0, 0x30, 0xE8, 0x82, 0xEF, 0xC5, 0xC3, 0x03, 0x74, 0xF5, 0xCA, 0x68, 0xF1, 0x9B,
+        0x32, 0x5F, 0x88, 0x20, 0x1E, 0xD3, 0x33, 0x8F, 0xD8, 0xE0, 0xA0, 0xF6, 0x1D, 0x49, 0x13,
+        0xC0, 0xB3, 0xCB, 0x68, 0x5D, 0x75, 0xF9, 0x6D, 0x55, 0xE2, 0x4B, 0x97, 0x69, 0xB0, 0x6E,
+        0xB0, 0x5D, 0x17, 0xEF, 0xBB, 0x84, 0xE3, 0xF5, 0x50, 0xA9, 0x2F, 0xB3, 0x98, 0x12, 0x72,
+        0x14, 0xD1, 0x67, 0xE7, 0x66, 0x39, 0x84, 0x4D, 0x02, 0xE6, 0xCF, 0x83, 0x84, 0x00, 0x7C,
+        0xD1, 0xB2, 0x1B, 0x54, 0x0D, 0x0F, 0xC3, 0x7F, 0xC8, 0x0D, 0x84, 0x88, 0xB3, 0xC4, 0xF2,
+        0x8F, 0x7F, 0x77, 0x49, 0x8D, 0x9E, 0x60, 0x69, 0x0B, 0x73, 0x3E, 0xE4, 0x7E, 0x0D, 0x54,
+        0x28, 0xAF, 0x4F, 0xCE, 0xFD, 0x47, 0x39, 0x33, 0x3D, 0xA1, 0xE9, 0x4E, 0x3B, 0x7B, 0x94,
+        0xF6, 0xF1, 0xE5, 0xEF, 0x8D, 0x90, 0xA7, 0xBE, 0x0F, 0x91, 0xC3, 0x79, 0xE9, 0xDF, 0x15,
+        0x61, 0xA3, 0x46, 0x84, 0xF0, 0xF1, 0xB2, 0xEA, 0x6B, 0x15, 0x98, 0xCE, 0xB1, 0xB6, 0x0C,
+        0x24, 0x9C, 0xC8, 0x8C, 0x4A, 0x11, 0x5A, 0xFC, 0x06, 0xB1, 0xA3, 0xA7, 0x77, 0x9E, 0x2F,
+        0x6A, 0xB7, 0x09, 0x30, 0x81, 0x7C, 0x7A, 0xC3, 0x36, 0x72, 0x84, 0x23, 0xF1, 0x3F, 0x91,
+        0x91, 0x72, 0xA1, 0xD6, 0xF8, 0x2D, 0x2B, 0x09, 0xA2, 0x64, 0x61, 0x0F, 0x3D, 0xE0, 0x4F,
+        0x05, 0x66, 0x02, 0xBC, 0x1C, 0x37, 0x7B, 0x0E, 0xB1, 0x41, 0x93, 0xA1, 0xC6, 0xE6, 0xFC,
+        0x6D, 0xA8, 0xE7, 0x09, 0xC0, 0xB3, 0xB4, 0x56, 0xF9, 0x96, 0x33, 0x75, 0x36, 0xDF, 0x40,
+        0xEE, 0x5A, 0xC8, 0x57, 0x1F, 0xE7, 0xC2, 0xBC, 0x7A, 0x19,
+    ];
+    let mut ss1 = [0u8; CRYPTO_BYTES];
+
+    crypto_kem_dec(&mut ss1, &ct, &sk)?;
+
+    let ss_ref = [
+        0x10, 0xAF, 0x7B, 0xA1, 0xD6, 0x25, 0xB1, 0x61, 0x72, 0xC5, 0xB8, 0x0E, 0x2E, 0xE5, 0x3A,
+        0xE9, 0xB7, 0xF3, 0xED, 0xBE, 0x2E, 0x22, 0x6F, 0x11, 0x3E, 0xDE, 0x5A, 0x0E, 0xA8, 0xD1,
+        0xA9, 0x78,
+    ];
+    assert_eq!(ss_ref, ss1);
+
+    Ok(())
+}
+
+#[test]
+fn test_poly_sq_tobytes() {
+    let mut a = Poly::new();
+    // unlike the previous testcases, we use some non-constant value here
+    a.coeffs = [
+        0x724F, 0xACE5, 0x13B9, 0x28EB, 0xC443, 0x5CD0, 0xA106, 0x0EFB, 0x5F30, 0x049A, 0xDB4E,
+        0x7E7F, 0xD304, 0xD47A, 0x7283, 0x83F7, 0x4D41, 0xFCC5, 0x0C45, 0xB8A5, 0x29E8, 0xC643,
+        0xFBFF, 0x6DE9, 0xF36B, 0x8B26, 0xDAF0, 0x1F45, 0x2F7A, 0xA1EA, 0x7CD6, 0x23FC, 0xDBAC,
+        0x5FF4, 0x0156, 0xFFD3, 0x319F, 0x3359, 0x483B, 0xA6B5, 0x6817, 0x5C4F, 0xBD04, 0x2039,
+        0x110D, 0xD5A4, 0xDA70, 0x61A0, 0x9A89, 0xCEFD, 0xA969, 0x3CF6, 0xDA71, 0x8F3F, 0x98C8,
+        0x2595, 0x4E2F, 0xED55, 0x81ED, 0xC15B, 0x55F3, 0x6CDD, 0xF25A, 0x9D03, 0x398D, 0xE6D9,
+        0xC369, 0xEB17, 0x398C, 0xBEF7, 0xDF20, 0x1D97, 0x7EE9, 0xE33B, 0x06CC, 0xD19C, 0x01E0,
+        0x777C, 0x594B, 0xFE43, 0xA73F, 0x0057, 0x80E6, 0x0697, 0x7E06, 0xDA10, 0xD45A, 0x6343,
+        0x109C, 0x381E, 0x0030, 0x33E4, 0x6BEE, 0x5EF8, 0xE763, 0xB228, 0xD953, 0x9A18, 0x24D8,
+        0x30FE, 0xC812, 0x9102, 0x4B55, 0xA2C2, 0xC21C, 0xEFF4, 0xB57E, 0xFD83, 0x9881, 0x0592,
+        0x9C00, 0xCA37, 0x4E20, 0x8011, 0xA40D, 0xC8F0, 0x70FB, 0x3DC4, 0x8B96, 0x717D, 0xB3F0,
+        0x5160, 0x9EDB, 0x95AF, 0x72C5, 0x3413, 0x05B4, 0x68DE, 0x6234, 0xE2AA, 0x51C8, 0x0D7F,
+        0x2036, 0xBC53, 0xE416, 0x767A, 0x5074, 0x0F14, 0xF47C, 0x08C8, 0xAD2B, 0x00BB, 0xBAD3,
+        0xA53B, 0x997F, 0x63D9, 0xE092, 0xB0EA, 0x1E3B, 0x444C, 0x769C, 0xFCB0, 0xDBB4, 0x014C,
+        0x1CD9, 0xA5B4, 0x8276, 0x5686, 0x2428, 0x8DA7, 0x89B1, 0x6C6C, 0x8286, 0xBA08, 0x7043,
+        0x4796, 0x3F5D, 0xE9DF, 0x70DB, 0x6234, 0xB85A, 0xCB64, 0x190C, 0x0A04, 0x0164, 0x845A,
+        0x82F9, 0xB31C, 0x4F05, 0x7BD1, 0x15AE, 0x1B2B, 0x2E75, 0x5DF6, 0x7BB5, 0x8D90, 0x373F,
+        0xF079, 0x1827, 0x7E69, 0x20E3, 0xB86C, 0xE138, 0x1B37, 0x3F12, 0x879A, 0xCAC9, 0x8F65,
+        0x72E5, 0x25A6, 0x91BD, 0x285F, 0xE6C7, 0xE6A7, 0xA9ED, 0xC8FC, 0x51F6, 0xFB73, 0x820F,
+        0x1979, 0xAC9A, 0x2F79, 0x0EDB, 0x4C28, 0x58A2, 0x9E68, 0xA49F, 0x5C35, 0xE788, 0x9CE7,
+        0xB488, 0xE872, 0x1606, 0x1897, 0x6564, 0x467C, 0xAE19, 0xC1A7, 0x9CDA, 0xA18E, 0x1835,
+        0x6D64, 0x491E, 0x66D4, 0xD030, 0x3A17, 0x1E18, 0x454E, 0x8626, 0x7780, 0x0565, 0x7FE0,
+        0x48F5, 0xF109, 0xAC0A, 0x7222, 0xB4A5, 0xD3D1, 0xC227, 0x0862, 0xC5DB, 0x8E63, 0x0B67,
+        0xE5E0, 0x3A3C, 0x8509, 0xBBDF, 0xA33B, 0x4BD4, 0x5E17, 0xD667, 0x33CB, 0x3820, 0x3B40,
+        0x0680, 0x4A3B, 0x60A4, 0x39BB, 0x386B, 0x8BB2, 0xC6A8, 0x08A9, 0x8389, 0xBC2D, 0xF8DE,
+        0x6148, 0x2460, 0xFF39, 0xA8B6, 0x420A, 0x0252, 0x763C, 0xD814, 0x5FAB, 0xA1C5, 0x14AD,
+        0x3288, 0x6B66, 0x9BE5, 0x71C8, 0xBB46, 0xBFB2, 0xD4B6, 0x5A7D, 0x8BBC, 0x2DC1, 0x4143,
+        0xBA60, 0x9C5B, 0x365C, 0x9533, 0x3B7A, 0x002B, 0x64AA, 0x8802, 0x9882, 0x17B2, 0x5803,
+        0x32F2, 0xCDFD, 0xFBD2, 0xA970, 0xBAEC, 0x5359, 0xC207, 0xD5AC, 0x7CFD, 0x9F5E, 0x0553,
+        0x7B19, 0x0E75, 0xFC46, 0x523C, 0xD91E, 0x6FB2, 0xBD5D, 0xACE3, 0xC806, 0xD562, 0xD0B0,
+        0xC19E, 0xF5BB, 0xC6B4, 0xF7E0, 0x930B, 0xD97C, 0x286F, 0xC379, 0x59FD, 0xC61E, 0x9CA2,
+        0x2546, 0x1EDE, 0xEAB1, 0x8F00, 0x20A0, 0x0A8E, 0xC259, 0xF19A, 0x1C93, 0x55FE, 0x93BA,
+        0xC477, 0xEA5E, 0x09AF, 0xFD6F, 0x7D9A, 0xFC37, 0x6BDA, 0xA4AD, 0x5D7A, 0xD881, 0xF307,
+        0xA9C0, 0x0D92, 0x2EC7, 0x7FC6, 0x390A, 0x0697, 0x8124, 0x6E32, 0x90EC, 0x9545, 0x8E9E,
+        0xB65A, 0x76A8, 0x127B, 0x91EC, 0x9C38, 0x3E5A, 0x0B14, 0xBA00, 0x0829, 0xF770, 0x3D21,
+        0x4C5D, 0xFC5E, 0xB29F, 0xC557, 0x4734, 0x3125, 0x9D98, 0x8B7E, 0xB393, 0x7C68, 0x1EE7,
+        0xBAA6, 0x760F, 0x2CEF, 0xF676, 0x7A1D, 0x0FF6, 0xF6F6, 0x803C, 0xBA75, 0x5609, 0xC003,
+        0xBAAA, 0x9BA9, 0xCCA2, 0x0AA2, 0x2F9F, 0x9847, 0xA3BD, 0xAF46, 0xBFF9, 0x69AE, 0x107D,
+        0x9C06, 0xFDDD, 0x22A5, 0xFAD6, 0x5B62, 0x3361, 0xC2F1, 0x8E52, 0x69EE, 0x8044, 0x8EDB,
+        0x1F39, 0xACC6, 0xEB90, 0x909F, 0x767E, 0xC305, 0x4028, 0x1510, 0x8E35, 0x9E07, 0x8F95,
+        0xF0C7, 0x80A0, 0x701A, 0x81F4, 0x156E, 0x84D3, 0x5A3E, 0xFFFF, 0x99D8, 0xC5B0, 0x2F93,
+        0xE85C, 0x46D9, 0xFA15, 0x38E2, 0x6620, 0x17D9, 0x1C74, 0x1876, 0x2016, 0x691E, 0x4DC7,
+        0xA5F3, 0x6946, 0xD76F, 0xB8A4, 0x2538, 0x5BBF, 0xF2DD, 0xA3F3, 0x8223, 0x9CD6, 0xDAB0,
+        0x3AB8, 0x91BE, 0x9FA0, 0xD27C, 0x5B7D, 0xB7F7, 0xB3E8, 0x67BF, 0x27F6, 0xB03E, 0xF1A0,
+        0x763B, 0xB776, 0x03C5, 0x743C, 0x5EA9, 0xCE81, 0xA933, 0xEA64, 0x85EC, 0x8D46, 0xBAB7,
+        0xD97E, 0x7131, 0xFF8D, 0x2AE1, 0x33BC, 0xF033, 0x4EBF, 0x92F1, 0xB992, 0xFADE, 0xFA1B,
+        0x0FEA, 0x4513, 0xD290, 0xE50F, 0x4FC3, 0x3ACC, 0xE1C4, 0xB386, 0xBD94, 0x6C75, 0x4AD0,
+        0x3744, 0x0F6A, 0x17CB, 0x9BCE, 0x0C8F, 0x4913, 0x0D95, 0x10FB, 0x9499, 0xF29F, 0xD135,
+        0x39EA, 0x7D06, 0x0C37, 0xDC75, 0x9EAF, 0xD522, 0xF7DD, 0x10CA, 0x1349, 0x19F0, 0x019D,
+        0x905F, 0xF762, 0x7151, 0xB47F, 0xEF56, 0xE9B2, 0x11F9, 0xBFB2, 0xFFED, 0xF71B, 0x09F2,
+        0xA821, 0x1DBF, 0xE272, 0x98B0, 0xD9AE, 0x31AA, 0x091A, 0x71B9, 0xDE17, 0x9630, 0x3DB3,
+        0xA03A, 0xB6FE, 0x2955, 0x7705, 0xD37B, 0x270C, 0x786C, 0x0700, 0x1DCE, 0x0A99, 0xD3D2,
+        0x7F2B, 0x076E, 0x9DEA, 0xA3C2, 0x2738, 0x06D8, 0x575D, 0x07BC, 0x54A4, 0x3061, 0xB580,
+        0xA4F9, 0x46F4, 0xB7C2, 0x16EA, 0x4565, 0xF34C, 0x2ECB, 0x8109, 0x3186, 0x3CE3, 0xB31B,
+        0x29DC, 0x8FF8, 0xB97F, 0x663D, 0xE804, 0x8EE8, 0x0F2A, 0x7E05, 0x99D1, 0x910A, 0x5080,
+        0xCFB5, 0xB7A9, 0xF2A0, 0xDD06, 0x4B36, 0x9840, 0x74A5, 0x0CDD, 0x7916, 0x3CD7, 0x65F6,
+        0x47FF, 0x45A8, 0x9D02, 0x67E4, 0x9125, 0x4DAA, 0xD8A0, 0xFB7E, 0x321E, 0xF284, 0xC787,
+        0xB208, 0x8A85, 0x4A95, 0x4537, 0x399E, 0x2790, 0x1FFB, 0xC5A8, 0xD9A6, 0xBCE1, 0xC3B3,
+        0x7991, 0x1EA7, 0xCB14, 0x8365, 0x5841, 0x3112, 0x21ED, 0xB702, 0xFDF8, 0x1931, 0x71F2,
+        0x2C86, 0xA2C2, 0xA83E, 0x57C4, 0x8347, 0x79CA, 0xAB46, 0xD20F, 0xD41A, 0x05E2, 0x4F0A,
+        0x3B03, 0xC5C8, 0x40AA, 0x79C5, 0x9A1F, 0xC901, 0xE55C, 0xBEC6, 0x05A1, 0xC9CE, 0x5339,
+        0xA8BD, 0x6633, 0x3EE0, 0x2AC0, 0xBE0F, 0x4B68, 0x3FB9, 0x3E6A, 0x8CB0, 0x2E36, 0xC382,
+        0x13E4, 0x843C, 0x7E5D, 0x37A1, 0xAD78, 0x9073, 0x47C5, 0xBDAE, 0x57E9, 0xA68F, 0xEB5D,
+        0x18F1, 0x5702, 0x9482, 0x92E6, 0x3973, 0xC733, 0xED15, 0x5252,
+    ];
+    let mut r = [0u8; 1138];
+    poly_sq_tobytes(&mut r, &a);
+    let expected_r = [
+        0x4F, 0xB2, 0x9C, 0xE5, 0xCE, 0x75, 0x34, 0x44, 0xA0, 0xB9, 0x41, 0xD8, 0x77, 0x30, 0x5F,
+        0x93, 0x38, 0xED, 0x3F, 0x4F, 0x30, 0xF5, 0xE8, 0xA0, 0xBC, 0x1F, 0x41, 0xAD, 0x98, 0x17,
+        0xB1, 0x52, 0x8C, 0x9E, 0x86, 0xCC, 0xFF, 0x4E, 0x6F, 0x6B, 0xD3, 0x64, 0xC1, 0xEB, 0xA2,
+        0xAF, 0xF7, 0xD4, 0x83, 0x35, 0xE7, 0x1F, 0xAC, 0x9B, 0xFE, 0x5B, 0x85, 0xE9, 0xFF, 0x19,
+        0xB3, 0xE6, 0x0E, 0xAA, 0x35, 0x17, 0xE8, 0x89, 0x13, 0xF4, 0x1C, 0xD0, 0x10, 0x49, 0x2B,
+        0x9C, 0x06, 0x0D, 0x89, 0xBA, 0xDF, 0xA5, 0x25, 0x7B, 0x1E, 0xA7, 0x7F, 0x1E, 0x32, 0xAE,
+        0x2C, 0x2F, 0xAE, 0xAA, 0xB5, 0x87, 0xAD, 0x30, 0x5F, 0xBB, 0x99, 0x96, 0x1C, 0xE8, 0x8D,
+        0x39, 0xDB, 0xA4, 0x8D, 0x8B, 0xC5, 0x98, 0xEF, 0x3D, 0xC8, 0xBF, 0xEC, 0xE9, 0x7E, 0x67,
+        0x30, 0x1B, 0xCE, 0x08, 0x1E, 0xF8, 0xEE, 0x52, 0x1E, 0xF2, 0x3F, 0xE7, 0x0A, 0x98, 0x83,
+        0x4B, 0x63, 0xE0, 0x21, 0xB4, 0x16, 0x1D, 0x1A, 0x9C, 0xD0, 0x03, 0xC3, 0x00, 0xF2, 0xE9,
+        0xBE, 0xF0, 0xFD, 0xD8, 0x41, 0x91, 0x53, 0x19, 0x43, 0x63, 0x13, 0x7F, 0x28, 0x81, 0x04,
+        0x62, 0xD5, 0x12, 0x16, 0x1C, 0x82, 0xFE, 0xF9, 0xD5, 0xC1, 0x1E, 0x88, 0x25, 0x0B, 0x00,
+        0xBF, 0x51, 0x20, 0x2E, 0x02, 0x34, 0x10, 0x78, 0xB4, 0x0F, 0x89, 0xBB, 0xE5, 0xEA, 0x8B,
+        0xF0, 0x13, 0x2C, 0x6E, 0xFB, 0xD7, 0x5A, 0x2C, 0x27, 0x28, 0x6D, 0xF1, 0x46, 0x34, 0x42,
+        0x55, 0x20, 0xC7, 0xBF, 0x66, 0x03, 0xA6, 0xB8, 0x05, 0xD1, 0xB3, 0x74, 0x90, 0xE2, 0xF1,
+        0x51, 0x64, 0xB4, 0xD2, 0x76, 0xC1, 0xB4, 0xDE, 0x29, 0x7F, 0x39, 0x7B, 0x48, 0x02, 0x75,
+        0xB8, 0xE3, 0x99, 0x08, 0xA7, 0x85, 0xE5, 0xB4, 0x9B, 0x29, 0x64, 0x73, 0xDA, 0x62, 0x27,
+        0x0C, 0x2D, 0x0A, 0x39, 0x6D, 0xB1, 0x89, 0x8D, 0x19, 0x0A, 0x04, 0x3D, 0x04, 0x2D, 0x4F,
+        0xD7, 0xFF, 0x4E, 0xDB, 0x90, 0x46, 0x68, 0x61, 0xB2, 0xC5, 0x90, 0x09, 0x14, 0x59, 0xD0,
+        0x22, 0xF9, 0x82, 0x63, 0x16, 0xBC, 0xE8, 0xED, 0x5A, 0x57, 0x76, 0x9D, 0xB3, 0xEF, 0xB5,
+        0x1B, 0xB2, 0xFD, 0xDC, 0x3C, 0x78, 0x82, 0xD3, 0xFC, 0x38, 0x60, 0xC3, 0x38, 0xE1, 0x66,
+        0x4B, 0x7C, 0xCD, 0x93, 0xAC, 0xCA, 0x5E, 0xB9, 0x34, 0x2D, 0xBD, 0xF1, 0x0B, 0x1D, 0x9B,
+        0x53, 0xD3, 0x9E, 0xF8, 0x91, 0x7D, 0x9C, 0xDB, 0x0F, 0x22, 0x2F, 0x6B, 0xB2, 0xBC, 0xB7,
+        0xED, 0x50, 0x98, 0x28, 0x46, 0xF3, 0x9F, 0xA4, 0x86, 0x23, 0x9E, 0x73, 0x8E, 0x48, 0xE5,
+        0x90, 0x81, 0xBD, 0xC4, 0x64, 0x85, 0xCF, 0x64, 0xB8, 0xD3, 0xA0, 0xCD, 0x1D, 0x43, 0x0D,
+        0x26, 0x6B, 0x1E, 0x89, 0xDA, 0xC0, 0xC0, 0x0B, 0x8D, 0xE1, 0x9D, 0x8A, 0x89, 0x01, 0xBC,
+        0x65, 0x05, 0xFC, 0xD7, 0xA3, 0x84, 0xA8, 0xC0, 0x44, 0x64, 0x29, 0x8D, 0x9E, 0x27, 0x42,
+        0x0C, 0x6D, 0x97, 0x31, 0x77, 0xB6, 0xC0, 0x0B, 0x8F, 0x4E, 0x28, 0xDF, 0x7B, 0x67, 0x50,
+        0xAF, 0x0B, 0x7F, 0x66, 0x97, 0x27, 0x08, 0x06, 0xDA, 0x80, 0x66, 0x47, 0x91, 0x82, 0xDD,
+        0xBC, 0x86, 0x65, 0x17, 0xAA, 0x49, 0x45, 0x89, 0xA3, 0x85, 0x7B, 0x63, 0xA4, 0x00, 0x46,
+        0x72, 0xBE, 0x2D, 0x52, 0x10, 0x52, 0x82, 0xC7, 0x52, 0xE0, 0xD5, 0x5F, 0x1C, 0x5A, 0x29,
+        0xA2, 0x34, 0x5B, 0xE5, 0x1B, 0x39, 0x1A, 0x6D, 0xD9, 0x6F, 0x4B, 0xFB, 0x34, 0xEF, 0x0A,
+        0x6E, 0x43, 0x01, 0x4C, 0x6F, 0x71, 0x2E, 0x3B, 0x53, 0xF5, 0xF6, 0x0A, 0x50, 0x25, 0x02,
+        0x48, 0x10, 0xCB, 0xDE, 0x01, 0x2C, 0x2F, 0xFB, 0x9B, 0xF4, 0x86, 0x4B, 0xEC, 0x3A, 0x6B,
+        0x1E, 0x08, 0xD6, 0xDA, 0xCF, 0xBD, 0xFE, 0x54, 0xC9, 0xD8, 0x75, 0xCE, 0x88, 0xF3, 0x48,
+        0x8F, 0x2C, 0xFB, 0xBA, 0xFA, 0x38, 0x33, 0x40, 0x62, 0x15, 0x16, 0x7A, 0x86, 0xDD, 0x4A,
+        0x6B, 0xC0, 0xEF, 0xC2, 0xE4, 0xCB, 0x6F, 0x28, 0x6F, 0xF4, 0x67, 0x0F, 0x23, 0xCA, 0x8D,
+        0x8A, 0xB7, 0x8F, 0x55, 0x00, 0x0F, 0x14, 0x38, 0xAA, 0x2C, 0xA1, 0x19, 0x27, 0xB9, 0x7F,
+        0xD5, 0x9D, 0x77, 0xC4, 0x4B, 0xBD, 0xA6, 0xB7, 0xAE, 0xD9, 0x6F, 0xB8, 0xF6, 0x6A, 0x25,
+        0x7A, 0x3D, 0x10, 0x1F, 0x4C, 0xE0, 0x24, 0xD9, 0x8E, 0x9D, 0xF1, 0x57, 0xC8, 0x97, 0x86,
+        0x24, 0xC8, 0x38, 0x76, 0x58, 0x54, 0x3D, 0x9D, 0x96, 0x45, 0xB5, 0x7B, 0x92, 0x3D, 0xE2,
+        0x70, 0x2D, 0x4F, 0xB1, 0x00, 0x74, 0x0A, 0x82, 0xBB, 0x21, 0xBD, 0x8B, 0x79, 0xF1, 0x4F,
+        0x79, 0x55, 0x68, 0x4E, 0x49, 0xC4, 0xEC, 0x7E, 0x6B, 0x72, 0xA2, 0xF1, 0x73, 0x6F, 0xAA,
+        0x1F, 0xEC, 0x3B, 0xB3, 0xB3, 0x1D, 0xDA, 0xFE, 0xD9, 0x5B, 0x1E, 0x50, 0xA7, 0x13, 0xEC,
+        0x00, 0x50, 0xD5, 0xA9, 0x5B, 0x94, 0x89, 0xAA, 0xCF, 0x77, 0x84, 0x7B, 0x87, 0xD1, 0xCB,
+        0xFF, 0xAE, 0xA9, 0x0F, 0x1A, 0xF0, 0xEE, 0x5E, 0x2A, 0xAC, 0xB5, 0xD8, 0x0E, 0x9B, 0xF1,
+        0x42, 0xCA, 0xB9, 0x27, 0x22, 0xB0, 0xED, 0x72, 0xBE, 0x31, 0x83, 0x5C, 0x9F, 0xD0, 0xCF,
+        0x16, 0x0C, 0x14, 0x00, 0x51, 0x6B, 0xDC, 0x81, 0xAF, 0x7C, 0xC7, 0x10, 0x14, 0x68, 0x40,
+        0xFA, 0xE0, 0x56, 0xA7, 0x89, 0x8F, 0xFE, 0xFF, 0xD8, 0x19, 0xB6, 0x4C, 0x3E, 0x2E, 0x94,
+        0x6D, 0x2A, 0xB4, 0x38, 0x06, 0x31, 0xD9, 0x97, 0x8E, 0xDB, 0x61, 0x0B, 0xE0, 0x91, 0x8E,
+        0xDB, 0x7C, 0x31, 0x4A, 0x6F, 0x97, 0x14, 0xE3, 0x94, 0xDF, 0xDD, 0x2D, 0xE7, 0xC7, 0x88,
+        0xB0, 0xE6, 0xB0, 0x1A, 0x57, 0xFB, 0x46, 0xD0, 0xCF, 0x27, 0xFB, 0xF6, 0xFD, 0x45, 0x9F,
+        0xBF, 0xC7, 0xFE, 0xF8, 0x40, 0xD0, 0xB8, 0x63, 0xED, 0x6E, 0xF1, 0xE0, 0xA1, 0xA9, 0x3E,
+        0xD0, 0xCD, 0x24, 0x32, 0xC5, 0x5E, 0x8C, 0xDA, 0xAD, 0xF6, 0xCB, 0x31, 0xB1, 0xF1, 0x87,
+        0x2B, 0xDE, 0x39, 0x03, 0x7F, 0x5D, 0xBC, 0x94, 0xCC, 0xDE, 0x7A, 0x43, 0xAB, 0xBF, 0x89,
+        0x02, 0x29, 0x1F, 0xCA, 0xF0, 0x63, 0xD6, 0xC4, 0xC1, 0x70, 0x52, 0xF6, 0x3A, 0x06, 0xAD,
+        0x88, 0xAE, 0xDA, 0x5B, 0xBE, 0xCE, 0xFB, 0x91, 0x4D, 0xA4, 0xCA, 0xB6, 0x0F, 0x33, 0xE9,
+        0xA7, 0xAC, 0x89, 0xEA, 0xD9, 0xA0, 0xDF, 0xB0, 0x3A, 0xFE, 0xEA, 0x45, 0x6A, 0xF7, 0x55,
+        0x86, 0x49, 0x13, 0x3E, 0x77, 0x86, 0x2F, 0x28, 0x76, 0xA3, 0xE2, 0x1F, 0xB5, 0x7A, 0xB2,
+        0x29, 0x3F, 0xCA, 0xFE, 0xF6, 0xBF, 0x71, 0xE5, 0x53, 0x08, 0xFA, 0xED, 0x72, 0x02, 0x16,
+        0xBB, 0x66, 0xD5, 0xA8, 0x91, 0x72, 0xE3, 0x85, 0x87, 0xB1, 0xB3, 0x5D, 0x07, 0xF8, 0xDB,
+        0xAA, 0x54, 0x70, 0xF7, 0x26, 0xC3, 0x61, 0xC3, 0x00, 0xC7, 0xB9, 0x67, 0x2A, 0xE9, 0xB9,
+        0xF2, 0xDD, 0x8E, 0x7A, 0x17, 0x1E, 0x38, 0x07, 0xDB, 0x74, 0x5D, 0xDE, 0x43, 0x4A, 0xC3,
+        0x20, 0x60, 0xCD, 0x27, 0xF4, 0x46, 0xF8, 0xAA, 0xDB, 0xB2, 0xC2, 0x34, 0x97, 0x5D, 0x42,
+        0x30, 0x8C, 0xE3, 0x7C, 0x63, 0x72, 0x27, 0xFC, 0xF7, 0x97, 0x7B, 0x0C, 0x01, 0x42, 0x77,
+        0x2A, 0xAF, 0xC0, 0x47, 0x67, 0x85, 0x08, 0x08, 0x6B, 0x5F, 0xEA, 0x05, 0x95, 0x06, 0xDD,
+        0x66, 0x01, 0xE1, 0x52, 0xDA, 0xCD, 0x2C, 0xF2, 0x35, 0xB7, 0x2F, 0xFF, 0x07, 0xB5, 0x08,
+        0x74, 0xF2, 0x53, 0x12, 0x55, 0x1B, 0x28, 0xF6, 0xDB, 0x1E, 0x92, 0x50, 0x1E, 0x1E, 0x04,
+        0x59, 0xA8, 0x2A, 0xD5, 0x4D, 0xF1, 0xCC, 0x90, 0x67, 0xFF, 0xA3, 0x16, 0xD3, 0x1C, 0xCE,
+        0x67, 0x47, 0x64, 0x3E, 0xF5, 0x14, 0xAB, 0x6C, 0x04, 0x61, 0x89, 0xD8, 0x1E, 0x04, 0x2E,
+        0x7E, 0x8F, 0xC9, 0xF2, 0xD1, 0x90, 0x09, 0x0B, 0x1F, 0x44, 0x7C, 0x8F, 0x86, 0x72, 0x36,
+        0x5A, 0x0F, 0x52, 0x83, 0x8A, 0x17, 0x85, 0x37, 0xB0, 0x91, 0x8B, 0x2A, 0x28, 0xCE, 0x1F,
+        0x3A, 0x20, 0x71, 0x15, 0x63, 0x1F, 0x5A, 0x9C, 0x53, 0xCE, 0xEC, 0x45, 0x33, 0x06, 0xDC,
+        0x03, 0xAB, 0x07, 0x8F, 0xB6, 0x72, 0xBF, 0x9A, 0x87, 0x65, 0x36, 0x4E, 0x70, 0x90, 0x4F,
+        0x1E, 0xD2, 0xE5, 0x43, 0x2F, 0x5E, 0x9B, 0x83, 0xC5, 0xC7, 0xB5, 0xA7, 0xDF, 0x47, 0xD3,
+        0xB5, 0xE2, 0xB1, 0xC0, 0x15, 0xA4, 0xE6, 0x72, 0x2E, 0xCF, 0x9C, 0x8A, 0x06,
+    ];
+    assert_eq!(r, expected_r);
+}
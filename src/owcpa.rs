@@ -1,26 +1,29 @@
 //! OWCPA (One-Wayness under chosen plaintext attack) implementation of NTRU. Used to build a IND-CCA2 secure KEM.
 
-use crate::api::{CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
-use crate::pack3::{poly_s3_frombytes, poly_s3_tobytes};
-use crate::packq::{
+use subtle::{Choice, ConstantTimeEq};
+
+use super::api::{CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES};
+use super::pack3::{poly_s3_frombytes, poly_s3_tobytes};
+use super::packq::{
     poly_rq_sum_zero_frombytes, poly_rq_sum_zero_tobytes, poly_sq_frombytes, poly_sq_tobytes,
 };
-use crate::params::{
-    NTRU_CIPHERTEXTBYTES, NTRU_LOGQ, NTRU_N, NTRU_OWCPA_MSGBYTES, NTRU_PACK_DEG,
-    NTRU_PACK_TRINARY_BYTES, NTRU_Q, NTRU_SAMPLE_FG_BYTES,
+use super::params::{
+    NTRU_CIPHERTEXTBYTES, NTRU_HPS, NTRU_HRSS, NTRU_LOGQ, NTRU_N, NTRU_OWCPA_MSGBYTES,
+    NTRU_PACK_DEG, NTRU_PACK_TRINARY_BYTES, NTRU_Q, NTRU_SAMPLE_FG_BYTES, NTRU_WEIGHT,
 };
-use crate::poly::Poly;
-use crate::poly::{poly_rq_inv, poly_s3_mul, poly_sq_mul, poly_trinary_zq_to_z3, poly_z3_to_zq};
-use crate::poly_lift::poly_lift;
-use crate::poly_mod::poly_rq_to_s3;
-use crate::poly_rq_mul::poly_rq_mul;
-use crate::poly_s3_inv::poly_s3_inv;
-use crate::sample::sample_fg;
-
-#[cfg(feature = "ntruhps")]
-use crate::params::NTRU_WEIGHT;
-
-pub fn owcpa_check_ciphertext(ciphertext: &[u8]) -> u16 {
+use super::poly::{poly_rq_inv, poly_s3_mul, poly_sq_mul, poly_trinary_zq_to_z3, poly_z3_to_zq};
+use super::poly::{Poly, MODQ};
+use super::poly_lift::poly_lift;
+use super::poly_mod::{poly_mod_q_phi_n, poly_rq_to_s3};
+use super::poly_rq_mul::poly_rq_mul;
+use super::poly_s3_inv::poly_s3_inv;
+use super::poly_ternary::{poly_rq_mul_ternary, poly_s3_mul_ternary};
+use super::sample::sample_fg;
+
+use crate::ctgrind::ct_poison;
+use crate::wipe::wipe;
+
+pub fn owcpa_check_ciphertext(ciphertext: &[u8]) -> Choice {
     /* A ciphertext is log2(q)*(n-1) bits packed into bytes.  */
     /* Check that any unused bits of the final byte are zero. */
 
@@ -29,10 +32,87 @@ pub fn owcpa_check_ciphertext(ciphertext: &[u8]) -> u16 {
 
     /* We have 0 <= t < 256 */
     /* Return 0 on success (t=0), 1 on failure */
-    1 & ((!t).wrapping_add(1) >> 15)
+    !t.ct_eq(&0)
+}
+
+pub fn owcpa_check_pk(pk: &[u8; CRYPTO_PUBLICKEYBYTES]) -> Choice {
+    /* A public key is packed like a ciphertext. */
+    /* Check that any unused bits of the final byte are zero. */
+    /* The coefficients need no check: log2(q)-bit fields always decode to [0, q). */
+    owcpa_check_ciphertext(pk)
+}
+
+/// Return 0 if `a` = 1 mod (q, Phi_n), 1 otherwise. Assumes a[n-1] = 0.
+fn poly_sq_check_one(a: &Poly) -> Choice {
+    let mut t: u16 = MODQ(a.coeffs[0]) ^ 1;
+    for i in 1..NTRU_N {
+        t |= MODQ(a.coeffs[i]);
+    }
+    !t.ct_eq(&0)
 }
 
-pub fn owcpa_check_r(r: &Poly) -> u32 {
+/// Return 0 if `a` is invertible mod (q, Phi_n), 1 otherwise
+fn poly_sq_check_invertible(a: &Poly) -> Choice {
+    let ainv = &mut Poly::new();
+    let one = &mut Poly::new();
+
+    /* Check that a * a^-1 = 1 mod (q, Phi_n) */
+    poly_rq_inv(ainv, a);
+    poly_sq_mul(one, a, ainv);
+    poly_sq_check_one(one)
+}
+
+pub fn owcpa_check_pk_invertible(pk: &[u8; CRYPTO_PUBLICKEYBYTES]) -> Choice {
+    let h = &mut Poly::new();
+    poly_rq_sum_zero_frombytes(h, pk);
+    poly_sq_check_invertible(h)
+}
+
+pub fn owcpa_check_sk_fp(sk: &[u8; CRYPTO_SECRETKEYBYTES]) -> Choice {
+    let f = &mut Poly::new();
+    let fp = &mut Poly::new();
+    let one = &mut Poly::new();
+
+    let mut sk_msgbytes = [0u8; NTRU_OWCPA_MSGBYTES];
+    sk_msgbytes.copy_from_slice(&sk[0..NTRU_OWCPA_MSGBYTES]);
+    poly_s3_frombytes(f, sk_msgbytes);
+    sk_msgbytes.copy_from_slice(
+        &sk[NTRU_PACK_TRINARY_BYTES..NTRU_PACK_TRINARY_BYTES + NTRU_OWCPA_MSGBYTES],
+    );
+    poly_s3_frombytes(fp, sk_msgbytes);
+    wipe(&mut sk_msgbytes);
+
+    /* Check that f * fp = 1 mod (3, Phi_n). Coefficients are in {0,1,2}. */
+    poly_s3_mul(one, f, fp);
+    let mut t: u16 = one.coeffs[0] ^ 1;
+    for i in 1..NTRU_N {
+        t |= one.coeffs[i];
+    }
+    !t.ct_eq(&0)
+}
+
+pub fn owcpa_check_sk_hq(sk: &[u8; CRYPTO_SECRETKEYBYTES]) -> Choice {
+    let hq = &mut Poly::new();
+    poly_sq_frombytes(hq, &sk[2 * NTRU_PACK_TRINARY_BYTES..]);
+    poly_sq_check_invertible(hq)
+}
+
+pub fn owcpa_check_sk_pk(
+    sk: &[u8; CRYPTO_SECRETKEYBYTES],
+    pk: &[u8; CRYPTO_PUBLICKEYBYTES],
+) -> Choice {
+    let hq = &mut Poly::new();
+    let h = &mut Poly::new();
+    let one = &mut Poly::new();
+
+    /* Check that hq * h = 1 mod (q, Phi_n) */
+    poly_sq_frombytes(hq, &sk[2 * NTRU_PACK_TRINARY_BYTES..]);
+    poly_rq_sum_zero_frombytes(h, pk);
+    poly_sq_mul(one, hq, h);
+    poly_sq_check_one(one)
+}
+
+pub fn owcpa_check_r(r: &Poly) -> Choice {
     /* A valid r has coefficients in {0,1,q-1} and has r[N-1] = 0 */
     /* Note: We may assume that 0 <= r[i] <= q-1 for all i        */
     let mut t: u32 = 0;
@@ -45,11 +125,10 @@ pub fn owcpa_check_r(r: &Poly) -> u32 {
 
     /* We have 0 <= t < 2^16. */
     /* Return 0 on success (t=0), 1 on failure */
-    1 & ((!t).wrapping_add(1) >> 31)
+    !t.ct_eq(&0)
 }
 
-#[cfg(feature = "ntruhps")]
-pub fn owcpa_check_m(m: &Poly) -> u32 {
+pub fn owcpa_check_m(m: &Poly) -> Choice {
     /* Check that m is in message space, i.e.                  */
     /*  (1)  |{i : m[i] = 1}| = |{i : m[i] = 2}|, and          */
     /*  (2)  |{i : m[i] != 0}| = NTRU_WEIGHT.                  */
@@ -68,13 +147,13 @@ pub fn owcpa_check_m(m: &Poly) -> u32 {
 
     /* We have 0 <= t < 2^16. */
     /* Return 0 on success (t=0), 1 on failure */
-    1 & ((!t).wrapping_add(1) >> 31)
+    !t.ct_eq(&0)
 }
 
 pub fn owcpa_keypair(
     pk: &mut [u8; CRYPTO_PUBLICKEYBYTES],
     sk: &mut [u8; CRYPTO_SECRETKEYBYTES],
-    seed: [u8; NTRU_SAMPLE_FG_BYTES],
+    mut seed: [u8; NTRU_SAMPLE_FG_BYTES],
 ) {
     let mut x3 = Poly::new();
 
@@ -88,6 +167,9 @@ pub fn owcpa_keypair(
     // let invh = &mut x3;
     // let h = &mut x3;
     sample_fg(f, g, seed);
+    wipe(&mut seed);
+    ct_poison(f);
+    ct_poison(g);
     poly_s3_inv(&mut x3, f);
     poly_s3_tobytes(
         <&mut [u8; NTRU_PACK_TRINARY_BYTES]>::try_from(&mut sk[..NTRU_PACK_TRINARY_BYTES]).unwrap(),
@@ -105,8 +187,7 @@ pub fn owcpa_keypair(
     poly_z3_to_zq(f);
     poly_z3_to_zq(g);
 
-    #[cfg(feature = "ntruhrss701")]
-    {
+    if NTRU_HRSS {
         /* g = 3*(x-1)*g */
         // C implementation loops from [NTRU_N - 1;0)
         // .rev() reverses the iterator AFTER the range has been evaluated
@@ -114,10 +195,7 @@ pub fn owcpa_keypair(
             g.coeffs[i] = (3u16).wrapping_mul(g.coeffs[i - 1].wrapping_sub(g.coeffs[i]));
         }
         g.coeffs[0] = 0u16.wrapping_sub(3 * g.coeffs[0]);
-    }
-
-    #[cfg(feature = "ntruhps")]
-    {
+    } else {
         /* g = 3*g */
         for i in 0..NTRU_N {
             g.coeffs[i] = g.coeffs[i].wrapping_mul(3);
@@ -134,11 +212,47 @@ pub fn owcpa_keypair(
     sk_pack_trinary_bytes.copy_from_slice(&sk[2 * NTRU_PACK_TRINARY_BYTES..]);
     poly_sq_tobytes(&mut sk_pack_trinary_bytes, &x3);
     sk[2 * NTRU_PACK_TRINARY_BYTES..].copy_from_slice(&sk_pack_trinary_bytes);
+    wipe(&mut sk_pack_trinary_bytes);
     poly_rq_mul(tmp, invgf, g);
     poly_rq_mul(&mut x3, tmp, g);
     poly_rq_sum_zero_tobytes(pk, &mut x3);
 }
 
+/// The inverse of `NTRU_N` modulo 2^16 (`NTRU_N` is odd), computed by Newton iteration
+const NTRU_N_INV: u16 = {
+    let n = NTRU_N as u16;
+    let mut inv = n; // n*n = 1 mod 8 for odd n
+    let mut i = 0;
+    while i < 4 {
+        inv = inv.wrapping_mul(2u16.wrapping_sub(n.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+};
+
+pub fn owcpa_sk_to_pk(pk: &mut [u8; CRYPTO_PUBLICKEYBYTES], sk: &[u8; CRYPTO_SECRETKEYBYTES]) {
+    let hq = &mut Poly::new();
+    let h = &mut Poly::new();
+
+    /* h = hq^-1 mod (q, Phi_n) */
+    poly_sq_frombytes(hq, &sk[2 * NTRU_PACK_TRINARY_BYTES..]);
+    poly_rq_inv(h, hq);
+    poly_mod_q_phi_n(h);
+
+    /* The public key is the representative h + c*Phi_n with h(1) = 0 mod q, */
+    /* i.e. c = -h(1)/n mod q. We compute modulo 2^16, a multiple of q.      */
+    let mut sum = 0u16;
+    for coeff in h.coeffs.iter() {
+        sum = sum.wrapping_add(*coeff);
+    }
+    let c = 0u16.wrapping_sub(sum).wrapping_mul(NTRU_N_INV);
+    for coeff in h.coeffs.iter_mut() {
+        *coeff = coeff.wrapping_add(c);
+    }
+
+    poly_rq_sum_zero_tobytes(pk, h);
+}
+
 pub fn owcpa_enc(
     c: &mut [u8; CRYPTO_CIPHERTEXTBYTES],
     r: &Poly,
@@ -153,7 +267,7 @@ pub fn owcpa_enc(
 
     poly_rq_sum_zero_frombytes(x1, pk);
 
-    poly_rq_mul(x2, r, x1);
+    poly_rq_mul_ternary(x2, r, x1);
 
     poly_lift(x1, m);
     for i in 0..NTRU_N {
@@ -162,24 +276,30 @@ pub fn owcpa_enc(
     poly_rq_sum_zero_tobytes(c, x2);
 }
 
-pub fn owcpa_dec(rm: &mut [u8], ciphertext: &[u8], secretkey: &[u8; CRYPTO_SECRETKEYBYTES]) -> u16 {
+/// Decrypt `ciphertext` to the message `m` and the unchecked `r` (see `owcpa_dec`)
+fn owcpa_dec_polys(
+    r: &mut Poly,
+    m: &mut Poly,
+    ciphertext: &[u8],
+    secretkey: &[u8; CRYPTO_SECRETKEYBYTES],
+) {
     let x1 = &mut Poly::new();
     let x2 = &mut Poly::new();
     let x3 = &mut Poly::new();
-    let x4 = &mut Poly::new();
 
     //   poly *c = &x1, *f = &x2, *cf = &x3;
-    //   poly *mf = &x2, *finv3 = &x3, *m = &x4;
-    //   poly *liftm = &x2, *invh = &x3, *r = &x4;
+    //   poly *mf = &x2, *finv3 = &x3;
+    //   poly *liftm = &x2, *invh = &x3;
     //   poly *b = &x1;
 
     poly_rq_sum_zero_frombytes(x1, ciphertext);
     let mut sk_msgbytes = [0u8; NTRU_OWCPA_MSGBYTES];
     sk_msgbytes.copy_from_slice(&secretkey[0..NTRU_OWCPA_MSGBYTES]);
     poly_s3_frombytes(x2, sk_msgbytes);
-    poly_z3_to_zq(x2);
+    wipe(&mut sk_msgbytes);
+    ct_poison(x2);
 
-    poly_rq_mul(x3, x1, x2);
+    poly_rq_mul_ternary(x3, x2, x1);
     poly_rq_to_s3(x2, x3);
 
     let mut sk_trinary_bytes = [0u8; NTRU_OWCPA_MSGBYTES];
@@ -187,33 +307,18 @@ pub fn owcpa_dec(rm: &mut [u8], ciphertext: &[u8], secretkey: &[u8; CRYPTO_SECRE
         &secretkey[NTRU_PACK_TRINARY_BYTES..NTRU_PACK_TRINARY_BYTES + NTRU_OWCPA_MSGBYTES],
     );
     poly_s3_frombytes(x3, sk_trinary_bytes);
-    poly_s3_mul(x4, x2, x3);
-    poly_s3_tobytes(
-        <&mut [u8; NTRU_PACK_TRINARY_BYTES]>::try_from(&mut rm[NTRU_PACK_TRINARY_BYTES..]).unwrap(),
-        x4,
-    );
-
-    /* Check that the unused bits of the last byte of the ciphertext are zero */
-    let mut fail = owcpa_check_ciphertext(ciphertext);
-
-    /* For the IND-CCA2 KEM we must ensure that c = Enc(h, (r,m)).             */
-    /* We can avoid re-computing r*h + Lift(m) as long as we check that        */
-    /* r (defined as b/h mod (q, Phi_n)) and m are in the message space.       */
-    /* (m can take any value in S3 in NTRU_HRSS) */
-    #[cfg(feature = "ntruhps")]
-    {
-        fail |= owcpa_check_m(x4) as u16;
-    }
+    wipe(&mut sk_trinary_bytes);
+    poly_s3_mul_ternary(m, x2, x3);
 
     /* b = c - Lift(m) mod (q, x^n - 1) */
-    poly_lift(x2, x4);
+    poly_lift(x2, m);
     for i in 0..NTRU_N {
         x1.coeffs[i] = x1.coeffs[i].wrapping_sub(x2.coeffs[i]);
     }
 
     /* r = b / h mod (q, Phi_n) */
     poly_sq_frombytes(x3, &secretkey[2 * NTRU_PACK_TRINARY_BYTES..]);
-    poly_sq_mul(x4, x1, x3);
+    poly_sq_mul(r, x1, x3);
 
     /* NOTE: Our definition of r as b/h mod (q, Phi_n) follows Figure 4 of     */
     /*   [Sch18] https://eprint.iacr.org/2018/1174/20181203:032458.            */
@@ -221,18 +326,156 @@ pub fn owcpa_dec(rm: &mut [u8], ciphertext: &[u8], secretkey: &[u8; CRYPTO_SECRE
     /*   [SXY17] https://eprint.iacr.org/2017/1005/20180516:055500             */
     /* where r gets a final reduction modulo p.                                */
     /* We need this change to use Proposition 1 of [Sch18].                    */
+}
+
+/// Pack `r` (with coefficients in {0,1,q-1}) and `m` into `rm`
+fn owcpa_pack_rm(rm: &mut [u8], r: &mut Poly, m: &Poly) {
+    poly_trinary_zq_to_z3(r);
+    poly_s3_tobytes(
+        <&mut [u8; NTRU_PACK_TRINARY_BYTES]>::try_from(&mut rm[..NTRU_PACK_TRINARY_BYTES]).unwrap(),
+        r,
+    );
+    poly_s3_tobytes(
+        <&mut [u8; NTRU_PACK_TRINARY_BYTES]>::try_from(&mut rm[NTRU_PACK_TRINARY_BYTES..]).unwrap(),
+        m,
+    );
+}
+
+pub fn owcpa_dec(
+    rm: &mut [u8],
+    ciphertext: &[u8],
+    secretkey: &[u8; CRYPTO_SECRETKEYBYTES],
+) -> Choice {
+    let r = &mut Poly::new();
+    let m = &mut Poly::new();
+
+    owcpa_dec_polys(r, m, ciphertext, secretkey);
+
+    /* Check that the unused bits of the last byte of the ciphertext are zero */
+    let mut fail = owcpa_check_ciphertext(ciphertext);
+
+    /* For the IND-CCA2 KEM we must ensure that c = Enc(h, (r,m)).             */
+    /* We can avoid re-computing r*h + Lift(m) as long as we check that        */
+    /* r (defined as b/h mod (q, Phi_n)) and m are in the message space.       */
+    /* (m can take any value in S3 in NTRU_HRSS) */
+    if NTRU_HPS {
+        fail |= owcpa_check_m(m);
+    }
 
     /* Proposition 1 of [Sch18] shows that re-encryption with (r,m) yields c.  */
     /* if and only if fail==0 after the following call to owcpa_check_r        */
     /* The procedure given in Fig. 8 of [Sch18] can be skipped because we have */
     /* c(1) = 0 due to the use of poly_Rq_sum_zero_{to,from}bytes.             */
-    fail |= owcpa_check_r(x4) as u16;
+    fail |= owcpa_check_r(r);
 
-    poly_trinary_zq_to_z3(x4);
-    poly_s3_tobytes(
-        <&mut [u8; NTRU_PACK_TRINARY_BYTES]>::try_from(&mut rm[..NTRU_PACK_TRINARY_BYTES]).unwrap(),
-        x4,
-    );
+    owcpa_pack_rm(rm, r, m);
 
     fail
 }
+
+/// `0xffffffff` if `x` is zero, `0` otherwise
+#[cfg(feature = "fault-hardened")]
+fn ct_is_zero(x: u32) -> u32 {
+    ((x | x.wrapping_neg()) >> 31).wrapping_sub(1)
+}
+
+/// Independent implementation of `owcpa_check_ciphertext`, `owcpa_check_m` and
+/// `owcpa_check_r` for the `fault-hardened` feature.
+/// Returns `0` on success and `0xffffffff` on failure.
+#[cfg(feature = "fault-hardened")]
+fn owcpa_check_alt(ciphertext: &[u8], r: &Poly, m: &Poly) -> u32 {
+    /* The used bits are the low bits of the last byte, the others must be zero */
+    let used = 7 & (NTRU_LOGQ * NTRU_PACK_DEG);
+    let mut t: u32 = if used == 0 {
+        0
+    } else {
+        ciphertext[NTRU_CIPHERTEXTBYTES - 1] as u32 >> used
+    };
+
+    /* r has coefficients in {0,1,q-1} and r[n-1] = 0 */
+    for i in 0..NTRU_N - 1 {
+        let c = MODQ(r.coeffs[i]) as u32;
+        t |= !(ct_is_zero(c) | ct_is_zero(c ^ 1) | ct_is_zero(c ^ (NTRU_Q as u32 - 1)));
+    }
+    t |= r.coeffs[NTRU_N - 1] as u32;
+
+    /* m has exactly NTRU_WEIGHT/2 coefficients equal to 1 and to 2 */
+    if NTRU_HPS {
+        let mut ones: u32 = 0;
+        let mut twos: u32 = 0;
+        for i in 0..NTRU_N {
+            let c = m.coeffs[i] as u32;
+            ones += ct_is_zero(c ^ 1) & 1;
+            twos += ct_is_zero(c ^ 2) & 1;
+        }
+        t |= (ones ^ (NTRU_WEIGHT as u32 / 2)) | (twos ^ (NTRU_WEIGHT as u32 / 2));
+    }
+
+    !ct_is_zero(t)
+}
+
+/// Like `owcpa_dec`, but the validity of the ciphertext is checked twice with independent
+/// implementations. Returns the failure flag of `owcpa_dec` (0 or 1) and the one of
+/// the second implementation (0 or 0xffffffff).
+#[cfg(feature = "fault-hardened")]
+pub fn owcpa_dec_hardened(
+    rm: &mut [u8],
+    ciphertext: &[u8],
+    secretkey: &[u8; CRYPTO_SECRETKEYBYTES],
+) -> (Choice, u32) {
+    let r = &mut Poly::new();
+    let m = &mut Poly::new();
+
+    owcpa_dec_polys(r, m, ciphertext, secretkey);
+
+    let mut fail = owcpa_check_ciphertext(ciphertext);
+    if NTRU_HPS {
+        fail |= owcpa_check_m(m);
+    }
+    fail |= owcpa_check_r(r);
+    let fail_alt = owcpa_check_alt(ciphertext, r, m);
+
+    owcpa_pack_rm(rm, r, m);
+
+    (fail, fail_alt)
+}
+
+#[cfg(all(test, feature = "fault-hardened"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_alt() {
+        let ct = [0u8; NTRU_CIPHERTEXTBYTES];
+        let r = &mut Poly::new();
+        let m = &mut Poly::new();
+        /* NTRU_WEIGHT only restricts m for HPS */
+        let weight = if NTRU_HPS { NTRU_WEIGHT } else { 200 };
+        for i in 0..weight / 2 {
+            m.coeffs[2 * i] = 1;
+            m.coeffs[2 * i + 1] = 2;
+            r.coeffs[3 * i] = NTRU_Q as u16 - 1;
+            r.coeffs[3 * i + 1] = 1;
+        }
+        let expected = |r: &Poly, m: &Poly| {
+            let mut fail = owcpa_check_r(r);
+            if NTRU_HPS {
+                fail |= owcpa_check_m(m);
+            }
+            0u32.wrapping_sub(fail.unwrap_u8() as u32)
+        };
+        assert_eq!(owcpa_check_alt(&ct, r, m), 0);
+
+        for c in [2, 3, NTRU_Q as u16 - 2, NTRU_Q as u16, NTRU_Q as u16 + 1] {
+            r.coeffs[5] = c;
+            assert_eq!(owcpa_check_alt(&ct, r, m), expected(r, m), "r = {}", c);
+        }
+        r.coeffs[5] = 0;
+        r.coeffs[NTRU_N - 1] = 1;
+        assert_eq!(owcpa_check_alt(&ct, r, m), 0xffffffff);
+        r.coeffs[NTRU_N - 1] = 0;
+
+        m.coeffs[NTRU_N - 2] = 1;
+        assert_eq!(owcpa_check_alt(&ct, r, m), expected(r, m));
+    }
+}
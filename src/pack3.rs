@@ -1,6 +1,7 @@
-use crate::params::{NTRU_N, NTRU_OWCPA_MSGBYTES, NTRU_PACK_DEG, NTRU_PACK_TRINARY_BYTES};
-use crate::poly::Poly;
-use crate::poly_mod::poly_mod_3_phi_n;
+use super::params::{NTRU_N, NTRU_OWCPA_MSGBYTES, NTRU_PACK_DEG, NTRU_PACK_TRINARY_BYTES};
+use super::poly::Poly;
+use super::poly_mod::poly_mod_3_phi_n;
+use crate::wipe::wipe;
 
 pub fn poly_s3_tobytes(msg: &mut [u8; NTRU_PACK_TRINARY_BYTES], a: &Poly) {
     let mut c: u8;
@@ -26,7 +27,7 @@ pub fn poly_s3_tobytes(msg: &mut [u8; NTRU_PACK_TRINARY_BYTES], a: &Poly) {
     }
 }
 
-pub fn poly_s3_frombytes(mut r: &mut Poly, msg: [u8; NTRU_OWCPA_MSGBYTES]) {
+pub fn poly_s3_frombytes(r: &mut Poly, mut msg: [u8; NTRU_OWCPA_MSGBYTES]) {
     for (i, c) in msg.iter().enumerate().take(NTRU_PACK_DEG / 5) {
         r.coeffs[5 * i] = *c as u16;
         r.coeffs[5 * i + 1] = (*c as u16).wrapping_mul(171) >> 9; // this is division by 3
@@ -46,4 +47,5 @@ pub fn poly_s3_frombytes(mut r: &mut Poly, msg: [u8; NTRU_OWCPA_MSGBYTES]) {
     }
     r.coeffs[NTRU_N - 1] = 0;
     poly_mod_3_phi_n(r);
+    wipe(&mut msg);
 }
@@ -1,9 +1,12 @@
-use std::fmt;
+use core::fmt;
 
-use crate::params::{NTRU_LOGQ, NTRU_N, NTRU_Q};
-use crate::poly_mod::{poly_mod_3_phi_n, poly_mod_q_phi_n};
-use crate::poly_r2_inv::poly_r2_inv;
-use crate::poly_rq_mul::poly_rq_mul;
+use super::params::{NTRU_LOGQ, NTRU_N, NTRU_Q};
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+use super::poly_avx2::{poly_s3_mul_avx2, poly_sq_mul_avx2};
+use super::poly_mod::poly_mod_q_phi_n;
+use super::poly_r2_inv::poly_r2_inv;
+use super::poly_rq_mul::poly_rq_mul;
+use super::poly_s3_bitsliced::poly_s3_mul_bitsliced;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Poly {
@@ -31,6 +34,23 @@ impl Default for Poly {
 
 impl Eq for Poly {}
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Poly {
+    fn zeroize(&mut self) {
+        self.coeffs.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Poly {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Poly {}
+
 impl fmt::Display for Poly {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Poly[")?;
@@ -63,17 +83,33 @@ pub fn poly_trinary_zq_to_z3(r: &mut Poly) {
 }
 
 pub fn poly_sq_mul(r: &mut Poly, a: &Poly, b: &Poly) {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if crate::backend::use_avx2() {
+        // SAFETY: `use_avx2` only returns true if the CPU supports AVX2
+        unsafe { poly_sq_mul_avx2(r, a, b) };
+        return;
+    }
     poly_rq_mul(r, a, b);
     poly_mod_q_phi_n(r);
 }
 
+/// `r = a·b mod (3, Φn)` for coefficients in {0,1,2}, bitsliced unless AVX2 is used
 pub fn poly_s3_mul(r: &mut Poly, a: &Poly, b: &Poly) {
-    poly_rq_mul(r, a, b);
-    poly_mod_3_phi_n(r);
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if crate::backend::use_avx2() {
+        // SAFETY: `use_avx2` only returns true if the CPU supports AVX2
+        unsafe { poly_s3_mul_avx2(r, a, b) };
+        return;
+    }
+    poly_s3_mul_bitsliced(r, a, b);
 }
 
+const _: () = assert!(
+    NTRU_Q > 256 && NTRU_Q < 65536,
+    "poly_R2_inv_to_Rq_inv in poly.c assumes 256 < q < 65536"
+);
+
 pub fn poly_r2_inv_to_rq_inv(r: &mut Poly, ai: Poly, a: &Poly) {
-    assert!(NTRU_Q > 256 && NTRU_Q < 65536, "poly_R2_inv_to_Rq_inv in poly.c assumes 256 < q < 65536");
     let mut b = Poly::new();
     let mut c = Poly::new();
     let mut s = Poly::new();
@@ -103,6 +139,11 @@ pub fn poly_r2_inv_to_rq_inv(r: &mut Poly, ai: Poly, a: &Poly) {
     poly_rq_mul(r, &c, &s); // r = s*c
 }
 
+/// `r = a^-1 mod (q, Φn)`: the inverse mod 2 lifted by `poly_r2_inv_to_rq_inv`
+///
+/// Divsteps need the coefficients to form a field, which Z/q is not for q = 2^k, so the
+/// inversion mod 2 is the only part using them. The Newton iterations of the lift cost eight
+/// multiplications in Rq, several times as much as the inversion mod 2.
 pub fn poly_rq_inv(r: &mut Poly, a: &Poly) {
     let mut ai2 = Poly::new();
     poly_r2_inv(&mut ai2, a);
@@ -0,0 +1,181 @@
+//! Multiplication in Rq, Sq and S3 with AVX2 instructions (`avx2` feature, x86_64 only)
+//!
+//! `poly_rq_mul_avx2` computes the cyclic convolution with 16 coefficients per 256-bit
+//! register. Since the lanes compute mod 2^16 just like `u16`, the results are identical
+//! to the portable implementation. The reductions mod Φn are vectorized the same way.
+//! All operations and memory accesses are independent of the coefficients.
+//!
+//! The functions must only be called if the CPU supports AVX2 (see `crate::backend`).
+
+use core::arch::x86_64::*;
+
+use super::params::NTRU_N;
+use super::poly::Poly;
+use super::poly_mod::mod3;
+use crate::wipe::wipe;
+
+/// Number of registers holding the `NTRU_N` coefficients of a product
+const BLOCKS: usize = NTRU_N.div_ceil(16);
+/// `NTRU_N` padded to a multiple of 16
+const PAD: usize = 16 * BLOCKS;
+/// Number of registers accumulated at once
+const GROUP: usize = 4;
+/// Coefficients reduced by full registers in `poly_mod_*_phi_n_avx2`, `r[NTRU_N - 1]` is in the tail
+const FULL: usize = NTRU_N / 16 * 16;
+
+/// `r = a·b mod (x^n - 1)`
+#[target_feature(enable = "avx2")]
+pub fn poly_rq_mul_avx2(r: &mut Poly, a: &Poly, b: &Poly) {
+    /* bb[t] = b[t mod n], so coefficient k of the product is the sum of a[i]·bb[k - i + n] */
+    let mut bb = [0u16; NTRU_N + PAD];
+    for (t, x) in bb.iter_mut().enumerate() {
+        *x = b.coeffs[t % NTRU_N];
+    }
+
+    let mut out = [0u16; PAD];
+    for group in (0..BLOCKS).step_by(GROUP) {
+        let width = GROUP.min(BLOCKS - group);
+        let mut acc = [_mm256_setzero_si256(); GROUP];
+        for (i, &ai) in a.coeffs.iter().enumerate() {
+            let ai = _mm256_set1_epi16(ai as i16);
+            for (u, acc_u) in acc.iter_mut().enumerate().take(width) {
+                let k = 16 * (group + u);
+                // SAFETY: k + n - i + 16 <= PAD + n, i.e. the 16 lanes lie within `bb`
+                let bv = unsafe { _mm256_loadu_si256(bb.as_ptr().add(k + NTRU_N - i).cast()) };
+                *acc_u = _mm256_add_epi16(*acc_u, _mm256_mullo_epi16(ai, bv));
+            }
+        }
+        for (u, acc_u) in acc.iter().enumerate().take(width) {
+            // SAFETY: 16 * (group + u) + 16 <= PAD, i.e. the 16 lanes lie within `out`
+            unsafe { _mm256_storeu_si256(out.as_mut_ptr().add(16 * (group + u)).cast(), *acc_u) };
+        }
+    }
+    r.coeffs.copy_from_slice(&out[..NTRU_N]);
+
+    wipe(&mut bb);
+    wipe(&mut out);
+}
+
+/// Vectorized `poly_mod_q_phi_n`
+#[target_feature(enable = "avx2")]
+fn poly_mod_q_phi_n_avx2(r: &mut Poly) {
+    let last = r.coeffs[NTRU_N - 1];
+    let lastv = _mm256_set1_epi16(last as i16);
+    for chunk in r.coeffs[..FULL].chunks_exact_mut(16) {
+        // SAFETY: the chunk has 16 coefficients
+        unsafe {
+            let x = _mm256_loadu_si256(chunk.as_ptr().cast());
+            _mm256_storeu_si256(chunk.as_mut_ptr().cast(), _mm256_sub_epi16(x, lastv));
+        }
+    }
+    for c in r.coeffs[FULL..].iter_mut() {
+        *c = c.wrapping_sub(last);
+    }
+}
+
+/// `mod3` of every lane
+#[target_feature(enable = "avx2")]
+fn mod3_avx2(a: __m256i) -> __m256i {
+    let m8 = _mm256_set1_epi16(0xff);
+    let m4 = _mm256_set1_epi16(0xf);
+    let m2 = _mm256_set1_epi16(0x3);
+    let mut r = _mm256_add_epi16(_mm256_srli_epi16::<8>(a), _mm256_and_si256(a, m8));
+    r = _mm256_add_epi16(_mm256_srli_epi16::<4>(r), _mm256_and_si256(r, m4));
+    r = _mm256_add_epi16(_mm256_srli_epi16::<2>(r), _mm256_and_si256(r, m2));
+    r = _mm256_add_epi16(_mm256_srli_epi16::<2>(r), _mm256_and_si256(r, m2));
+
+    let t = _mm256_sub_epi16(r, m2);
+    let c = _mm256_srai_epi16::<15>(t);
+    _mm256_xor_si256(_mm256_and_si256(c, r), _mm256_andnot_si256(c, t))
+}
+
+/// Vectorized `poly_mod_3_phi_n`
+#[target_feature(enable = "avx2")]
+fn poly_mod_3_phi_n_avx2(r: &mut Poly) {
+    let last2 = r.coeffs[NTRU_N - 1].wrapping_mul(2);
+    let last2v = _mm256_set1_epi16(last2 as i16);
+    for chunk in r.coeffs[..FULL].chunks_exact_mut(16) {
+        // SAFETY: the chunk has 16 coefficients
+        unsafe {
+            let x = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let x = mod3_avx2(_mm256_add_epi16(x, last2v));
+            _mm256_storeu_si256(chunk.as_mut_ptr().cast(), x);
+        }
+    }
+    for c in r.coeffs[FULL..].iter_mut() {
+        *c = mod3(c.wrapping_add(last2));
+    }
+}
+
+/// `r = a·b mod (q, Φn)`
+#[target_feature(enable = "avx2")]
+pub fn poly_sq_mul_avx2(r: &mut Poly, a: &Poly, b: &Poly) {
+    poly_rq_mul_avx2(r, a, b);
+    poly_mod_q_phi_n_avx2(r);
+}
+
+/// `r = a·b mod (3, Φn)`
+#[target_feature(enable = "avx2")]
+pub fn poly_s3_mul_avx2(r: &mut Poly, a: &Poly, b: &Poly) {
+    poly_rq_mul_avx2(r, a, b);
+    poly_mod_3_phi_n_avx2(r);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::poly_mod::{poly_mod_3_phi_n, poly_mod_q_phi_n};
+    use super::super::poly_rq_mul::poly_rq_mul_schoolbook;
+    use super::*;
+    use crate::rng::{AesState, RNGState};
+
+    fn random_poly(rng: &mut AesState, modulus: u16) -> Poly {
+        let mut bytes = [0u8; 2 * NTRU_N];
+        rng.randombytes(&mut bytes).unwrap();
+        let mut p = Poly::new();
+        for (c, b) in p.coeffs.iter_mut().zip(bytes.chunks_exact(2)) {
+            *c = u16::from_le_bytes([b[0], b[1]]).wrapping_rem(modulus);
+        }
+        p
+    }
+
+    #[test]
+    fn test_poly_avx2_matches_portable() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        let mut rng = AesState::new();
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        for modulus in [u16::MAX, 3] {
+            for _ in 0..4 {
+                let a = random_poly(&mut rng, modulus);
+                let b = random_poly(&mut rng, modulus);
+                poly_rq_mul_schoolbook(&mut r_ref, &a, &b);
+                // SAFETY: the CPU supports AVX2
+                unsafe { poly_rq_mul_avx2(&mut r, &a, &b) };
+                assert_eq!(r, r_ref);
+
+                poly_mod_q_phi_n(&mut r_ref);
+                // SAFETY: the CPU supports AVX2
+                unsafe { poly_sq_mul_avx2(&mut r, &a, &b) };
+                assert_eq!(r, r_ref);
+
+                if modulus == 3 {
+                    poly_rq_mul_schoolbook(&mut r_ref, &a, &b);
+                    poly_mod_3_phi_n(&mut r_ref);
+                    // SAFETY: the CPU supports AVX2
+                    unsafe { poly_s3_mul_avx2(&mut r, &a, &b) };
+                    assert_eq!(r, r_ref);
+                }
+            }
+        }
+
+        /* extreme coefficients */
+        let a = Poly::build(u16::MAX);
+        let b = Poly::build(u16::MAX);
+        poly_rq_mul_schoolbook(&mut r_ref, &a, &b);
+        // SAFETY: the CPU supports AVX2
+        unsafe { poly_rq_mul_avx2(&mut r, &a, &b) };
+        assert_eq!(r, r_ref);
+    }
+}
@@ -0,0 +1,236 @@
+//! Constant-time divsteps (Bernstein–Yang safegcd) on packed polynomials
+//!
+//! `poly_r2_inv` and `poly_s3_inv` run `2·(n-1) - 1` divsteps on `f = Φn` and the reversed
+//! input `g`. With `P = [[x, 0], [s, 1]]`, or `P = [[0, x], [1, s]]` if `f` and `g` are
+//! swapped, a divstep maps `(f, g)` to `P·(f, g)/x`, where `s = -f(0)·g(0)`. The product `U`
+//! of all `P` yields the result `v` of the reference implementation as `U[0][1]/x`, so the
+//! results are identical.
+//!
+//! `divsteps` runs them one by one on polynomials packed 64 coefficients per word (see
+//! `Packed`), and only on the words which can still affect the result: a divstep only looks
+//! at the constant coefficients of `f` and `g`, so after `t` divsteps, `f` and `g` are only
+//! needed mod x^(STEPS-t), and the entries of column 1 of `U` have degree <= t + 1.
+//! All branches, loop bounds and indices only depend on the number of divsteps, never on
+//! the coefficients.
+
+use super::params::NTRU_N;
+use super::poly_s3_bitsliced::Trits;
+use crate::wipe::wipe;
+
+/// Number of divsteps of the inversions
+pub const STEPS: usize = 2 * (NTRU_N - 1) - 1;
+/// Words holding the polynomials of degree < `NTRU_N`
+pub const WORDS: usize = words(NTRU_N);
+/// Bits of the last word belonging to coefficients of degree < `NTRU_N`
+const TOP_MASK: u64 = (1 << (NTRU_N % 64)) - 1;
+
+/// Number of words holding `coeffs` coefficients
+const fn words(coeffs: usize) -> usize {
+    coeffs.div_ceil(64)
+}
+
+/// Types `wipe` can overwrite
+#[cfg(feature = "zeroize")]
+pub trait Wipe: zeroize::Zeroize {}
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> Wipe for T {}
+/// Types `wipe` can overwrite
+#[cfg(not(feature = "zeroize"))]
+pub trait Wipe {}
+#[cfg(not(feature = "zeroize"))]
+impl<T> Wipe for T {}
+
+/// 64 packed coefficients of a polynomial over F2 or F3
+pub trait Packed: Copy + Default + Wipe {
+    /// Coefficient-wise `self + b`
+    fn add(self, b: Self) -> Self;
+    /// Coefficient-wise `self·b`
+    fn mul(self, b: Self) -> Self;
+    /// Coefficient-wise `-self`
+    fn neg(self) -> Self;
+    /// 64 copies of coefficient 0 of `self`
+    fn splat(self) -> Self;
+    /// Bit i is set if coefficient i is not zero
+    fn nonzero(self) -> u64;
+    /// Shift the coefficients `k` positions up, `0 < k < 64`
+    fn shl(self, k: u32) -> Self;
+    /// Shift the coefficients `k` positions down, `0 < k < 64`
+    fn shr(self, k: u32) -> Self;
+    /// Combine the coefficients of `self` and `b`, one of which must be zero at each position
+    fn or(self, b: Self) -> Self;
+    /// Swap `self` and `b` if `swap` is all ones, keep them if it is zero
+    fn cswap(&mut self, b: &mut Self, swap: u64);
+    /// Coefficient i is 1 if bit i of `mask` is set, 0 otherwise
+    fn ones(mask: u64) -> Self;
+}
+
+/// F2: bit i is coefficient i
+impl Packed for u64 {
+    #[inline(always)]
+    fn add(self, b: Self) -> Self {
+        self ^ b
+    }
+    #[inline(always)]
+    fn mul(self, b: Self) -> Self {
+        self & b
+    }
+    #[inline(always)]
+    fn neg(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn splat(self) -> Self {
+        0u64.wrapping_sub(self & 1)
+    }
+    #[inline(always)]
+    fn nonzero(self) -> u64 {
+        self
+    }
+    #[inline(always)]
+    fn shl(self, k: u32) -> Self {
+        self << k
+    }
+    #[inline(always)]
+    fn shr(self, k: u32) -> Self {
+        self >> k
+    }
+    #[inline(always)]
+    fn or(self, b: Self) -> Self {
+        self | b
+    }
+    #[inline(always)]
+    fn cswap(&mut self, b: &mut Self, swap: u64) {
+        let t = swap & (*self ^ *b);
+        *self ^= t;
+        *b ^= t;
+    }
+    #[inline(always)]
+    fn ones(mask: u64) -> Self {
+        mask
+    }
+}
+
+/// F3: bitsliced trits
+impl Packed for Trits {
+    #[inline(always)]
+    fn add(self, b: Self) -> Self {
+        Trits::add(self, b)
+    }
+    #[inline(always)]
+    fn mul(self, b: Self) -> Self {
+        Trits::mul(self, b)
+    }
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Trits::neg(self)
+    }
+    #[inline(always)]
+    fn splat(self) -> Self {
+        Trits::splat(self)
+    }
+    #[inline(always)]
+    fn nonzero(self) -> u64 {
+        self.nonzero
+    }
+    #[inline(always)]
+    fn shl(self, k: u32) -> Self {
+        Trits {
+            nonzero: self.nonzero << k,
+            sign: self.sign << k,
+        }
+    }
+    #[inline(always)]
+    fn shr(self, k: u32) -> Self {
+        Trits {
+            nonzero: self.nonzero >> k,
+            sign: self.sign >> k,
+        }
+    }
+    #[inline(always)]
+    fn or(self, b: Self) -> Self {
+        Trits {
+            nonzero: self.nonzero | b.nonzero,
+            sign: self.sign | b.sign,
+        }
+    }
+    #[inline(always)]
+    fn cswap(&mut self, b: &mut Self, swap: u64) {
+        Trits::cswap(self, b, swap)
+    }
+    #[inline(always)]
+    fn ones(mask: u64) -> Self {
+        Trits {
+            nonzero: mask,
+            sign: 0,
+        }
+    }
+}
+
+fn both_negative_mask(x: i16, y: i16) -> i16 {
+    (x & y) >> 15
+}
+
+/// Run the `STEPS` divsteps of the inversion on `f = Φn` and `g` with `delta = 1`.
+/// Returns the result `v` of the reference implementation (mod x^n) and the constant
+/// coefficient of the final `f`.
+pub fn divsteps<C: Packed>(g: &[C; WORDS]) -> ([C; WORDS], C) {
+    /* one more word, which stays zero, to shift g */
+    let mut f = [C::ones(!0); WORDS + 1];
+    f[WORDS - 1] = C::ones(TOP_MASK);
+    f[WORDS] = C::default();
+    let mut g = {
+        let mut t = [C::default(); WORDS + 1];
+        t[..WORDS].copy_from_slice(g);
+        t
+    };
+
+    /* column 1 of the transition matrix, starting with (0, 1), is needed mod x^(n+1) */
+    let mut u = [C::default(); WORDS + 1];
+    let mut r = [C::default(); WORDS + 1];
+    r[0] = C::ones(1);
+
+    let mut delta: i16 = 1;
+    for t in 0..STEPS {
+        let s = g[0].mul(f[0]).neg().splat();
+        let swap = both_negative_mask(-delta, -((g[0].nonzero() & 1) as i16));
+        delta ^= swap & (delta ^ -delta);
+        delta += 1;
+        let swap = swap as i64 as u64;
+
+        /* f and g are only needed mod x^(STEPS-t) */
+        let w = words(STEPS - t).min(WORDS);
+        for (fw, gw) in f[..w].iter_mut().zip(g[..w].iter_mut()) {
+            fw.cswap(gw, swap);
+            *gw = gw.add(fw.mul(s));
+        }
+        for k in 0..w {
+            g[k] = g[k].shr(1).or(g[k + 1].shl(63));
+        }
+
+        /* u and r have degree <= t + 1 after this divstep */
+        let w = words((t + 2).min(NTRU_N + 1));
+        for (uw, rw) in u[..w].iter_mut().zip(r[..w].iter_mut()) {
+            uw.cswap(rw, swap);
+            *rw = rw.add(uw.mul(s));
+        }
+        /* u times x */
+        for k in (1..w).rev() {
+            u[k] = u[k].shl(1).or(u[k - 1].shr(63));
+        }
+        u[0] = u[0].shl(1);
+    }
+
+    /* v = u/x mod x^n */
+    let mut v = [C::default(); WORDS];
+    for (i, vi) in v.iter_mut().enumerate() {
+        *vi = u[i].shr(1).or(u[i + 1].shl(63));
+    }
+    v[WORDS - 1] = v[WORDS - 1].mul(C::ones(TOP_MASK));
+    let f0 = f[0].splat();
+
+    wipe(&mut f);
+    wipe(&mut g);
+    wipe(&mut u);
+    wipe(&mut r);
+    (v, f0)
+}
@@ -1,23 +1,37 @@
-use crate::params::NTRU_N;
-use crate::poly::{poly_z3_to_zq, Poly};
+use super::params::{NTRU_HPS, NTRU_N};
+use super::poly::{poly_z3_to_zq, Poly};
+use super::poly_mod::poly_mod_3_phi_n;
 
-#[cfg(feature = "ntruhrss")]
-use crate::poly_mod::poly_mod_3_phi_n;
-
-#[cfg(feature = "ntruhps")]
 pub fn poly_lift(r: &mut Poly, a: &Poly) {
+    if NTRU_HPS {
+        poly_lift_hps(r, a)
+    } else {
+        poly_lift_hrss(r, a)
+    }
+}
+
+fn poly_lift_hps(r: &mut Poly, a: &Poly) {
     for i in 0..NTRU_N {
         r.coeffs[i] = a.coeffs[i];
     }
     poly_z3_to_zq(r);
 }
 
-#[cfg(feature = "ntruhrss")]
-pub fn poly_lift(r: &mut Poly, a: &Poly) {
+fn poly_lift_hrss(r: &mut Poly, a: &Poly) {
+    let mut b = Poly::new();
+    poly_s3_div_x_minus_1(&mut b, a);
+
+    /* Switch from {0,1,2} to {0,1,q-1} coefficient representation */
+    poly_z3_to_zq(&mut b);
+
+    poly_mul_x_minus_1(r, &b);
+}
+
+/// Compute `b = a/(x-1) mod (3, Phi_n)` for HRSS, linear in `a`
+pub fn poly_s3_div_x_minus_1(b: &mut Poly, a: &Poly) {
     /* NOTE: Assumes input is in {0,1,2}^N */
-    /*       Produces output in [0,Q-1]^N */
+    /*       Produces output in {0,1,2}^N */
 
-    let mut b = Poly::new();
     let t = (3 - (NTRU_N % 3)) as u16;
     let mut zj = 0u16;
 
@@ -48,12 +62,11 @@ pub fn poly_lift(r: &mut Poly, a: &Poly) {
     }
 
     /* Finish reduction mod Phi by subtracting Phi * b[N-1] */
-    poly_mod_3_phi_n(&mut b);
-
-    /* Switch from {0,1,2} to {0,1,q-1} coefficient representation */
-    poly_z3_to_zq(&mut b);
+    poly_mod_3_phi_n(b);
+}
 
-    /* Multiply by (x-1) */
+/// Multiply `b` by `x-1` in Rq, linear in `b`
+pub fn poly_mul_x_minus_1(r: &mut Poly, b: &Poly) {
     r.coeffs[0] = 0u16.wrapping_sub(b.coeffs[0]);
     for i in 0..(NTRU_N - 1) {
         r.coeffs[i + 1] = b.coeffs[i].wrapping_sub(b.coeffs[i + 1]);
@@ -1,7 +1,7 @@
-use std::num::Wrapping;
+use core::num::Wrapping;
 
-use crate::params::{NTRU_LOGQ, NTRU_N};
-use crate::poly::{Poly, MODQ};
+use super::params::{NTRU_LOGQ, NTRU_N};
+use super::poly::{Poly, MODQ};
 
 pub fn mod3(a: u16) -> u16 {
     let mut r: u16 = (a >> 8) + (a & 0xff); // r mod 255 == a mod 255
@@ -12,7 +12,7 @@ pub fn mod3(a: u16) -> u16 {
     let t: i16 = r as i16 - 3;
     let c: i16 = t >> 15;
 
-    ((c as u16) & r) as u16 ^ (!c & t) as u16
+    ((c as u16) & r) ^ (!c & t) as u16
 }
 
 pub fn poly_mod_q_phi_n(r: &mut Poly) {
@@ -21,7 +21,7 @@ pub fn poly_mod_q_phi_n(r: &mut Poly) {
     }
 }
 
-pub fn poly_mod_3_phi_n(mut r: &mut Poly) {
+pub fn poly_mod_3_phi_n(r: &mut Poly) {
     for i in 0..NTRU_N {
         r.coeffs[i] = mod3(r.coeffs[i] + 2 * r.coeffs[NTRU_N - 1]);
     }
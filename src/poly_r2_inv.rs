@@ -1,11 +1,42 @@
-use crate::params::NTRU_N;
-use crate::poly::Poly;
+//! Inversion in (Z/2)[x]/Φn
+//!
+//! `poly_r2_inv` computes the divsteps of the reference implementation (see `poly_divstep`) on
+//! polynomials packed 64 coefficients per `u64`.
+//! `poly_r2_inv_scalar` is the reference implementation with one coefficient per `u16`.
+
+use super::params::NTRU_N;
+use super::poly::Poly;
+use super::poly_divstep::{divsteps, WORDS};
+use crate::wipe::wipe;
 
 fn both_negative_mask(x: i16, y: i16) -> i16 {
     (x & y) >> 15
 }
 
+/// `r = a^-1 mod (2, Φn)`, the result has coefficients in {0,1} and `r[NTRU_N - 1] = 0`
 pub fn poly_r2_inv(r: &mut Poly, a: &Poly) {
+    /* g = the reversed a mod (2, Φn) */
+    let mut g = [0u64; WORDS];
+    for i in 0..NTRU_N - 1 {
+        let c = ((a.coeffs[i] ^ a.coeffs[NTRU_N - 1]) & 1) as u64;
+        g[(NTRU_N - 2 - i) / 64] |= c << ((NTRU_N - 2 - i) % 64);
+    }
+
+    let (mut v, _) = divsteps(&g);
+
+    /* r = v reversed */
+    for i in 0..NTRU_N - 1 {
+        r.coeffs[i] = ((v[(NTRU_N - 2 - i) / 64] >> ((NTRU_N - 2 - i) % 64)) & 1) as u16;
+    }
+    r.coeffs[NTRU_N - 1] = 0;
+
+    wipe(&mut g);
+    wipe(&mut v);
+}
+
+/// Reference implementation of `poly_r2_inv` with one coefficient per `u16`
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn poly_r2_inv_scalar(r: &mut Poly, a: &Poly) {
     let mut v = Poly::new();
     let mut w = Poly::new();
     let mut f = Poly::build(1);
@@ -58,3 +89,101 @@ pub fn poly_r2_inv(r: &mut Poly, a: &Poly) {
     }
     r.coeffs[NTRU_N - 1] = 0;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::poly::{poly_r2_inv_to_rq_inv, poly_rq_inv, poly_sq_mul, MODQ};
+    use super::*;
+    use crate::rng::{AesState, RNGState};
+
+    #[test]
+    fn test_poly_r2_inv_matches_scalar() {
+        let mut rng = AesState::new();
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        let mut bytes = [0u8; 2 * NTRU_N];
+        let mut one = Poly::new();
+        one.coeffs[0] = 1;
+
+        let mut invertible = 0;
+        for _ in 0..8 {
+            rng.randombytes(&mut bytes).unwrap();
+            let mut a = Poly::new();
+            for (c, b) in a.coeffs.iter_mut().zip(bytes.chunks_exact(2)) {
+                *c = u16::from_le_bytes([b[0], b[1]]);
+            }
+            poly_r2_inv(&mut r, &a);
+            poly_r2_inv_scalar(&mut r_ref, &a);
+            assert_eq!(r, r_ref);
+
+            /* the lift to Rq */
+            let ai = r_ref.clone();
+            poly_r2_inv_to_rq_inv(&mut r_ref, ai, &a);
+            poly_rq_inv(&mut r, &a);
+            assert_eq!(r, r_ref);
+
+            let mut product = Poly::new();
+            poly_sq_mul(&mut product, &a, &r);
+            for c in product.coeffs.iter_mut() {
+                *c = MODQ(*c);
+            }
+            if product
+                .coeffs
+                .iter()
+                .map(|c| c & 1)
+                .eq(one.coeffs.iter().copied())
+            {
+                assert_eq!(product, one);
+                invertible += 1;
+            }
+        }
+        assert!(invertible > 0);
+
+        /* 1 + x is invertible (Φn(-1) = 1 for odd n) */
+        let mut a = Poly::new();
+        a.coeffs[0] = 1;
+        a.coeffs[1] = 1;
+        poly_rq_inv(&mut r, &a);
+        let mut product = Poly::new();
+        poly_sq_mul(&mut product, &a, &r);
+        for c in product.coeffs.iter_mut() {
+            *c = MODQ(*c);
+        }
+        assert_eq!(product, one);
+
+        /* Φn ≡ 0 is not invertible */
+        let a = Poly::build(1);
+        poly_r2_inv(&mut r, &a);
+        poly_r2_inv_scalar(&mut r_ref, &a);
+        assert_eq!(r, r_ref);
+    }
+
+    #[test]
+    fn test_poly_r2_inv_structured_matches_scalar() {
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        let mut inputs = vec![Poly::new()];
+        for k in [0, 1, 63, 64, NTRU_N - 2, NTRU_N - 1] {
+            let mut a = Poly::new();
+            a.coeffs[k] = 1;
+            inputs.push(a);
+        }
+        for period in [2, 3, 64] {
+            let mut a = Poly::new();
+            for (i, c) in a.coeffs.iter_mut().enumerate() {
+                *c = (i % period == 0) as u16;
+            }
+            inputs.push(a);
+        }
+        /* all ones but one coefficient */
+        let mut a = Poly::build(1);
+        a.coeffs[NTRU_N / 2] = 0;
+        inputs.push(a);
+
+        for a in &inputs {
+            poly_r2_inv(&mut r, a);
+            poly_r2_inv_scalar(&mut r_ref, a);
+            assert_eq!(r, r_ref);
+        }
+    }
+}
@@ -1,9 +1,30 @@
-use std::num::Wrapping;
+//! Multiplication in Z_{2^16}[x]/(x^n - 1)
+//!
+//! `poly_rq_mul` splits the operands into four limbs and multiplies them by Toom-Cook-4,
+//! each of the seven products by two levels of Karatsuba and schoolbook multiplication.
+//! The interpolation divides by up to 2^6 in total, so it computes on `u32` (all coefficients
+//! exact mod 2^26) to stay bit-identical to the schoolbook multiplication mod 2^16.
+//! All operations are independent of the coefficients.
 
-use crate::params::NTRU_N;
-use crate::poly::Poly;
+use core::num::Wrapping;
 
-pub fn poly_rq_mul(r: &mut Poly, a: &Poly, b: &Poly) {
+use super::params::NTRU_N;
+use super::poly::Poly;
+use crate::wipe::wipe;
+
+/// `NTRU_N` padded to a multiple of 64
+const L: usize = NTRU_N.div_ceil(64) * 64;
+/// Size of a Toom-Cook-4 limb
+const M: usize = L / 4;
+/// Size of the schoolbook multiplications after two levels of Karatsuba
+const K: usize = M / 4;
+
+const INV3: u32 = 0xAAAA_AAAB; /* 3^-1 mod 2^32 */
+const INV5: u32 = 0xCCCC_CCCD; /* 5^-1 mod 2^32 */
+
+/// Reference implementation: schoolbook multiplication with O(n²) operations
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn poly_rq_mul_schoolbook(r: &mut Poly, a: &Poly, b: &Poly) {
     for k in 0..NTRU_N {
         r.coeffs[k] = 0;
         for i in 1..NTRU_N - k {
@@ -17,3 +38,188 @@ pub fn poly_rq_mul(r: &mut Poly, a: &Poly, b: &Poly) {
         }
     }
 }
+
+pub fn poly_rq_mul(r: &mut Poly, a: &Poly, b: &Poly) {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if crate::backend::use_avx2() {
+        // SAFETY: `use_avx2` only returns true if the CPU supports AVX2
+        unsafe { super::poly_avx2::poly_rq_mul_avx2(r, a, b) };
+        return;
+    }
+    toom4_mul(r, a, b);
+}
+
+/// `r = a·b` for the limbs `a` and `b`, `r[2n-1]` is set to zero
+fn schoolbook(r: &mut [u32], a: &[u32], b: &[u32]) {
+    r.fill(0);
+    for (i, &ai) in a.iter().enumerate() {
+        for (j, &bj) in b.iter().enumerate() {
+            r[i + j] = r[i + j].wrapping_add(ai.wrapping_mul(bj));
+        }
+    }
+}
+
+/// `r = a·b` by Karatsuba down to limbs of `K` coefficients
+fn karatsuba(r: &mut [u32], a: &[u32], b: &[u32]) {
+    let n = a.len();
+    if n <= K {
+        schoolbook(r, a, b);
+        return;
+    }
+    let h = n / 2;
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+
+    let mut sum_a = [0u32; M / 2];
+    let mut sum_b = [0u32; M / 2];
+    let mut mid = [0u32; M];
+    let (sum_a, sum_b, mid) = (&mut sum_a[..h], &mut sum_b[..h], &mut mid[..n]);
+    for i in 0..h {
+        sum_a[i] = a0[i].wrapping_add(a1[i]);
+        sum_b[i] = b0[i].wrapping_add(b1[i]);
+    }
+
+    karatsuba(&mut r[..n], a0, b0);
+    karatsuba(&mut r[n..], a1, b1);
+    karatsuba(mid, sum_a, sum_b);
+
+    /* mid = (a0 + a1)(b0 + b1) - a0·b0 - a1·b1 */
+    for i in 0..n {
+        mid[i] = mid[i].wrapping_sub(r[i]).wrapping_sub(r[n + i]);
+    }
+    for i in 0..n {
+        r[h + i] = r[h + i].wrapping_add(mid[i]);
+    }
+
+    wipe(sum_a);
+    wipe(sum_b);
+    wipe(mid);
+}
+
+/// Evaluate the limbs `a0 + a1·y + a2·y² + a3·y³` of `a` (zero-padded to `L` coefficients)
+/// at `y = t` (`t = None` for ∞)
+fn toom4_eval(e: &mut [u32; M], a: &Poly, t: Option<u32>) {
+    let limb = |j: usize, i: usize| a.coeffs.get(j * M + i).map_or(0, |&c| c as u32);
+    for (i, ei) in e.iter_mut().enumerate() {
+        *ei = match t {
+            /* Horner's method */
+            Some(t) => limb(3, i)
+                .wrapping_mul(t)
+                .wrapping_add(limb(2, i))
+                .wrapping_mul(t)
+                .wrapping_add(limb(1, i))
+                .wrapping_mul(t)
+                .wrapping_add(limb(0, i)),
+            None => limb(3, i),
+        };
+    }
+}
+
+/// `r = a·b mod (x^n - 1)` by Toom-Cook-4 with the points 0, ±1, ±2, 3 and ∞
+fn toom4_mul(r: &mut Poly, a: &Poly, b: &Poly) {
+    const POINTS: [Option<u32>; 7] = [
+        Some(0),
+        Some(1),
+        Some(1u32.wrapping_neg()),
+        Some(2),
+        Some(2u32.wrapping_neg()),
+        Some(3),
+        None,
+    ];
+    let mut w = [[0u32; 2 * M]; 7];
+    let mut ea = [0u32; M];
+    let mut eb = [0u32; M];
+    for (wi, t) in w.iter_mut().zip(POINTS) {
+        toom4_eval(&mut ea, a, t);
+        toom4_eval(&mut eb, b, t);
+        karatsuba(wi, &ea, &eb);
+    }
+
+    /* interpolate the coefficients c0, …, c6 of the product in y */
+    let [w0, w1, wm1, w2, wm2, w3, winf] = &w;
+    r.coeffs.fill(0);
+    for i in 0..2 * M {
+        let c0 = w0[i];
+        let c6 = winf[i];
+
+        let e1 = w1[i].wrapping_add(wm1[i]) >> 1; /* c0 + c2 + c4 + c6 */
+        let o1 = w1[i].wrapping_sub(wm1[i]) >> 1; /* c1 + c3 + c5 */
+        let e2 = w2[i].wrapping_add(wm2[i]) >> 1; /* c0 + 4c2 + 16c4 + 64c6 */
+        let o2 = w2[i].wrapping_sub(wm2[i]) >> 2; /* c1 + 4c3 + 16c5 */
+
+        let s24 = e1.wrapping_sub(c0).wrapping_sub(c6); /* c2 + c4 */
+        let t24 = e2.wrapping_sub(c0).wrapping_sub(c6 << 6) >> 2; /* c2 + 4c4 */
+        let c4 = t24.wrapping_sub(s24).wrapping_mul(INV3);
+        let c2 = s24.wrapping_sub(c4);
+
+        /* c1 + 9c3 + 81c5 */
+        let d = w3[i]
+            .wrapping_sub(c0)
+            .wrapping_sub(c2.wrapping_mul(9))
+            .wrapping_sub(c4.wrapping_mul(81))
+            .wrapping_sub(c6.wrapping_mul(729))
+            .wrapping_mul(INV3);
+        let p = o2.wrapping_sub(o1).wrapping_mul(INV3); /* c3 + 5c5 */
+        let q = d.wrapping_sub(o2).wrapping_mul(INV5); /* c3 + 13c5 */
+        /* c2 and c4 are exact mod 2^29 (shifts of e2 and t24), so c1, c3 and c5 mod 2^26 */
+        let c5 = q.wrapping_sub(p) >> 3;
+        let c3 = p.wrapping_sub(c5.wrapping_mul(5));
+        let c1 = o1.wrapping_sub(c3).wrapping_sub(c5);
+
+        /* add c_j·x^(jM + i) mod x^n - 1, the product has less than 2n coefficients */
+        for (j, cj) in [c0, c1, c2, c3, c4, c5, c6].into_iter().enumerate() {
+            let k = j * M + i;
+            let k = if k >= NTRU_N { k - NTRU_N } else { k };
+            if k < NTRU_N {
+                r.coeffs[k] = r.coeffs[k].wrapping_add(cj as u16);
+            }
+        }
+    }
+
+    wipe(&mut ea);
+    wipe(&mut eb);
+    wipe(&mut w);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::{AesState, RNGState};
+
+    fn random_poly(rng: &mut AesState) -> Poly {
+        let mut bytes = [0u8; 2 * NTRU_N];
+        rng.randombytes(&mut bytes).unwrap();
+        let mut p = Poly::new();
+        for (c, b) in p.coeffs.iter_mut().zip(bytes.chunks_exact(2)) {
+            *c = u16::from_le_bytes([b[0], b[1]]);
+        }
+        p
+    }
+
+    #[test]
+    fn test_poly_rq_mul_matches_schoolbook() {
+        let mut rng = AesState::new();
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        for _ in 0..8 {
+            let a = random_poly(&mut rng);
+            let b = random_poly(&mut rng);
+            poly_rq_mul(&mut r, &a, &b);
+            poly_rq_mul_schoolbook(&mut r_ref, &a, &b);
+            assert_eq!(r, r_ref);
+        }
+
+        /* extreme coefficients */
+        let a = Poly::build(u16::MAX);
+        let mut b = Poly::build(u16::MAX);
+        poly_rq_mul(&mut r, &a, &b);
+        poly_rq_mul_schoolbook(&mut r_ref, &a, &b);
+        assert_eq!(r, r_ref);
+
+        b.coeffs.fill(0);
+        b.coeffs[NTRU_N - 1] = 1;
+        poly_rq_mul(&mut r, &a, &b);
+        poly_rq_mul_schoolbook(&mut r_ref, &a, &b);
+        assert_eq!(r, r_ref);
+    }
+}
@@ -0,0 +1,257 @@
+//! Bitsliced arithmetic in (Z/3)[x]/Φn
+//!
+//! A polynomial mod 3 is packed into `WORDS` pairs of `u64`, 64 coefficients per pair:
+//! bit i of `nonzero` is set if coefficient i is 1 or 2 (= -1), and bit i of `sign` is set
+//! if it is 2. Sums and products of 64 coefficients at once take a handful of bitwise
+//! operations. There are no branches or memory accesses depending on the coefficients,
+//! all shifts and indices only depend on the degree.
+
+use super::params::NTRU_N;
+use super::poly::Poly;
+use crate::wipe::wipe;
+
+/// Number of words per bit plane of a polynomial of degree < `NTRU_N`
+pub const WORDS: usize = NTRU_N.div_ceil(64);
+/// Bits of the last word belonging to coefficients of degree < `NTRU_N`
+const TOP_MASK: u64 = (1 << (NTRU_N % 64)) - 1;
+
+const _: () = assert!(NTRU_N % 64 != 0, "the top word must be partially used");
+
+/// 64 coefficients mod 3
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Trits {
+    /// Bit i is set if coefficient i is not zero
+    pub nonzero: u64,
+    /// Bit i is set if coefficient i is -1, a subset of `nonzero`
+    pub sign: u64,
+}
+
+impl Trits {
+    /// 64 copies of coefficient 0 of `self`
+    #[inline(always)]
+    pub fn splat(self) -> Trits {
+        Trits {
+            nonzero: 0u64.wrapping_sub(self.nonzero & 1),
+            sign: 0u64.wrapping_sub(self.sign & 1),
+        }
+    }
+
+    /// Coefficient-wise `self + b`
+    #[inline(always)]
+    pub fn add(self, b: Trits) -> Trits {
+        let only_a = self.nonzero & !b.nonzero;
+        let only_b = b.nonzero & !self.nonzero;
+        /* 1 + 1 = -1 and -1 + -1 = 1, 1 + -1 = 0 */
+        let same = self.nonzero & b.nonzero & !(self.sign ^ b.sign);
+        Trits {
+            nonzero: only_a | only_b | same,
+            sign: (only_a & self.sign) | (only_b & b.sign) | (same & !self.sign),
+        }
+    }
+
+    /// Coefficient-wise `-self`
+    #[inline(always)]
+    pub fn neg(self) -> Trits {
+        Trits {
+            nonzero: self.nonzero,
+            sign: self.sign ^ self.nonzero,
+        }
+    }
+
+    /// Coefficient-wise `self - b`
+    #[inline(always)]
+    pub fn sub(self, b: Trits) -> Trits {
+        self.add(b.neg())
+    }
+
+    /// Coefficient-wise `self·b`
+    #[inline(always)]
+    pub fn mul(self, b: Trits) -> Trits {
+        let nonzero = self.nonzero & b.nonzero;
+        Trits {
+            nonzero,
+            sign: (self.sign ^ b.sign) & nonzero,
+        }
+    }
+
+    /// Swap `self` and `b` if `swap` is all ones, keep them if it is zero
+    #[inline(always)]
+    pub fn cswap(&mut self, b: &mut Trits, swap: u64) {
+        let t = swap & (self.nonzero ^ b.nonzero);
+        self.nonzero ^= t;
+        b.nonzero ^= t;
+        let t = swap & (self.sign ^ b.sign);
+        self.sign ^= t;
+        b.sign ^= t;
+    }
+
+    /// Shift all coefficients one position up, `carry` is the coefficient shifted in at the bottom
+    #[inline(always)]
+    fn shl1(self, carry: Trits) -> Trits {
+        Trits {
+            nonzero: self.nonzero << 1 | carry.nonzero >> 63,
+            sign: self.sign << 1 | carry.sign >> 63,
+        }
+    }
+
+    /// Keep the coefficients selected by `mask`, zero the other ones
+    #[inline(always)]
+    fn and(self, mask: u64) -> Trits {
+        Trits {
+            nonzero: self.nonzero & mask,
+            sign: self.sign & mask,
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::DefaultIsZeroes for Trits {}
+
+/// Pack `a` with coefficients in {0,1,2} (3 is treated as 0)
+pub fn pack(a: &Poly) -> [Trits; WORDS] {
+    let mut r = [Trits::default(); WORDS];
+    for (i, &c) in a.coeffs.iter().enumerate() {
+        let c = (c & 3) as u64;
+        let lo = c & 1;
+        let hi = c >> 1;
+        r[i / 64].nonzero |= (lo ^ hi) << (i % 64);
+        r[i / 64].sign |= (hi & !lo) << (i % 64);
+    }
+    r
+}
+
+/// Unpack `a` into `r` with coefficients in {0,1,2}
+pub fn unpack(r: &mut Poly, a: &[Trits; WORDS]) {
+    for (i, c) in r.coeffs.iter_mut().enumerate() {
+        let t = a[i / 64];
+        *c = ((t.nonzero >> (i % 64)) & 1) as u16 + ((t.sign >> (i % 64)) & 1) as u16;
+    }
+}
+
+/// `r = a·b mod (3, Φn)`
+pub fn s3_mul(r: &mut [Trits; WORDS], a: &[Trits; WORDS], b: &[Trits; WORDS]) {
+    /* shifted[s] = b·x^s for s < 64, so a[i]·x^i·b is shifted[i % 64] moved by i / 64 words */
+    let mut shifted = [[Trits::default(); WORDS + 1]; 64];
+    shifted[0][..WORDS].copy_from_slice(b);
+    for s in 1..64 {
+        let prev = shifted[s - 1];
+        shifted[s][0] = prev[0].shl1(Trits::default());
+        for j in 1..=WORDS {
+            shifted[s][j] = prev[j].shl1(prev[j - 1]);
+        }
+    }
+
+    let mut product = [Trits::default(); 2 * WORDS + 1];
+    for (w, aw) in a.iter().enumerate() {
+        for (s, bs) in shifted.iter().enumerate() {
+            let c = Trits {
+                nonzero: aw.nonzero >> s,
+                sign: aw.sign >> s,
+            }
+            .splat();
+            for (p, &x) in product[w..w + WORDS + 1].iter_mut().zip(bs.iter()) {
+                *p = p.add(x.mul(c));
+            }
+        }
+    }
+
+    /* x^n = 1: add the coefficients of degree >= n, i.e. product / x^n, */
+    /* to the lower ones                                                 */
+    const Q: usize = NTRU_N / 64;
+    const S: usize = NTRU_N % 64;
+    for (j, rj) in r.iter_mut().enumerate() {
+        let high = Trits {
+            nonzero: product[j + Q].nonzero >> S | product[j + Q + 1].nonzero << (64 - S),
+            sign: product[j + Q].sign >> S | product[j + Q + 1].sign << (64 - S),
+        };
+        *rj = product[j].add(high);
+    }
+    r[WORDS - 1] = r[WORDS - 1].and(TOP_MASK);
+
+    /* Φn = 1 + x + … + x^(n-1): subtract coefficient n - 1 times Φn */
+    let top = Trits {
+        nonzero: r[WORDS - 1].nonzero >> (S - 1),
+        sign: r[WORDS - 1].sign >> (S - 1),
+    }
+    .splat();
+    for rj in r.iter_mut() {
+        *rj = rj.sub(top);
+    }
+    r[WORDS - 1] = r[WORDS - 1].and(TOP_MASK);
+
+    wipe(&mut shifted);
+    wipe(&mut product);
+}
+
+/// `r = a·b mod (3, Φn)` for `a` and `b` with coefficients in {0,1,2}, the result has
+/// coefficients in {0,1,2} and `r[NTRU_N - 1] = 0`
+pub fn poly_s3_mul_bitsliced(r: &mut Poly, a: &Poly, b: &Poly) {
+    let mut pa = pack(a);
+    let mut pb = pack(b);
+    let mut pr = [Trits::default(); WORDS];
+    s3_mul(&mut pr, &pa, &pb);
+    unpack(r, &pr);
+
+    wipe(&mut pa);
+    wipe(&mut pb);
+    wipe(&mut pr);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::poly_mod::poly_mod_3_phi_n;
+    use super::super::poly_rq_mul::poly_rq_mul_schoolbook;
+    use super::*;
+    use crate::rng::{AesState, RNGState};
+
+    fn random_s3(rng: &mut AesState) -> Poly {
+        let mut bytes = [0u8; NTRU_N];
+        rng.randombytes(&mut bytes).unwrap();
+        let mut p = Poly::new();
+        for (c, b) in p.coeffs.iter_mut().zip(bytes.iter()) {
+            *c = (b % 3) as u16;
+        }
+        p
+    }
+
+    #[test]
+    fn test_trits_arithmetic() {
+        /* all 9 pairs of coefficients */
+        let a = pack(&Poly {
+            coeffs: core::array::from_fn(|i| (i % 3) as u16),
+        });
+        let b = pack(&Poly {
+            coeffs: core::array::from_fn(|i| (i / 3 % 3) as u16),
+        });
+        let mut sum = Poly::new();
+        let mut product = Poly::new();
+        unpack(&mut sum, &core::array::from_fn(|j| a[j].add(b[j])));
+        unpack(&mut product, &core::array::from_fn(|j| a[j].mul(b[j])));
+        for i in 0..NTRU_N {
+            let (x, y) = ((i % 3) as u16, (i / 3 % 3) as u16);
+            assert_eq!(sum.coeffs[i], (x + y) % 3);
+            assert_eq!(product.coeffs[i], (x * y) % 3);
+        }
+    }
+
+    #[test]
+    fn test_poly_s3_mul_bitsliced() {
+        let mut rng = AesState::new();
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        for _ in 0..8 {
+            let a = random_s3(&mut rng);
+            let b = random_s3(&mut rng);
+            poly_s3_mul_bitsliced(&mut r, &a, &b);
+            poly_rq_mul_schoolbook(&mut r_ref, &a, &b);
+            poly_mod_3_phi_n(&mut r_ref);
+            assert_eq!(r, r_ref);
+        }
+
+        let a = Poly::build(2);
+        poly_s3_mul_bitsliced(&mut r, &a, &a);
+        poly_rq_mul_schoolbook(&mut r_ref, &a, &a);
+        poly_mod_3_phi_n(&mut r_ref);
+        assert_eq!(r, r_ref);
+    }
+}
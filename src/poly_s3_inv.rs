@@ -1,5 +1,14 @@
-use crate::params::NTRU_N;
-use crate::poly::Poly;
+//! Inversion in (Z/3)[x]/Φn
+//!
+//! `poly_s3_inv` computes the divsteps of the reference implementation (see `poly_divstep`)
+//! on bitsliced polynomials (see `poly_s3_bitsliced`).
+//! `poly_s3_inv_scalar` is the reference implementation with one coefficient per `u16`.
+
+use super::params::NTRU_N;
+use super::poly::Poly;
+use super::poly_divstep::divsteps;
+use super::poly_s3_bitsliced::{pack, unpack};
+use crate::wipe::wipe;
 
 fn mod3(a: &mut u16) -> u16 {
     *a = ((*a >> 2) + *a) & 3;
@@ -12,7 +21,35 @@ fn both_negative_mask(x: i16, y: i16) -> i16 {
     (x & y) >> 15
 }
 
-pub fn poly_s3_inv(r: &mut Poly, a: &mut Poly) {
+/// `r = a^-1 mod (3, Φn)` for `a` with coefficients in {0,1,2}
+pub fn poly_s3_inv(r: &mut Poly, a: &Poly) {
+    /* g = the reversed a mod (3, Φn) */
+    let mut g = Poly::new();
+    for i in 0..NTRU_N - 1 {
+        g.coeffs[NTRU_N - 2 - i] = mod3(&mut ((a.coeffs[i] & 3) + 2 * (a.coeffs[NTRU_N - 1] & 3)));
+    }
+    let mut packed = pack(&g);
+
+    let (mut v, f0) = divsteps(&packed);
+
+    /* r = f[0]·v reversed */
+    for vj in v.iter_mut() {
+        *vj = vj.mul(f0);
+    }
+    unpack(&mut g, &v);
+    for i in 0..NTRU_N - 1 {
+        r.coeffs[i] = g.coeffs[NTRU_N - 2 - i];
+    }
+    r.coeffs[NTRU_N - 1] = 0;
+
+    wipe(&mut g);
+    wipe(&mut packed);
+    wipe(&mut v);
+}
+
+/// Reference implementation of `poly_s3_inv` with one coefficient per `u16`
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn poly_s3_inv_scalar(r: &mut Poly, a: &Poly) {
     let mut delta: i16 = 1;
     let mut sign: i16;
     let mut swap: i16;
@@ -68,3 +105,76 @@ pub fn poly_s3_inv(r: &mut Poly, a: &mut Poly) {
     }
     r.coeffs[NTRU_N - 1] = 0;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::poly::poly_s3_mul;
+    use super::*;
+    use crate::rng::{AesState, RNGState};
+
+    #[test]
+    fn test_poly_s3_inv_matches_scalar() {
+        let mut rng = AesState::new();
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        let mut bytes = [0u8; NTRU_N];
+        for _ in 0..8 {
+            rng.randombytes(&mut bytes).unwrap();
+            let mut a = Poly::new();
+            for (c, b) in a.coeffs.iter_mut().zip(bytes.iter()) {
+                *c = (b % 3) as u16;
+            }
+            poly_s3_inv(&mut r, &a);
+            poly_s3_inv_scalar(&mut r_ref, &a);
+            assert_eq!(r, r_ref);
+        }
+
+        /* 1 + x is invertible (Φn(-1) = 1 for odd n) */
+        let mut a = Poly::new();
+        a.coeffs[0] = 1;
+        a.coeffs[1] = 1;
+        poly_s3_inv(&mut r, &a);
+        let mut one = Poly::new();
+        poly_s3_mul(&mut one, &a, &r);
+        let mut expected = Poly::new();
+        expected.coeffs[0] = 1;
+        assert_eq!(one, expected);
+
+        /* Φn ≡ 0 is not invertible */
+        let a = Poly::build(1);
+        poly_s3_inv(&mut r, &a);
+        poly_s3_inv_scalar(&mut r_ref, &a);
+        assert_eq!(r, r_ref);
+    }
+
+    #[test]
+    fn test_poly_s3_inv_structured_matches_scalar() {
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        let mut inputs = vec![Poly::new(), Poly::build(2)];
+        for k in [0, 1, 63, 64, NTRU_N - 2, NTRU_N - 1] {
+            for c in [1, 2] {
+                let mut a = Poly::new();
+                a.coeffs[k] = c;
+                inputs.push(a);
+            }
+        }
+        for period in [2, 3, 64] {
+            let mut a = Poly::new();
+            for (i, c) in a.coeffs.iter_mut().enumerate() {
+                *c = (i % period % 3) as u16;
+            }
+            inputs.push(a);
+        }
+        /* all ones but one coefficient */
+        let mut a = Poly::build(1);
+        a.coeffs[NTRU_N / 2] = 2;
+        inputs.push(a);
+
+        for a in &inputs {
+            poly_s3_inv(&mut r, a);
+            poly_s3_inv_scalar(&mut r_ref, a);
+            assert_eq!(r, r_ref);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Multiplication by a ternary polynomial
+//!
+//! In `owcpa_enc` (`r·h`) and `owcpa_dec` (`c·f`, `mf·fp`), one operand is ternary.
+//! Instead of multiplying by its coefficients, `poly_rq_mul_ternary` adds the other
+//! operand where a coefficient is 1 and subtracts it where it is -1. Both cases are
+//! selected by masks, so there are no branches or memory accesses depending on the
+//! ternary coefficients.
+
+use super::params::NTRU_N;
+use super::poly::Poly;
+use super::poly_mod::poly_mod_3_phi_n;
+use crate::wipe::wipe;
+
+/// `r = a·b mod (q, x^n - 1)` for a ternary `a` with coefficients in {0,1,2} or {0,1,q-1},
+/// where 2 and q-1 represent -1. Like `poly_rq_mul`, the coefficients of `r` are not reduced mod q.
+pub fn poly_rq_mul_ternary(r: &mut Poly, a: &Poly, b: &Poly) {
+    #[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+    if crate::backend::use_avx2() {
+        // SAFETY: `use_avx2` only returns true if the CPU supports AVX2
+        unsafe { poly_rq_mul_ternary_avx2(r, a, b) };
+        return;
+    }
+    rq_mul_ternary(r, a, b);
+}
+
+/// `poly_rq_mul_ternary` compiled with AVX2, so the inner loops are vectorized with 16 lanes
+#[cfg(all(feature = "avx2", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+fn poly_rq_mul_ternary_avx2(r: &mut Poly, a: &Poly, b: &Poly) {
+    rq_mul_ternary(r, a, b);
+}
+
+/// Number of coefficients of the product accumulated at once
+const BLOCK: usize = 64;
+/// `NTRU_N` padded to a multiple of `BLOCK`
+const PAD: usize = NTRU_N.div_ceil(BLOCK) * BLOCK;
+
+#[inline(always)]
+fn rq_mul_ternary(r: &mut Poly, a: &Poly, b: &Poly) {
+    /* a[i]·y = (y ^ sign[i]) & nonzero[i] + (1 if a[i] = -1), since y ^ 0xffff = -y - 1. */
+    /* Bit 1 is set for 2 and q-1 (sign), bit 0 or bit 1 for 1, 2 and q-1 (nonzero).      */
+    let mut sign = [0u16; NTRU_N];
+    let mut nonzero = [0u16; NTRU_N];
+    let mut negatives = 0u16;
+    for ((s, m), &ai) in sign.iter_mut().zip(nonzero.iter_mut()).zip(a.coeffs.iter()) {
+        *s = 0u16.wrapping_sub((ai >> 1) & 1);
+        *m = 0u16.wrapping_sub((ai | (ai >> 1)) & 1);
+        negatives = negatives.wrapping_add(*s & 1);
+    }
+
+    /* bb[t] = b[t mod n], so coefficient k of the product is the sum of a[i]·bb[k - i + n] */
+    let mut bb = [0u16; NTRU_N + PAD];
+    for (t, x) in bb.iter_mut().enumerate() {
+        *x = b.coeffs[t % NTRU_N];
+    }
+
+    let mut out = [0u16; PAD];
+    for (k, block) in out.chunks_exact_mut(BLOCK).enumerate() {
+        /* every coefficient of the product gets the +1 of each a[i] = -1 */
+        let mut acc = [negatives; BLOCK];
+        /* a[i] meets the window of bb starting at k·BLOCK + n - i */
+        let windows = bb[k * BLOCK + 1..k * BLOCK + NTRU_N + BLOCK]
+            .windows(BLOCK)
+            .rev();
+        for ((&s, &m), window) in sign.iter().zip(nonzero.iter()).zip(windows) {
+            let window: &[u16; BLOCK] = window.try_into().unwrap();
+            for (x, &y) in acc.iter_mut().zip(window) {
+                *x = x.wrapping_add((y ^ s) & m);
+            }
+        }
+        block.copy_from_slice(&acc);
+        wipe(&mut acc);
+    }
+    r.coeffs.copy_from_slice(&out[..NTRU_N]);
+
+    wipe(&mut sign);
+    wipe(&mut nonzero);
+    wipe(&mut negatives);
+    wipe(&mut bb);
+    wipe(&mut out);
+}
+
+/// Multiple of 3 which makes the coefficients of `a·b` non-negative in `poly_s3_mul_ternary`
+const S3_OFFSET: u16 = 3 * NTRU_N as u16;
+
+const _: () = assert!(
+    5 * 3 * NTRU_N < 65536,
+    "the coefficients in poly_s3_mul_ternary must not overflow"
+);
+
+/// `r = a·b mod (3, Φn)` for a ternary `a` (as in `poly_rq_mul_ternary`) and `b` with
+/// coefficients in {0,1,2}. The result is identical to `poly_s3_mul(r, a, b)` if `a` has
+/// coefficients in {0,1,2}.
+pub fn poly_s3_mul_ternary(r: &mut Poly, a: &Poly, b: &Poly) {
+    poly_rq_mul_ternary(r, a, b);
+    /* the coefficients lie in [-2n, 2n], shift them to [n, 5n] without changing them mod 3 */
+    for c in r.coeffs.iter_mut() {
+        *c = c.wrapping_add(S3_OFFSET);
+    }
+    poly_mod_3_phi_n(r);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::poly::{poly_s3_mul, poly_z3_to_zq, MODQ};
+    use super::super::poly_rq_mul::poly_rq_mul;
+    use super::*;
+    use crate::rng::{AesState, RNGState};
+
+    fn random_poly(rng: &mut AesState, modulus: u16) -> Poly {
+        let mut bytes = [0u8; 2 * NTRU_N];
+        rng.randombytes(&mut bytes).unwrap();
+        let mut p = Poly::new();
+        for (c, b) in p.coeffs.iter_mut().zip(bytes.chunks_exact(2)) {
+            *c = u16::from_le_bytes([b[0], b[1]]).wrapping_rem(modulus);
+        }
+        p
+    }
+
+    #[test]
+    fn test_poly_mul_ternary() {
+        let mut rng = AesState::new();
+        let mut r = Poly::new();
+        let mut r_ref = Poly::new();
+        for _ in 0..8 {
+            let mut a = random_poly(&mut rng, 3);
+            let b = random_poly(&mut rng, u16::MAX);
+            let b3 = random_poly(&mut rng, 3);
+
+            /* {0,1,2} representation */
+            poly_s3_mul_ternary(&mut r, &a, &b3);
+            poly_s3_mul(&mut r_ref, &a, &b3);
+            assert_eq!(r, r_ref);
+
+            /* {0,1,q-1} representation, equal mod q */
+            poly_rq_mul_ternary(&mut r, &a, &b);
+            poly_z3_to_zq(&mut a);
+            poly_rq_mul(&mut r_ref, &a, &b);
+            for (x, y) in r.coeffs.iter().zip(r_ref.coeffs.iter()) {
+                assert_eq!(MODQ(*x), MODQ(*y));
+            }
+            poly_rq_mul_ternary(&mut r_ref, &a, &b);
+            assert_eq!(r, r_ref);
+        }
+
+        /* extreme coefficients */
+        let a = Poly::build(2);
+        let b = Poly::build(2);
+        poly_s3_mul_ternary(&mut r, &a, &b);
+        poly_s3_mul(&mut r_ref, &a, &b);
+        assert_eq!(r, r_ref);
+    }
+}
@@ -6,29 +6,90 @@
 
 use aes::BlockEncrypt;
 use aes::NewBlockCipher;
-use std::error;
-use std::fmt;
+use core::fmt;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::error::NtruError;
+use crate::wipe::wipe;
 
 /// Trait requiring primitives to generate pseudo-random numbers.
-/// `AesState` is an object implementing this trait.
+/// It is implemented for every cryptographically secure `rand_core` RNG,
+/// e.g. `AesState` or `rand_core::OsRng`. Seeding depends on the RNG,
+/// e.g. `AesState::randombytes_init` or `SeedableRng::from_seed`.
 pub trait RNGState {
     /// Fill the buffer `x` with pseudo-random bytes resulting from the
     /// RNG run updating the RNG state
-    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), Box<dyn error::Error>>;
-    /// Initialize/reset the RNG state based on the seed provided as `entropy_input`
-    fn randombytes_init(&mut self, entropy_input: [u8; 48]);
+    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), NtruError>;
+}
+
+impl<R: RngCore + CryptoRng + ?Sized> RNGState for R {
+    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), NtruError> {
+        self.try_fill_bytes(x).map_err(|_| NtruError::Rng)
+    }
+}
+
+/// The 48 bytes of entropy input seeding an `AesState`
+#[derive(Clone, PartialEq, Eq)]
+pub struct AesSeed(pub [u8; 48]);
+
+impl fmt::Debug for AesSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AesSeed([REDACTED])")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AesSeed {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AesSeed {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for AesSeed {}
+
+impl Default for AesSeed {
+    fn default() -> Self {
+        Self([0u8; 48])
+    }
+}
+
+impl AsMut<[u8]> for AesSeed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl From<[u8; 48]> for AesSeed {
+    fn from(entropy_input: [u8; 48]) -> Self {
+        Self(entropy_input)
+    }
 }
 
 /// AesState is a struct storing data of a pseudo-random number generator.
 /// Using `randombytes_init`, it can be initialized once. Using `randombytes`,
 /// one can successively fetch new pseudo-random numbers.
-#[derive(Clone, Debug, PartialEq)]
+/// It also implements `RngCore` and `SeedableRng` with an `AesSeed` as seed.
+#[derive(Clone, PartialEq)]
 pub struct AesState {
     pub key: [u8; 32],
     pub v: [u8; 16],
     pub reseed_counter: i32,
 }
 
+impl fmt::Debug for AesState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AesState([REDACTED])")
+    }
+}
+
 impl AesState {
     /// Returns a fresh RNG state
     pub fn new() -> AesState {
@@ -74,13 +135,40 @@ impl AesState {
         key[0..16].copy_from_slice(&temp[0]);
         key[16..32].copy_from_slice(&temp[1]);
         v.copy_from_slice(&temp[2]);
+        wipe(&mut temp);
+    }
+
+    /// Initialize/reset the state based on the seed provided as `entropy_input`
+    pub fn randombytes_init(&mut self, mut entropy_input: [u8; 48]) {
+        self.key = [0u8; 32];
+        self.v = [0u8; 16];
+        self.reseed_counter = 1i32;
+
+        let mut provided_data = Some(entropy_input);
+        Self::aes256_ctr_update(&mut provided_data, &mut self.key, &mut self.v);
+        self.reseed_counter = 1;
+
+        wipe(&mut entropy_input);
+        if let Some(d) = provided_data.as_mut() {
+            wipe(d);
+        }
     }
 }
 
-impl RNGState for AesState {
+impl RngCore for AesState {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
     /// Fill the buffer `x` with pseudo-random bytes resulting from the
-    /// AES run in counter mode updating the object state
-    fn randombytes(&mut self, x: &mut [u8]) -> Result<(), Box<dyn error::Error>> {
+    /// AES run in counter mode updating the object state.
+    /// Like the NIST reference, the state is updated after every call,
+    /// so the output depends on how the requested bytes are split into calls.
+    fn fill_bytes(&mut self, x: &mut [u8]) {
         for chunk in x.chunks_mut(16) {
             let count = u128::from_be_bytes(self.v);
             self.v.copy_from_slice(&(count + 1).to_be_bytes());
@@ -89,22 +177,29 @@ impl RNGState for AesState {
             Self::aes256_ecb(&self.key, &self.v, &mut block);
 
             (*chunk).copy_from_slice(&block[..chunk.len()]);
+            wipe(&mut block);
         }
 
         Self::aes256_ctr_update(&mut None, &mut self.key, &mut self.v);
         self.reseed_counter += 1;
+    }
 
+    fn try_fill_bytes(&mut self, x: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(x);
         Ok(())
     }
+}
 
-    /// Initialize/reset the state based on the seed provided as `entropy_input`
-    fn randombytes_init(&mut self, entropy_input: [u8; 48]) {
-        self.key = [0u8; 32];
-        self.v = [0u8; 16];
-        self.reseed_counter = 1i32;
+impl CryptoRng for AesState {}
 
-        Self::aes256_ctr_update(&mut Some(entropy_input), &mut self.key, &mut self.v);
-        self.reseed_counter = 1;
+impl SeedableRng for AesState {
+    type Seed = AesSeed;
+
+    fn from_seed(mut seed: AesSeed) -> Self {
+        let mut rng = Self::new();
+        rng.randombytes_init(seed.0);
+        wipe(&mut seed.0);
+        rng
     }
 }
 
@@ -116,30 +211,37 @@ impl Default for AesState {
 
 impl Eq for AesState {}
 
-impl fmt::Display for AesState {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "AesState {{")?;
-        writeln!(f, "  key = {:?}", self.key)?;
-        writeln!(f, "  v   = {:?}", self.v)?;
-        writeln!(f, "  reseed_counter = {}", self.reseed_counter)?;
-        writeln!(f, "}}")
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for AesState {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.v.zeroize();
+        self.reseed_counter.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AesState {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for AesState {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_rng() -> Result<(), Box<dyn error::Error>> {
+    fn test_rng() -> Result<(), NtruError> {
         let mut data = [0u8; 256];
         let mut entropy_input = [0u8; 48];
-        let mut personalization_string = [0u8; 48];
         let mut rng_state = AesState::new();
 
-        for i in 0..48 {
-            entropy_input[i] = i as u8;
-            personalization_string[i] = 0 as u8;
+        for (i, e) in entropy_input.iter_mut().enumerate() {
+            *e = i as u8;
         }
 
         rng_state.randombytes_init(entropy_input);
@@ -194,4 +296,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rng_core() {
+        let mut entropy_input = [0u8; 48];
+        for (i, e) in entropy_input.iter_mut().enumerate() {
+            *e = i as u8;
+        }
+
+        let mut rng_state = AesState::new();
+        rng_state.randombytes_init(entropy_input);
+        let mut seeded = AesState::from_seed(AesSeed(entropy_input));
+        assert_eq!(rng_state, seeded);
+
+        let mut expected = [0u8; 64];
+        let mut actual = [0u8; 64];
+        rng_state.randombytes(&mut expected).unwrap();
+        seeded.fill_bytes(&mut actual);
+        assert_eq!(expected, actual);
+
+        let mut expected = [0u8; 8];
+        rng_state.randombytes(&mut expected).unwrap();
+        assert_eq!(seeded.next_u64(), u64::from_le_bytes(expected));
+    }
+
+    #[test]
+    fn test_debug_redacted() {
+        assert_eq!(
+            format!("{:?}", AesSeed([0x42u8; 48])),
+            "AesSeed([REDACTED])"
+        );
+        assert_eq!(
+            format!("{:?}", AesState::from_seed(AesSeed([0x42u8; 48]))),
+            "AesState([REDACTED])"
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut rng_state = AesState::from_seed(AesSeed([0x42u8; 48]));
+        rng_state.zeroize();
+        assert_eq!(rng_state, AesState::new());
+    }
 }
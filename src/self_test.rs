@@ -0,0 +1,74 @@
+//! Power-on self test of all variants compiled into this build.
+//!
+//! Every variant reruns the first testcase of its NIST KAT file using `AesState`
+//! and the derandomized `crypto_kem_*_derand` functions. Applications can call
+//! `self_test` at startup and refuse to operate if any variant fails.
+
+use core::fmt;
+
+use crate::variant::Variant;
+
+/// Outcome of the known-answer test of one variant
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelfTestResult {
+    /// The tested variant
+    pub variant: Variant,
+    /// Whether pk, sk, ct and the shared key matched the expected values
+    pub passed: bool,
+}
+
+/// Outcomes of the known-answer tests of all variants in `Variant::ALL`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfTestReport {
+    results: [SelfTestResult; Variant::ALL.len()],
+}
+
+impl SelfTestReport {
+    /// One result per compiled variant, in the order of `Variant::ALL`
+    pub fn results(&self) -> &[SelfTestResult] {
+        &self.results
+    }
+
+    /// `true` if the known-answer tests of all variants passed
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|r| r.passed)
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, r) in self.results.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            let outcome = if r.passed { "passed" } else { "FAILED" };
+            write!(f, "{}: {}", r.variant, outcome)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the embedded known-answer test of every variant compiled into this build
+pub fn self_test() -> SelfTestReport {
+    SelfTestReport {
+        results: core::array::from_fn(|i| SelfTestResult {
+            variant: Variant::ALL[i],
+            passed: Variant::ALL[i].self_test(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test() {
+        let report = self_test();
+        assert!(report.passed(), "{}", report);
+        assert_eq!(report.results().len(), Variant::ALL.len());
+        for (r, v) in report.results().iter().zip(Variant::ALL) {
+            assert_eq!(r.variant, *v);
+        }
+    }
+}
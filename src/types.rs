@@ -0,0 +1,215 @@
+//! Strongly typed keys, ciphertexts and shared secrets wrapping the byte arrays of the KEM
+
+use core::fmt;
+
+use subtle::{Choice, ConstantTimeEq};
+
+use super::api::{
+    CRYPTO_BYTES, CRYPTO_CIPHERTEXTBYTES, CRYPTO_PUBLICKEYBYTES, CRYPTO_SECRETKEYBYTES,
+};
+use super::kem::{crypto_kem_dec, crypto_kem_enc, crypto_kem_keypair};
+use crate::error::{InvalidLength, NtruError};
+use crate::rng::RNGState;
+
+macro_rules! bytes_newtype {
+    ($name:ident, $len:ident) => {
+        impl $name {
+            /// Wrap a byte slice. Fails if it does not have the size required by this variant.
+            pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidLength> {
+                let array = <[u8; $len]>::try_from(bytes).map_err(|_| InvalidLength {
+                    expected: $len,
+                    actual: bytes.len(),
+                })?;
+                Ok(Self(array))
+            }
+
+            /// Returns the underlying bytes
+            pub fn as_bytes(&self) -> &[u8; $len] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+    };
+}
+
+/// A public key which can be shared with any party
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey([u8; CRYPTO_PUBLICKEYBYTES]);
+
+/// A secret key which must only be accessible to the party who generated it
+#[derive(Clone)]
+pub struct SecretKey([u8; CRYPTO_SECRETKEYBYTES]);
+
+/// A ciphertext resulting from an encapsulation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext([u8; CRYPTO_CIPHERTEXTBYTES]);
+
+/// A shared key negotiated between two parties.
+/// It is compared in constant time, also by `==`.
+#[derive(Clone)]
+pub struct SharedSecret([u8; CRYPTO_BYTES]);
+
+bytes_newtype!(PublicKey, CRYPTO_PUBLICKEYBYTES);
+bytes_newtype!(SecretKey, CRYPTO_SECRETKEYBYTES);
+bytes_newtype!(Ciphertext, CRYPTO_CIPHERTEXTBYTES);
+bytes_newtype!(SharedSecret, CRYPTO_BYTES);
+
+/// Given an RNG instance, compute a public and secret key
+pub fn keypair(rng: &mut impl RNGState) -> Result<(PublicKey, SecretKey), NtruError> {
+    let mut pk = PublicKey([0u8; CRYPTO_PUBLICKEYBYTES]);
+    let mut sk = SecretKey([0u8; CRYPTO_SECRETKEYBYTES]);
+    crypto_kem_keypair(&mut pk.0, &mut sk.0, rng)?;
+    Ok((pk, sk))
+}
+
+impl PublicKey {
+    /// Given an RNG instance, sample a shared secret and the ciphertext
+    /// which allows the owner of the secret key to recover it
+    pub fn encapsulate(
+        &self,
+        rng: &mut impl RNGState,
+    ) -> Result<(Ciphertext, SharedSecret), NtruError> {
+        let mut ct = Ciphertext([0u8; CRYPTO_CIPHERTEXTBYTES]);
+        let mut ss = SharedSecret([0u8; CRYPTO_BYTES]);
+        crypto_kem_enc(&mut ct.0, &mut ss.0, &self.0, rng)?;
+        Ok((ct, ss))
+    }
+}
+
+impl SecretKey {
+    /// Determine the shared secret from a ciphertext
+    pub fn decapsulate(&self, ct: &Ciphertext) -> Result<SharedSecret, NtruError> {
+        let mut ss = SharedSecret([0u8; CRYPTO_BYTES]);
+        crypto_kem_dec(&mut ss.0, &ct.0, &self.0)?;
+        Ok(ss)
+    }
+}
+
+impl ConstantTimeEq for SharedSecret {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for SharedSecret {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SharedSecret {}
+
+/// Wipe the secret bytes on drop
+macro_rules! zeroize_on_drop {
+    ($name:ident) => {
+        #[cfg(feature = "zeroize")]
+        impl zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl Drop for $name {
+            fn drop(&mut self) {
+                zeroize::Zeroize::zeroize(self);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::ZeroizeOnDrop for $name {}
+    };
+}
+
+zeroize_on_drop!(SecretKey);
+zeroize_on_drop!(SharedSecret);
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretKey([REDACTED])")
+    }
+}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SharedSecret([REDACTED])")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::AesState;
+
+    #[test]
+    fn test_roundtrip() -> Result<(), NtruError> {
+        let mut rng = AesState::new();
+        let (pk, sk) = keypair(&mut rng)?;
+        let (ct, ss_bob) = pk.encapsulate(&mut rng)?;
+        let ss_alice = sk.decapsulate(&ct)?;
+        assert_eq!(ss_alice, ss_bob);
+
+        let ct = Ciphertext::from_bytes(ct.as_bytes())?;
+        assert_eq!(sk.decapsulate(&ct)?, ss_bob);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rand_core_rng() -> Result<(), NtruError> {
+        let (pk, sk) = keypair(&mut rand_core::OsRng)?;
+        let (ct, ss_bob) = pk.encapsulate(&mut rand_core::OsRng)?;
+        assert_eq!(sk.decapsulate(&ct)?, ss_bob);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_invalid_length() {
+        let bytes = [0u8; CRYPTO_PUBLICKEYBYTES + 1];
+        assert_eq!(
+            PublicKey::from_bytes(&bytes),
+            Err(InvalidLength {
+                expected: CRYPTO_PUBLICKEYBYTES,
+                actual: CRYPTO_PUBLICKEYBYTES + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_shared_secret_ct_eq() {
+        let a = SharedSecret::from([0x42u8; CRYPTO_BYTES]);
+        let mut bytes = [0x42u8; CRYPTO_BYTES];
+        assert_eq!(a.ct_eq(&SharedSecret::from(bytes)).unwrap_u8(), 1);
+        assert_eq!(a, SharedSecret::from(bytes));
+
+        bytes[CRYPTO_BYTES - 1] ^= 1;
+        assert_eq!(a.ct_eq(&SharedSecret::from(bytes)).unwrap_u8(), 0);
+        assert_ne!(a, SharedSecret::from(bytes));
+    }
+
+    #[test]
+    fn test_debug_redacted() {
+        let sk = SecretKey::from([0x42u8; CRYPTO_SECRETKEYBYTES]);
+        assert_eq!(format!("{:?}", sk), "SecretKey([REDACTED])");
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut sk = SecretKey::from([0x42u8; CRYPTO_SECRETKEYBYTES]);
+        sk.zeroize();
+        assert_eq!(sk.as_bytes(), &[0u8; CRYPTO_SECRETKEYBYTES]);
+    }
+}
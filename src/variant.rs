@@ -0,0 +1,199 @@
+//! Runtime selection of an NTRU variant.
+//!
+//! The functions of the variant modules take arrays whose size depends on the variant.
+//! If the variant is only known at runtime (e.g. read from a configuration file),
+//! `Variant` dispatches to the corresponding `crypto_kem_*` functions and checks
+//! the lengths of the provided byte slices.
+
+use core::fmt;
+
+use crate::error::{InvalidLength, NtruError};
+use crate::rng::RNGState;
+
+type R = Result<(), NtruError>;
+
+/// One of the NTRU parameter sets compiled into this build
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// NTRU-HPS with n = 509 and q = 2048
+    #[cfg(feature = "ntruhps2048509")]
+    NtruHps2048509,
+    /// NTRU-HPS with n = 677 and q = 2048
+    #[cfg(feature = "ntruhps2048677")]
+    NtruHps2048677,
+    /// NTRU-HPS with n = 821 and q = 4096
+    #[cfg(feature = "ntruhps4096821")]
+    NtruHps4096821,
+    /// NTRU-HRSS with n = 701 and q = 8192
+    #[cfg(feature = "ntruhrss701")]
+    NtruHrss701,
+}
+
+/// Evaluate `$body` with `$ntru` bound to the module of the variant `$variant`
+macro_rules! dispatch {
+    ($variant:expr, $ntru:ident => $body:expr) => {
+        match $variant {
+            #[cfg(feature = "ntruhps2048509")]
+            Variant::NtruHps2048509 => {
+                use crate::ntruhps2048509 as $ntru;
+                $body
+            }
+            #[cfg(feature = "ntruhps2048677")]
+            Variant::NtruHps2048677 => {
+                use crate::ntruhps2048677 as $ntru;
+                $body
+            }
+            #[cfg(feature = "ntruhps4096821")]
+            Variant::NtruHps4096821 => {
+                use crate::ntruhps4096821 as $ntru;
+                $body
+            }
+            #[cfg(feature = "ntruhrss701")]
+            Variant::NtruHrss701 => {
+                use crate::ntruhrss701 as $ntru;
+                $body
+            }
+        }
+    };
+}
+
+fn array<const N: usize>(x: &[u8]) -> Result<&[u8; N], InvalidLength> {
+    x.try_into().map_err(|_| InvalidLength {
+        expected: N,
+        actual: x.len(),
+    })
+}
+
+fn array_mut<const N: usize>(x: &mut [u8]) -> Result<&mut [u8; N], InvalidLength> {
+    let actual = x.len();
+    x.try_into().map_err(|_| InvalidLength {
+        expected: N,
+        actual,
+    })
+}
+
+impl Variant {
+    /// All variants compiled into this build
+    pub const ALL: &'static [Variant] = &[
+        #[cfg(feature = "ntruhps2048509")]
+        Variant::NtruHps2048509,
+        #[cfg(feature = "ntruhps2048677")]
+        Variant::NtruHps2048677,
+        #[cfg(feature = "ntruhps4096821")]
+        Variant::NtruHps4096821,
+        #[cfg(feature = "ntruhrss701")]
+        Variant::NtruHrss701,
+    ];
+
+    /// Look up a variant by its `CRYPTO_ALGNAME` (e.g. `"ntruhps2048509"`).
+    /// Returns `None` if the name is unknown or the variant is not compiled into this build.
+    pub fn from_name(name: &str) -> Option<Variant> {
+        Self::ALL.iter().copied().find(|v| v.name() == name)
+    }
+
+    /// Name of the variant (its `CRYPTO_ALGNAME`)
+    pub fn name(&self) -> &'static str {
+        dispatch!(self, ntru => ntru::CRYPTO_ALGNAME)
+    }
+
+    /// The number of bytes required to store the public key
+    pub fn public_key_len(&self) -> usize {
+        dispatch!(self, ntru => ntru::CRYPTO_PUBLICKEYBYTES)
+    }
+
+    /// The number of bytes required to store the secret key
+    pub fn secret_key_len(&self) -> usize {
+        dispatch!(self, ntru => ntru::CRYPTO_SECRETKEYBYTES)
+    }
+
+    /// The number of bytes required to store the ciphertext
+    pub fn ciphertext_len(&self) -> usize {
+        dispatch!(self, ntru => ntru::CRYPTO_CIPHERTEXTBYTES)
+    }
+
+    /// The number of bytes required to store the negotiated/shared key
+    pub fn shared_key_len(&self) -> usize {
+        dispatch!(self, ntru => ntru::CRYPTO_BYTES)
+    }
+
+    /// Like `crypto_kem_keypair` of the variant, but `pk` and `sk` must have
+    /// `public_key_len()` and `secret_key_len()` bytes respectively
+    pub fn keypair(&self, pk: &mut [u8], sk: &mut [u8], rng: &mut impl RNGState) -> R {
+        dispatch!(self, ntru => ntru::crypto_kem_keypair(array_mut(pk)?, array_mut(sk)?, rng))
+    }
+
+    /// Like `crypto_kem_enc` of the variant, but `c`, `k` and `pk` must have
+    /// `ciphertext_len()`, `shared_key_len()` and `public_key_len()` bytes respectively
+    pub fn encapsulate(&self, c: &mut [u8], k: &mut [u8], pk: &[u8], rng: &mut impl RNGState) -> R {
+        dispatch!(self, ntru => ntru::crypto_kem_enc(array_mut(c)?, array_mut(k)?, array(pk)?, rng))
+    }
+
+    /// Like `crypto_kem_dec` of the variant, but `k`, `c` and `sk` must have
+    /// `shared_key_len()`, `ciphertext_len()` and `secret_key_len()` bytes respectively
+    pub fn decapsulate(&self, k: &mut [u8], c: &[u8], sk: &[u8]) -> R {
+        dispatch!(self, ntru => ntru::crypto_kem_dec(array_mut(k)?, array(c)?, array(sk)?))
+    }
+
+    /// Run the embedded known-answer test of the variant (see `crypto_kem_self_test`)
+    pub fn self_test(&self) -> bool {
+        dispatch!(self, ntru => ntru::crypto_kem_self_test())
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::AesState;
+
+    #[test]
+    fn test_from_name() {
+        for variant in Variant::ALL {
+            assert_eq!(Variant::from_name(variant.name()), Some(*variant));
+        }
+        assert_eq!(Variant::from_name("ntruhps2048"), None);
+    }
+
+    #[test]
+    fn test_roundtrip() -> R {
+        let mut rng = AesState::new();
+        for variant in Variant::ALL {
+            let mut pk = vec![0u8; variant.public_key_len()];
+            let mut sk = vec![0u8; variant.secret_key_len()];
+            let mut ct = vec![0u8; variant.ciphertext_len()];
+            let mut ss_alice = vec![0u8; variant.shared_key_len()];
+            let mut ss_bob = vec![0u8; variant.shared_key_len()];
+
+            variant.keypair(&mut pk, &mut sk, &mut rng)?;
+            variant.encapsulate(&mut ct, &mut ss_bob, &pk, &mut rng)?;
+            variant.decapsulate(&mut ss_alice, &ct, &sk)?;
+
+            assert_eq!(ss_alice, ss_bob, "shared keys of {} differ", variant);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let mut rng = AesState::new();
+        for variant in Variant::ALL {
+            let pk = vec![0u8; variant.public_key_len() + 1];
+            let mut ct = vec![0u8; variant.ciphertext_len()];
+            let mut ss = vec![0u8; variant.shared_key_len()];
+
+            let err = variant
+                .encapsulate(&mut ct, &mut ss, &pk, &mut rng)
+                .unwrap_err();
+            let expected = InvalidLength {
+                expected: variant.public_key_len(),
+                actual: variant.public_key_len() + 1,
+            };
+            assert_eq!(err, NtruError::InvalidLength(expected));
+        }
+    }
+}
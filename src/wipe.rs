@@ -0,0 +1,12 @@
+//! Overwriting of buffers holding secret-dependent data.
+//!
+//! With the `zeroize` feature, `wipe` zeroes the buffer in a way the
+//! compiler cannot optimize away. Without it, `wipe` does nothing.
+
+#[cfg(feature = "zeroize")]
+pub(crate) fn wipe<Z: zeroize::Zeroize + ?Sized>(secret: &mut Z) {
+    secret.zeroize();
+}
+
+#[cfg(not(feature = "zeroize"))]
+pub(crate) fn wipe<Z: ?Sized>(_secret: &mut Z) {}